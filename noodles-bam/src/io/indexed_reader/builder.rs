@@ -8,7 +8,7 @@ use std::{
 use noodles_bgzf as bgzf;
 use noodles_csi::{self as csi, BinningIndex};
 
-use super::IndexedReader;
+use super::{IndexedReader, Reader};
 use crate::bai;
 
 /// An indexed BAM reader builder.
@@ -84,6 +84,38 @@ impl Builder {
 
         Ok(IndexedReader::new(reader, index))
     }
+
+    /// Builds an indexed BAM reader from a BGZF decoder.
+    ///
+    /// This allows using a decoder other than [`bgzf::Reader`], e.g., a
+    /// [`bgzf::MultithreadedReader`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{io, num::NonZeroUsize};
+    /// use noodles_bam::{bai, io::indexed_reader::Builder};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let index = bai::Index::default();
+    /// let data = io::Cursor::new(Vec::new());
+    /// let decoder = bgzf::MultithreadedReader::with_worker_count(NonZeroUsize::MIN, data);
+    /// let reader = Builder::default().set_index(index).build_from_decoder(decoder)?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_decoder<R>(self, decoder: R) -> io::Result<IndexedReader<R>>
+    where
+        R: bgzf::io::BufRead + bgzf::io::Seek,
+    {
+        let index = self
+            .index
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index"))?;
+
+        Ok(IndexedReader {
+            inner: Reader::from(decoder),
+            index,
+        })
+    }
 }
 
 fn read_associated_index<P>(src: P) -> io::Result<Box<dyn BinningIndex>>
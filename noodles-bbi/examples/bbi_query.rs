@@ -0,0 +1,52 @@
+//! Queries a bigWig or bigBed file with a given region.
+//!
+//! bigWig records are printed as bedGraph, and bigBed records, as BED.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, Write},
+};
+
+use noodles_bbi::{self as bbi, header::Kind};
+use noodles_bed as bed;
+use noodles_core::Region;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let region: Region = args.next().expect("missing region").parse()?;
+
+    let mut reader = File::open(src).map(bbi::io::Reader::new)?;
+    let header = reader.read_header()?;
+
+    let mut stdout = io::stdout().lock();
+
+    match header.kind() {
+        Kind::BigWig => {
+            for result in reader.query_bigwig(&header, &region)? {
+                let record = result?;
+
+                writeln!(
+                    stdout,
+                    "{}\t{}\t{}\t{}",
+                    region.name(),
+                    usize::from(record.start()) - 1,
+                    record.end(),
+                    record.value()
+                )?;
+            }
+        }
+        Kind::BigBed => {
+            let mut writer = bed::io::Writer::<3, _>::new(stdout);
+
+            for result in reader.query_bigbed(&header, &region)? {
+                let record = result?;
+                writer.write_feature_record(&record)?;
+            }
+        }
+    }
+
+    Ok(())
+}
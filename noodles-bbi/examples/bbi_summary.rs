@@ -0,0 +1,41 @@
+//! Prints the summaries of the lowest resolution zoom level of a bigWig or bigBed file.
+
+use std::env;
+
+use noodles_bbi as bbi;
+use noodles_core::Region;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let region: Region = args.next().expect("missing region").parse()?;
+
+    let mut reader = bbi::io::Reader::new(std::fs::File::open(src)?);
+    let header = reader.read_header()?;
+
+    if let Some(summary) = header.total_summary() {
+        println!("total\tmean={:?}", summary.mean());
+    }
+
+    let Some(zoom_header) = header.zoom_headers().last() else {
+        return Ok(());
+    };
+
+    for result in reader.query_zoom(&header, zoom_header, &region)? {
+        let record = result?;
+        let summary = record.summary();
+
+        println!(
+            "{}\t{}\t{}\tmin={}\tmax={}\tmean={:?}",
+            region.name(),
+            usize::from(record.start()) - 1,
+            record.end(),
+            summary.min(),
+            summary.max(),
+            summary.mean(),
+        );
+    }
+
+    Ok(())
+}
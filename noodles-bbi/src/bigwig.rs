@@ -0,0 +1,5 @@
+//! bigWig.
+
+mod record;
+
+pub use self::record::Record;
@@ -0,0 +1,39 @@
+use noodles_core::{region::Interval, Position};
+
+/// A bigWig record.
+///
+/// This is a value over an interval of a reference sequence.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Record {
+    pub(crate) reference_sequence_id: usize,
+    pub(crate) start: Position,
+    pub(crate) end: Position,
+    pub(crate) value: f32,
+}
+
+impl Record {
+    /// Returns the reference sequence ID.
+    pub fn reference_sequence_id(&self) -> usize {
+        self.reference_sequence_id
+    }
+
+    /// Returns the start position.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position.
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the interval.
+    pub fn interval(&self) -> Interval {
+        (self.start..=self.end).into()
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+}
@@ -0,0 +1,85 @@
+//! BBI header.
+
+mod kind;
+mod zoom_header;
+
+use bstr::BString;
+use indexmap::IndexMap;
+
+pub use self::{kind::Kind, zoom_header::ZoomHeader};
+use crate::Summary;
+
+/// BBI reference sequences.
+///
+/// This maps reference sequence (chromosome) names to their lengths. The position of each entry
+/// is its reference sequence ID.
+pub type ReferenceSequences = IndexMap<BString, u32>;
+
+/// A BBI header.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Header {
+    pub(crate) kind: Kind,
+    pub(crate) version: u16,
+    pub(crate) zoom_headers: Vec<ZoomHeader>,
+    pub(crate) full_data_offset: u64,
+    pub(crate) full_index_offset: u64,
+    pub(crate) field_count: u16,
+    pub(crate) defined_field_count: u16,
+    pub(crate) auto_sql: Option<BString>,
+    pub(crate) total_summary: Option<Summary>,
+    pub(crate) uncompressed_buffer_size: u32,
+    pub(crate) reference_sequences: ReferenceSequences,
+}
+
+impl Header {
+    /// Returns the kind of file.
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Returns the format version.
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the zoom headers.
+    ///
+    /// These are ordered from the highest to lowest resolution.
+    pub fn zoom_headers(&self) -> &[ZoomHeader] {
+        &self.zoom_headers
+    }
+
+    /// Returns the number of fields in bigBed records.
+    ///
+    /// This is 0 for bigWig files.
+    pub fn field_count(&self) -> u16 {
+        self.field_count
+    }
+
+    /// Returns the number of standard BED fields in bigBed records.
+    ///
+    /// This is 0 for bigWig files.
+    pub fn defined_field_count(&self) -> u16 {
+        self.defined_field_count
+    }
+
+    /// Returns the raw autoSql definition of bigBed records.
+    pub fn auto_sql(&self) -> Option<&BString> {
+        self.auto_sql.as_ref()
+    }
+
+    /// Returns the summary over all data.
+    pub fn total_summary(&self) -> Option<&Summary> {
+        self.total_summary.as_ref()
+    }
+
+    /// Returns whether data blocks are compressed.
+    pub fn is_compressed(&self) -> bool {
+        self.uncompressed_buffer_size > 0
+    }
+
+    /// Returns the reference sequences.
+    pub fn reference_sequences(&self) -> &ReferenceSequences {
+        &self.reference_sequences
+    }
+}
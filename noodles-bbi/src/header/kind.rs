@@ -0,0 +1,8 @@
+/// A BBI file kind.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// bigWig.
+    BigWig,
+    /// bigBed.
+    BigBed,
+}
@@ -0,0 +1,18 @@
+/// A BBI zoom header.
+///
+/// Each zoom level has its own data section and R-tree index of precomputed summaries.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ZoomHeader {
+    pub(crate) reduction_level: u32,
+    pub(crate) data_offset: u64,
+    pub(crate) index_offset: u64,
+}
+
+impl ZoomHeader {
+    /// Returns the reduction level.
+    ///
+    /// This is the number of bases summarized by each zoom record.
+    pub fn reduction_level(&self) -> u32 {
+        self.reduction_level
+    }
+}
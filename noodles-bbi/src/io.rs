@@ -0,0 +1,5 @@
+//! BBI I/O.
+
+pub mod reader;
+
+pub use self::reader::Reader;
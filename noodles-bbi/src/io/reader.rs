@@ -0,0 +1,511 @@
+//! BBI reader.
+
+mod blocks;
+mod header;
+mod index;
+pub mod query;
+
+use std::io::{self, Read, Seek};
+
+use noodles_core::Region;
+
+use self::{
+    blocks::Blocks,
+    header::read_header,
+    query::{BigBedQuery, BigWigQuery, Target, ZoomQuery},
+};
+use crate::{
+    header::{Kind, ZoomHeader},
+    Header,
+};
+
+/// A BBI reader.
+///
+/// This reads both bigBed and bigWig files.
+pub struct Reader<R> {
+    inner: R,
+}
+
+impl<R> Reader<R> {
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bbi as bbi;
+    /// let reader = bbi::io::Reader::new(io::empty());
+    /// let _inner = reader.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bbi as bbi;
+    /// let mut reader = bbi::io::Reader::new(io::empty());
+    /// let _inner = reader.get_mut();
+    /// ```
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bbi as bbi;
+    /// let reader = bbi::io::Reader::new(io::empty());
+    /// let _inner = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R> Reader<R>
+where
+    R: Read + Seek,
+{
+    /// Creates a BBI reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bbi as bbi;
+    /// let reader = bbi::io::Reader::new(io::empty());
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    /// Reads the header.
+    ///
+    /// This reads the header, zoom headers, total summary, autoSql definition, and the
+    /// chromosome B+ tree. The stream is rewound to the start before reading.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bbi as bbi;
+    /// let mut reader = File::open("sample.bw").map(bbi::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_header(&mut self) -> io::Result<Header> {
+        read_header(&mut self.inner)
+    }
+
+    /// Returns an iterator over bigWig records that intersect the given region.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bbi as bbi;
+    ///
+    /// let mut reader = File::open("sample.bw").map(bbi::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let region = "sq0:8-13".parse()?;
+    ///
+    /// for result in reader.query_bigwig(&header, &region)? {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_bigwig(
+        &mut self,
+        header: &Header,
+        region: &Region,
+    ) -> io::Result<BigWigQuery<'_, R>> {
+        validate_kind(header, Kind::BigWig)?;
+
+        let target = Target::try_new(header, region)?;
+        let blocks = self.query_blocks(header, header.full_index_offset, target)?;
+
+        Ok(BigWigQuery::new(blocks, target))
+    }
+
+    /// Returns an iterator over bigBed records that intersect the given region.
+    ///
+    /// Records are returned as BED3+ records. Fields after the feature end are read as raw
+    /// other fields, which can be interpreted using [`Header::defined_field_count`] and
+    /// [`Header::auto_sql`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bbi as bbi;
+    ///
+    /// let mut reader = File::open("sample.bb").map(bbi::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let region = "sq0:8-13".parse()?;
+    ///
+    /// for result in reader.query_bigbed(&header, &region)? {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_bigbed<'r>(
+        &'r mut self,
+        header: &'r Header,
+        region: &Region,
+    ) -> io::Result<BigBedQuery<'r, R>> {
+        validate_kind(header, Kind::BigBed)?;
+
+        let target = Target::try_new(header, region)?;
+        let blocks = self.query_blocks(header, header.full_index_offset, target)?;
+
+        Ok(BigBedQuery::new(
+            blocks,
+            header.reference_sequences(),
+            target,
+        ))
+    }
+
+    /// Returns an iterator over zoom records of the given zoom level that intersect the given
+    /// region.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bbi as bbi;
+    ///
+    /// let mut reader = File::open("sample.bw").map(bbi::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let region = "sq0".parse()?;
+    ///
+    /// if let Some(zoom_header) = header.zoom_headers().last() {
+    ///     for result in reader.query_zoom(&header, zoom_header, &region)? {
+    ///         let record = result?;
+    ///         // ...
+    ///     }
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_zoom(
+        &mut self,
+        header: &Header,
+        zoom_header: &ZoomHeader,
+        region: &Region,
+    ) -> io::Result<ZoomQuery<'_, R>> {
+        let target = Target::try_new(header, region)?;
+        let blocks = self.query_blocks(header, zoom_header.index_offset, target)?;
+        Ok(ZoomQuery::new(blocks, target))
+    }
+
+    fn query_blocks(
+        &mut self,
+        header: &Header,
+        index_offset: u64,
+        target: Target,
+    ) -> io::Result<Blocks<'_, R>> {
+        let chunks = index::query(&mut self.inner, index_offset, target)?;
+        Ok(Blocks::new(&mut self.inner, chunks, header.is_compressed()))
+    }
+}
+
+fn validate_kind(header: &Header, kind: Kind) -> io::Result<()> {
+    if header.kind() == kind {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid file kind: expected {kind:?}, got {:?}",
+                header.kind()
+            ),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use flate2::{write::ZlibEncoder, Compression};
+    use noodles_core::Position;
+
+    use super::*;
+
+    type Section<'a> = (u32, &'a [(u32, u32, f32)]);
+
+    fn build_bigwig() -> io::Result<Vec<u8>> {
+        build_bigwig_with_sections(&[(b"sq0", 100)], &[(0, &[(10, 20, 1.5), (20, 30, 2.5)])], 1)
+    }
+
+    /// Builds a bigWig with one bedGraph section per data block.
+    ///
+    /// If there are more blocks than `items_per_slot`, the R-tree index is built with a non-leaf
+    /// root node and multiple leaf nodes.
+    fn build_bigwig_with_sections(
+        reference_sequences: &[(&[u8; 3], u32)],
+        sections: &[Section<'_>],
+        items_per_slot: usize,
+    ) -> io::Result<Vec<u8>> {
+        const HEADER_SIZE: u64 = 64;
+        const CHROMOSOME_TREE_HEADER_SIZE: u64 = 32;
+        const R_TREE_HEADER_SIZE: u64 = 48;
+        const NODE_HEADER_SIZE: u64 = 4;
+        const NON_LEAF_ITEM_SIZE: u64 = 24;
+        const LEAF_ITEM_SIZE: u64 = 32;
+
+        let mut uncompressed_buffer_size = 0;
+        let mut blocks = Vec::with_capacity(sections.len());
+
+        for &(chrom_id, items) in sections {
+            let start = items
+                .first()
+                .map(|(start, _, _)| *start)
+                .unwrap_or_default();
+            let end = items.last().map(|(_, end, _)| *end).unwrap_or_default();
+
+            let mut section = Vec::new();
+            section.extend(chrom_id.to_le_bytes()); // chromId
+            section.extend(start.to_le_bytes()); // chromStart
+            section.extend(end.to_le_bytes()); // chromEnd
+            section.extend(0u32.to_le_bytes()); // itemStep
+            section.extend(0u32.to_le_bytes()); // itemSpan
+            section.extend([1, 0]); // type, reserved
+            section.extend((items.len() as u16).to_le_bytes()); // itemCount
+
+            for (start, end, value) in items {
+                section.extend(start.to_le_bytes());
+                section.extend(end.to_le_bytes());
+                section.extend(value.to_le_bytes());
+            }
+
+            uncompressed_buffer_size = uncompressed_buffer_size.max(section.len() as u32);
+
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&section)?;
+            blocks.push(((chrom_id, start), (chrom_id, end), encoder.finish()?));
+        }
+
+        let chromosome_tree_size =
+            CHROMOSOME_TREE_HEADER_SIZE + NODE_HEADER_SIZE + 12 * reference_sequences.len() as u64;
+        let full_data_offset = HEADER_SIZE + chromosome_tree_size;
+
+        let mut block_offsets = Vec::with_capacity(blocks.len());
+        let mut block_offset = full_data_offset + 8;
+
+        for (_, _, block) in &blocks {
+            block_offsets.push(block_offset);
+            block_offset += block.len() as u64;
+        }
+
+        let full_index_offset = block_offset;
+
+        let mut buf = Vec::new();
+
+        // header
+        buf.extend(0x888ffc26u32.to_le_bytes()); // magic
+        buf.extend(4u16.to_le_bytes()); // version
+        buf.extend(0u16.to_le_bytes()); // zoomLevels
+        buf.extend(HEADER_SIZE.to_le_bytes()); // chromosomeTreeOffset
+        buf.extend(full_data_offset.to_le_bytes()); // fullDataOffset
+        buf.extend(full_index_offset.to_le_bytes()); // fullIndexOffset
+        buf.extend(0u16.to_le_bytes()); // fieldCount
+        buf.extend(0u16.to_le_bytes()); // definedFieldCount
+        buf.extend(0u64.to_le_bytes()); // autoSqlOffset
+        buf.extend(0u64.to_le_bytes()); // totalSummaryOffset
+        buf.extend(uncompressed_buffer_size.to_le_bytes()); // uncompressBufSize
+        buf.extend(0u64.to_le_bytes()); // extensionOffset
+
+        // chromosome B+ tree
+        buf.extend(0x78ca8c91u32.to_le_bytes()); // magic
+        buf.extend((reference_sequences.len() as u32).to_le_bytes()); // blockSize
+        buf.extend(4u32.to_le_bytes()); // keySize
+        buf.extend(8u32.to_le_bytes()); // valSize
+        buf.extend((reference_sequences.len() as u64).to_le_bytes()); // itemCount
+        buf.extend(0u64.to_le_bytes()); // reserved
+        buf.extend([1, 0]); // isLeaf, reserved
+        buf.extend((reference_sequences.len() as u16).to_le_bytes()); // count
+
+        for (chrom_id, (name, length)) in reference_sequences.iter().enumerate() {
+            buf.extend(*name); // key
+            buf.push(0x00);
+            buf.extend((chrom_id as u32).to_le_bytes()); // chromId
+            buf.extend(length.to_le_bytes()); // chromSize
+        }
+
+        // data
+        buf.extend((blocks.len() as u64).to_le_bytes()); // dataCount
+
+        for (_, _, block) in &blocks {
+            buf.extend(block);
+        }
+
+        // R-tree index
+        let (first_start, last_end) = match (blocks.first(), blocks.last()) {
+            (Some((start, _, _)), Some((_, end, _))) => (*start, *end),
+            _ => ((0, 0), (0, 0)),
+        };
+
+        buf.extend(0x2468ace0u32.to_le_bytes()); // magic
+        buf.extend((items_per_slot as u32).to_le_bytes()); // blockSize
+        buf.extend((blocks.len() as u64).to_le_bytes()); // itemCount
+        buf.extend(first_start.0.to_le_bytes()); // startChromIx
+        buf.extend(first_start.1.to_le_bytes()); // startBase
+        buf.extend(last_end.0.to_le_bytes()); // endChromIx
+        buf.extend(last_end.1.to_le_bytes()); // endBase
+        buf.extend(full_index_offset.to_le_bytes()); // endFileOffset
+        buf.extend((items_per_slot as u32).to_le_bytes()); // itemsPerSlot
+        buf.extend(0u32.to_le_bytes()); // reserved
+
+        let leaves: Vec<_> = blocks
+            .iter()
+            .zip(&block_offsets)
+            .collect::<Vec<_>>()
+            .chunks(items_per_slot)
+            .map(|chunk| chunk.to_vec())
+            .collect();
+
+        if leaves.len() > 1 {
+            let root_offset = full_index_offset + R_TREE_HEADER_SIZE;
+            let mut leaf_offset =
+                root_offset + NODE_HEADER_SIZE + NON_LEAF_ITEM_SIZE * leaves.len() as u64;
+
+            buf.extend([0, 0]); // isLeaf, reserved
+            buf.extend((leaves.len() as u16).to_le_bytes()); // count
+
+            for leaf in &leaves {
+                let (start, _, _) = leaf[0].0;
+                let (_, end, _) = leaf[leaf.len() - 1].0;
+
+                buf.extend(start.0.to_le_bytes()); // startChromIx
+                buf.extend(start.1.to_le_bytes()); // startBase
+                buf.extend(end.0.to_le_bytes()); // endChromIx
+                buf.extend(end.1.to_le_bytes()); // endBase
+                buf.extend(leaf_offset.to_le_bytes()); // dataOffset
+
+                leaf_offset += NODE_HEADER_SIZE + LEAF_ITEM_SIZE * leaf.len() as u64;
+            }
+        }
+
+        for leaf in leaves {
+            buf.extend([1, 0]); // isLeaf, reserved
+            buf.extend((leaf.len() as u16).to_le_bytes()); // count
+
+            for ((start, end, block), block_offset) in leaf {
+                buf.extend(start.0.to_le_bytes()); // startChromIx
+                buf.extend(start.1.to_le_bytes()); // startBase
+                buf.extend(end.0.to_le_bytes()); // endChromIx
+                buf.extend(end.1.to_le_bytes()); // endBase
+                buf.extend(block_offset.to_le_bytes()); // dataOffset
+                buf.extend((block.len() as u64).to_le_bytes()); // dataSize
+            }
+        }
+
+        Ok(buf)
+    }
+
+    #[test]
+    fn test_query_bigwig() -> Result<(), Box<dyn std::error::Error>> {
+        let mut reader = build_bigwig().map(Cursor::new).map(Reader::new)?;
+        let header = reader.read_header()?;
+
+        assert_eq!(header.kind(), Kind::BigWig);
+        assert!(header.is_compressed());
+        assert_eq!(
+            header.reference_sequences().get("sq0".as_bytes()),
+            Some(&100)
+        );
+
+        let region = "sq0:15-25".parse()?;
+        let records: Vec<_> = reader
+            .query_bigwig(&header, &region)?
+            .collect::<io::Result<_>>()?;
+        let values: Vec<_> = records.iter().map(|record| record.value()).collect();
+        assert_eq!(values, [1.5, 2.5]);
+        assert_eq!(records[0].start(), Position::try_from(11)?);
+        assert_eq!(records[0].end(), Position::try_from(20)?);
+
+        let region = "sq0:21-100".parse()?;
+        let values: Vec<_> = reader
+            .query_bigwig(&header, &region)?
+            .map(|result| result.map(|record| record.value()))
+            .collect::<io::Result<_>>()?;
+        assert_eq!(values, [2.5]);
+
+        let region = "sq0:31-100".parse()?;
+        assert_eq!(reader.query_bigwig(&header, &region)?.count(), 0);
+
+        let region = "sq0".parse()?;
+        assert!(matches!(
+            reader.query_bigbed(&header, &region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_bigwig_with_multiple_blocks_and_index_levels(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_bigwig_with_sections(
+            &[(b"sq0", 100), (b"sq1", 50)],
+            &[
+                (0, &[(0, 10, 1.0), (10, 20, 2.0)]),
+                (0, &[(20, 30, 3.0)]),
+                (0, &[(30, 40, 4.0), (40, 50, 5.0)]),
+                (1, &[(0, 25, 6.0)]),
+                (1, &[(25, 50, 7.0)]),
+            ],
+            2,
+        )?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+        let header = reader.read_header()?;
+
+        let mut query = |region: &str| -> Result<Vec<f32>, Box<dyn std::error::Error>> {
+            let region = region.parse()?;
+            let values = reader
+                .query_bigwig(&header, &region)?
+                .map(|result| result.map(|record| record.value()))
+                .collect::<io::Result<_>>()?;
+            Ok(values)
+        };
+
+        // spans blocks in the first and second leaf nodes
+        assert_eq!(query("sq0:15-35")?, [2.0, 3.0, 4.0]);
+        assert_eq!(query("sq0")?, [1.0, 2.0, 3.0, 4.0, 5.0]);
+        // spans blocks in the second and third leaf nodes
+        assert_eq!(query("sq1")?, [6.0, 7.0]);
+        assert_eq!(query("sq1:30-40")?, [7.0]);
+        assert!(query("sq0:51-100")?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_bigwig_with_unknown_reference_sequence() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut reader = build_bigwig().map(Cursor::new).map(Reader::new)?;
+        let header = reader.read_header()?;
+
+        let region = "sq1".parse()?;
+        assert!(matches!(
+            reader.query_bigwig(&header, &region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use std::{
+    io::{self, Read, Seek, SeekFrom},
+    vec,
+};
+
+use flate2::read::ZlibDecoder;
+
+use super::index::Chunk;
+
+/// A reader of data blocks.
+pub(super) struct Blocks<'r, R> {
+    reader: &'r mut R,
+    chunks: vec::IntoIter<Chunk>,
+    is_compressed: bool,
+    src: Vec<u8>,
+    dst: Vec<u8>,
+}
+
+impl<'r, R> Blocks<'r, R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn new(reader: &'r mut R, chunks: Vec<Chunk>, is_compressed: bool) -> Self {
+        Self {
+            reader,
+            chunks: chunks.into_iter(),
+            is_compressed,
+            src: Vec::new(),
+            dst: Vec::new(),
+        }
+    }
+
+    /// Reads and, if necessary, decompresses the next data block.
+    pub(super) fn next_block(&mut self) -> io::Result<Option<&[u8]>> {
+        let Some(chunk) = self.chunks.next() else {
+            return Ok(None);
+        };
+
+        let size = usize::try_from(chunk.size)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        self.reader.seek(SeekFrom::Start(chunk.offset))?;
+
+        self.src.resize(size, 0);
+        self.reader.read_exact(&mut self.src)?;
+
+        if self.is_compressed {
+            self.dst.clear();
+            let mut decoder = ZlibDecoder::new(&self.src[..]);
+            decoder.read_to_end(&mut self.dst)?;
+            Ok(Some(&self.dst))
+        } else {
+            Ok(Some(&self.src))
+        }
+    }
+}
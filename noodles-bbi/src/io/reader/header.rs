@@ -0,0 +1,178 @@
+mod reference_sequences;
+
+use std::io::{self, Read, Seek, SeekFrom};
+
+use bstr::BString;
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use self::reference_sequences::read_reference_sequences;
+use crate::{
+    header::{Kind, ZoomHeader},
+    Header, Summary,
+};
+
+const BIGWIG_MAGIC_NUMBER: u32 = 0x888ffc26;
+const BIGBED_MAGIC_NUMBER: u32 = 0x8789f2eb;
+
+pub(super) fn read_header<R>(reader: &mut R) -> io::Result<Header>
+where
+    R: Read + Seek,
+{
+    reader.seek(SeekFrom::Start(0))?;
+
+    let kind = read_magic_number(reader)?;
+    let version = reader.read_u16::<LittleEndian>()?;
+    let zoom_level_count = reader.read_u16::<LittleEndian>()?;
+    let chromosome_tree_offset = reader.read_u64::<LittleEndian>()?;
+    let full_data_offset = reader.read_u64::<LittleEndian>()?;
+    let full_index_offset = reader.read_u64::<LittleEndian>()?;
+    let field_count = reader.read_u16::<LittleEndian>()?;
+    let defined_field_count = reader.read_u16::<LittleEndian>()?;
+    let auto_sql_offset = reader.read_u64::<LittleEndian>()?;
+    let total_summary_offset = reader.read_u64::<LittleEndian>()?;
+    let uncompressed_buffer_size = reader.read_u32::<LittleEndian>()?;
+    let _extension_offset = reader.read_u64::<LittleEndian>()?;
+
+    let zoom_headers = (0..zoom_level_count)
+        .map(|_| read_zoom_header(reader))
+        .collect::<io::Result<_>>()?;
+
+    let auto_sql = if auto_sql_offset == 0 {
+        None
+    } else {
+        reader.seek(SeekFrom::Start(auto_sql_offset))?;
+        read_c_string(reader).map(Some)?
+    };
+
+    let total_summary = if total_summary_offset == 0 {
+        None
+    } else {
+        reader.seek(SeekFrom::Start(total_summary_offset))?;
+        read_total_summary(reader).map(Some)?
+    };
+
+    let reference_sequences = read_reference_sequences(reader, chromosome_tree_offset)?;
+
+    Ok(Header {
+        kind,
+        version,
+        zoom_headers,
+        full_data_offset,
+        full_index_offset,
+        field_count,
+        defined_field_count,
+        auto_sql,
+        total_summary,
+        uncompressed_buffer_size,
+        reference_sequences,
+    })
+}
+
+fn read_magic_number<R>(reader: &mut R) -> io::Result<Kind>
+where
+    R: Read,
+{
+    match reader.read_u32::<LittleEndian>()? {
+        BIGWIG_MAGIC_NUMBER => Ok(Kind::BigWig),
+        BIGBED_MAGIC_NUMBER => Ok(Kind::BigBed),
+        n if n.swap_bytes() == BIGWIG_MAGIC_NUMBER || n.swap_bytes() == BIGBED_MAGIC_NUMBER => {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "big-endian BBI files are not supported",
+            ))
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid BBI header",
+        )),
+    }
+}
+
+fn read_zoom_header<R>(reader: &mut R) -> io::Result<ZoomHeader>
+where
+    R: Read,
+{
+    let reduction_level = reader.read_u32::<LittleEndian>()?;
+    let _reserved = reader.read_u32::<LittleEndian>()?;
+    let data_offset = reader.read_u64::<LittleEndian>()?;
+    let index_offset = reader.read_u64::<LittleEndian>()?;
+
+    Ok(ZoomHeader {
+        reduction_level,
+        data_offset,
+        index_offset,
+    })
+}
+
+fn read_total_summary<R>(reader: &mut R) -> io::Result<Summary>
+where
+    R: Read,
+{
+    let bases_covered = reader.read_u64::<LittleEndian>()?;
+    let min = reader.read_f64::<LittleEndian>()?;
+    let max = reader.read_f64::<LittleEndian>()?;
+    let sum = reader.read_f64::<LittleEndian>()?;
+    let sum_squares = reader.read_f64::<LittleEndian>()?;
+
+    Ok(Summary::new(bases_covered, min, max, sum, sum_squares))
+}
+
+fn read_c_string<R>(reader: &mut R) -> io::Result<BString>
+where
+    R: Read,
+{
+    const NUL: u8 = 0x00;
+
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_u8()? {
+            NUL => break,
+            b => buf.push(b),
+        }
+    }
+
+    Ok(buf.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_magic_number() -> io::Result<()> {
+        let mut src = &BIGWIG_MAGIC_NUMBER.to_le_bytes()[..];
+        assert_eq!(read_magic_number(&mut src)?, Kind::BigWig);
+
+        let mut src = &BIGBED_MAGIC_NUMBER.to_le_bytes()[..];
+        assert_eq!(read_magic_number(&mut src)?, Kind::BigBed);
+
+        let mut src = &BIGWIG_MAGIC_NUMBER.to_be_bytes()[..];
+        assert!(matches!(
+            read_magic_number(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut src = &b"BBI\x00"[..];
+        assert!(matches!(
+            read_magic_number(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_c_string() -> io::Result<()> {
+        let mut src = &b"table bed\x00"[..];
+        assert_eq!(read_c_string(&mut src)?, "table bed");
+
+        let mut src = &b"table bed"[..];
+        assert!(matches!(
+            read_c_string(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use std::io::{self, Read, Seek, SeekFrom};
+
+use bstr::BString;
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::header::ReferenceSequences;
+
+const MAGIC_NUMBER: u32 = 0x78ca8c91;
+const HEADER_SIZE: u64 = 32;
+
+/// Reads the reference sequences from the chromosome B+ tree.
+pub(super) fn read_reference_sequences<R>(
+    reader: &mut R,
+    offset: u64,
+) -> io::Result<ReferenceSequences>
+where
+    R: Read + Seek,
+{
+    reader.seek(SeekFrom::Start(offset))?;
+
+    if reader.read_u32::<LittleEndian>()? != MAGIC_NUMBER {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid chromosome B+ tree header",
+        ));
+    }
+
+    let _block_size = reader.read_u32::<LittleEndian>()?;
+
+    let key_size = reader.read_u32::<LittleEndian>().and_then(|n| {
+        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    let value_size = reader.read_u32::<LittleEndian>()?;
+
+    if value_size != 8 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid chromosome B+ tree value size",
+        ));
+    }
+
+    let item_count = reader.read_u64::<LittleEndian>()?;
+    let _reserved = reader.read_u64::<LittleEndian>()?;
+
+    let mut entries = Vec::new();
+    let mut node_offsets = vec![offset + HEADER_SIZE];
+    let mut key = vec![0; key_size];
+
+    while let Some(node_offset) = node_offsets.pop() {
+        reader.seek(SeekFrom::Start(node_offset))?;
+
+        let is_leaf = reader.read_u8()? != 0;
+        let _reserved = reader.read_u8()?;
+        let count = reader.read_u16::<LittleEndian>()?;
+
+        for _ in 0..count {
+            reader.read_exact(&mut key)?;
+
+            if is_leaf {
+                let id = reader.read_u32::<LittleEndian>()?;
+                let length = reader.read_u32::<LittleEndian>()?;
+                entries.push((id, parse_key(&key), length));
+            } else {
+                let child_offset = reader.read_u64::<LittleEndian>()?;
+                node_offsets.push(child_offset);
+            }
+        }
+    }
+
+    if entries.len() as u64 != item_count {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "chromosome B+ tree item count mismatch",
+        ));
+    }
+
+    entries.sort_unstable_by_key(|(id, _, _)| *id);
+
+    let mut reference_sequences = ReferenceSequences::with_capacity(entries.len());
+
+    for (i, (id, name, length)) in entries.into_iter().enumerate() {
+        if usize::try_from(id).ok() != Some(i) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid chromosome ID",
+            ));
+        }
+
+        if reference_sequences.insert(name, length).is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "duplicate chromosome name",
+            ));
+        }
+    }
+
+    Ok(reference_sequences)
+}
+
+fn parse_key(buf: &[u8]) -> BString {
+    const NUL: u8 = 0x00;
+
+    // Keys are padded with NULs to the key size.
+    let len = buf.iter().position(|&b| b == NUL).unwrap_or(buf.len());
+    BString::from(&buf[..len])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_read_reference_sequences() -> io::Result<()> {
+        let mut buf = Vec::new();
+
+        // header
+        buf.extend(MAGIC_NUMBER.to_le_bytes()); // magic
+        buf.extend(2u32.to_le_bytes()); // blockSize
+        buf.extend(4u32.to_le_bytes()); // keySize
+        buf.extend(8u32.to_le_bytes()); // valSize
+        buf.extend(2u64.to_le_bytes()); // itemCount
+        buf.extend(0u64.to_le_bytes()); // reserved
+
+        // leaf node
+        buf.extend([1, 0]); // isLeaf, reserved
+        buf.extend(2u16.to_le_bytes()); // count
+        buf.extend(b"sq0\x00"); // key
+        buf.extend(1u32.to_le_bytes()); // chromId
+        buf.extend(8u32.to_le_bytes()); // chromSize
+        buf.extend(b"sq10"); // key
+        buf.extend(0u32.to_le_bytes()); // chromId
+        buf.extend(13u32.to_le_bytes()); // chromSize
+
+        let mut reader = Cursor::new(buf);
+        let actual = read_reference_sequences(&mut reader, 0)?;
+
+        let expected: ReferenceSequences = [(BString::from("sq10"), 13), (BString::from("sq0"), 8)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
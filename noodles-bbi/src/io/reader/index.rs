@@ -0,0 +1,74 @@
+use std::io::{self, Read, Seek, SeekFrom};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use super::query::Target;
+
+const MAGIC_NUMBER: u32 = 0x2468ace0;
+const HEADER_SIZE: u64 = 48;
+
+/// A compressed data block in the file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) struct Chunk {
+    pub(super) offset: u64,
+    pub(super) size: u64,
+}
+
+/// Searches the R-tree index at the given offset for data blocks that intersect the target.
+pub(super) fn query<R>(reader: &mut R, offset: u64, target: Target) -> io::Result<Vec<Chunk>>
+where
+    R: Read + Seek,
+{
+    reader.seek(SeekFrom::Start(offset))?;
+
+    if reader.read_u32::<LittleEndian>()? != MAGIC_NUMBER {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid R-tree index header",
+        ));
+    }
+
+    let mut chunks = Vec::new();
+    let mut node_offsets = vec![offset + HEADER_SIZE];
+
+    while let Some(node_offset) = node_offsets.pop() {
+        reader.seek(SeekFrom::Start(node_offset))?;
+
+        let is_leaf = reader.read_u8()? != 0;
+        let _reserved = reader.read_u8()?;
+        let count = reader.read_u16::<LittleEndian>()?;
+
+        for _ in 0..count {
+            let start_reference_sequence_id = reader.read_u32::<LittleEndian>()?;
+            let start = reader.read_u32::<LittleEndian>()?;
+            let end_reference_sequence_id = reader.read_u32::<LittleEndian>()?;
+            let end = reader.read_u32::<LittleEndian>()?;
+            let data_offset = reader.read_u64::<LittleEndian>()?;
+
+            let size = if is_leaf {
+                reader.read_u64::<LittleEndian>().map(Some)?
+            } else {
+                None
+            };
+
+            if !target.intersects_span(
+                (start_reference_sequence_id, start),
+                (end_reference_sequence_id, end),
+            ) {
+                continue;
+            }
+
+            match size {
+                Some(size) => chunks.push(Chunk {
+                    offset: data_offset,
+                    size,
+                }),
+                None => node_offsets.push(data_offset),
+            }
+        }
+    }
+
+    chunks.sort_unstable_by_key(|chunk| chunk.offset);
+
+    Ok(chunks)
+}
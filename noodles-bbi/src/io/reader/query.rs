@@ -0,0 +1,164 @@
+//! BBI reader queries.
+
+mod bigbed;
+mod bigwig;
+mod zoom;
+
+use std::io;
+
+use noodles_core::{Position, Region};
+
+pub use self::{bigbed::BigBedQuery, bigwig::BigWigQuery, zoom::ZoomQuery};
+use crate::Header;
+
+/// A query target, i.e., a resolved region.
+///
+/// The interval is 0-based, half-open.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Target {
+    reference_sequence_id: u32,
+    start: u32,
+    end: u32,
+}
+
+impl Target {
+    pub(super) fn try_new(header: &Header, region: &Region) -> io::Result<Self> {
+        let (i, _, length) = header
+            .reference_sequences()
+            .get_full(region.name())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid reference sequence name: {}", region.name()),
+                )
+            })?;
+
+        let reference_sequence_id =
+            u32::try_from(i).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let interval = region.interval();
+
+        let start = match interval.start() {
+            Some(position) => u32::try_from(usize::from(position) - 1)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+            None => 0,
+        };
+
+        let end = match interval.end() {
+            Some(position) => u32::try_from(usize::from(position))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+            None => *length,
+        };
+
+        Ok(Self {
+            reference_sequence_id,
+            start,
+            end,
+        })
+    }
+
+    fn intersects(&self, reference_sequence_id: u32, start: u32, end: u32) -> bool {
+        reference_sequence_id == self.reference_sequence_id && start < self.end && end > self.start
+    }
+
+    /// Returns whether the target intersects a span that can cross reference sequences.
+    pub(super) fn intersects_span(&self, start: (u32, u32), end: (u32, u32)) -> bool {
+        start < (self.reference_sequence_id, self.end)
+            && end > (self.reference_sequence_id, self.start)
+    }
+}
+
+fn parse_start(start: u32) -> io::Result<Position> {
+    usize::try_from(start)
+        .ok()
+        .and_then(|n| n.checked_add(1))
+        .and_then(Position::new)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid start"))
+}
+
+fn parse_interval(start: u32, end: u32) -> io::Result<(Position, Position)> {
+    let start = parse_start(start)?;
+
+    let end = usize::try_from(end)
+        .ok()
+        .and_then(Position::new)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid end"))?;
+
+    Ok((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use bstr::BString;
+
+    use super::*;
+    use crate::header::{Kind, ReferenceSequences};
+
+    fn build_header(reference_sequences: ReferenceSequences) -> Header {
+        Header {
+            kind: Kind::BigWig,
+            version: 4,
+            zoom_headers: Vec::new(),
+            full_data_offset: 0,
+            full_index_offset: 0,
+            field_count: 0,
+            defined_field_count: 0,
+            auto_sql: None,
+            total_summary: None,
+            uncompressed_buffer_size: 0,
+            reference_sequences,
+        }
+    }
+
+    #[test]
+    fn test_target_try_new() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header(
+            [(BString::from("sq0"), 8), (BString::from("sq1"), 13)]
+                .into_iter()
+                .collect(),
+        );
+
+        let region = "sq1".parse()?;
+        assert_eq!(
+            Target::try_new(&header, &region)?,
+            Target {
+                reference_sequence_id: 1,
+                start: 0,
+                end: 13,
+            }
+        );
+
+        let region = "sq1:5-8".parse()?;
+        assert_eq!(
+            Target::try_new(&header, &region)?,
+            Target {
+                reference_sequence_id: 1,
+                start: 4,
+                end: 8,
+            }
+        );
+
+        let region = "sq2".parse()?;
+        assert!(matches!(
+            Target::try_new(&header, &region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_target_intersects_span() {
+        let target = Target {
+            reference_sequence_id: 1,
+            start: 4,
+            end: 8,
+        };
+
+        assert!(target.intersects_span((1, 0), (1, 5)));
+        assert!(target.intersects_span((0, 100), (2, 0)));
+        assert!(!target.intersects_span((1, 0), (1, 4)));
+        assert!(!target.intersects_span((1, 8), (1, 13)));
+        assert!(!target.intersects_span((0, 0), (0, 100)));
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    io::{self, Read, Seek},
+    vec,
+};
+
+use bstr::{BString, ByteSlice};
+use byteorder::{LittleEndian, ReadBytesExt};
+use noodles_bed::{self as bed, feature::record_buf::other_fields::Value};
+use noodles_core::Position;
+
+use super::{parse_start, Target};
+use crate::{header::ReferenceSequences, io::reader::blocks::Blocks};
+
+type Record = bed::feature::RecordBuf<3>;
+
+/// An iterator over bigBed records that intersect a given region.
+///
+/// This is created by calling [`super::super::Reader::query_bigbed`].
+pub struct BigBedQuery<'r, R> {
+    blocks: Blocks<'r, R>,
+    reference_sequences: &'r ReferenceSequences,
+    target: Target,
+    records: vec::IntoIter<Record>,
+}
+
+impl<'r, R> BigBedQuery<'r, R>
+where
+    R: Read + Seek,
+{
+    pub(in crate::io::reader) fn new(
+        blocks: Blocks<'r, R>,
+        reference_sequences: &'r ReferenceSequences,
+        target: Target,
+    ) -> Self {
+        Self {
+            blocks,
+            reference_sequences,
+            target,
+            records: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<R> Iterator for BigBedQuery<'_, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.records.next() {
+                return Some(Ok(record));
+            }
+
+            let mut records = Vec::new();
+
+            match self.blocks.next_block() {
+                Ok(Some(src)) => {
+                    if let Err(e) =
+                        read_block(src, self.reference_sequences, self.target, &mut records)
+                    {
+                        return Some(Err(e));
+                    }
+                }
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+
+            self.records = records.into_iter();
+        }
+    }
+}
+
+fn read_block(
+    mut src: &[u8],
+    reference_sequences: &ReferenceSequences,
+    target: Target,
+    dst: &mut Vec<Record>,
+) -> io::Result<()> {
+    const NUL: u8 = 0x00;
+
+    while !src.is_empty() {
+        let reference_sequence_id = src.read_u32::<LittleEndian>()?;
+        let start = src.read_u32::<LittleEndian>()?;
+        let end = src.read_u32::<LittleEndian>()?;
+
+        let i = src
+            .iter()
+            .position(|&b| b == NUL)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "unterminated record"))?;
+
+        let (rest, remaining) = src.split_at(i);
+        src = &remaining[1..];
+
+        if !target.intersects(reference_sequence_id, start, end) {
+            continue;
+        }
+
+        let (name, _) = usize::try_from(reference_sequence_id)
+            .ok()
+            .and_then(|j| reference_sequences.get_index(j))
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence ID")
+            })?;
+
+        let feature_start = parse_start(start)?;
+
+        let mut builder = Record::builder()
+            .set_reference_sequence_name(name.clone())
+            .set_feature_start(feature_start);
+
+        // A zero-length feature (e.g., an insertion point) at the start of a reference sequence
+        // has a feature end of 0, which is represented as missing.
+        if let Some(feature_end) = usize::try_from(end).ok().and_then(Position::new) {
+            builder = builder.set_feature_end(feature_end);
+        }
+
+        if !rest.is_empty() {
+            let values: Vec<_> = rest
+                .split_str("\t")
+                .map(|field| Value::String(BString::from(field)))
+                .collect();
+
+            builder = builder.set_other_fields(values.into());
+        }
+
+        dst.push(builder.build());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_block() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences: ReferenceSequences =
+            [(BString::from("sq0"), 100)].into_iter().collect();
+
+        let target = Target {
+            reference_sequence_id: 0,
+            start: 15,
+            end: 35,
+        };
+
+        let mut src = Vec::new();
+
+        for (start, end, rest) in [
+            (10u32, 20u32, &b"ndls0\t0\t+"[..]),
+            (20, 30, b""),
+            (40, 50, b"ndls2"),
+        ] {
+            src.extend(0u32.to_le_bytes());
+            src.extend(start.to_le_bytes());
+            src.extend(end.to_le_bytes());
+            src.extend(rest);
+            src.push(0x00);
+        }
+
+        let mut records = Vec::new();
+        read_block(&src, &reference_sequences, target, &mut records)?;
+
+        let expected = [
+            Record::builder()
+                .set_reference_sequence_name("sq0")
+                .set_feature_start(Position::try_from(11)?)
+                .set_feature_end(Position::try_from(20)?)
+                .set_other_fields(
+                    vec![
+                        Value::String(BString::from("ndls0")),
+                        Value::String(BString::from("0")),
+                        Value::String(BString::from("+")),
+                    ]
+                    .into(),
+                )
+                .build(),
+            Record::builder()
+                .set_reference_sequence_name("sq0")
+                .set_feature_start(Position::try_from(21)?)
+                .set_feature_end(Position::try_from(30)?)
+                .build(),
+        ];
+
+        assert_eq!(records, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    io::{self, Read, Seek},
+    vec,
+};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use super::{parse_interval, Target};
+use crate::{bigwig::Record, io::reader::blocks::Blocks};
+
+/// An iterator over bigWig records that intersect a given region.
+///
+/// This is created by calling [`super::super::Reader::query_bigwig`].
+pub struct BigWigQuery<'r, R> {
+    blocks: Blocks<'r, R>,
+    target: Target,
+    records: vec::IntoIter<Record>,
+}
+
+impl<'r, R> BigWigQuery<'r, R>
+where
+    R: Read + Seek,
+{
+    pub(in crate::io::reader) fn new(blocks: Blocks<'r, R>, target: Target) -> Self {
+        Self {
+            blocks,
+            target,
+            records: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<R> Iterator for BigWigQuery<'_, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.records.next() {
+                return Some(Ok(record));
+            }
+
+            let mut records = Vec::new();
+
+            match self.blocks.next_block() {
+                Ok(Some(src)) => {
+                    if let Err(e) = read_block(src, self.target, &mut records) {
+                        return Some(Err(e));
+                    }
+                }
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+
+            self.records = records.into_iter();
+        }
+    }
+}
+
+fn read_block(mut src: &[u8], target: Target, dst: &mut Vec<Record>) -> io::Result<()> {
+    while !src.is_empty() {
+        read_section(&mut src, target, dst)?;
+    }
+
+    Ok(())
+}
+
+fn read_section(src: &mut &[u8], target: Target, dst: &mut Vec<Record>) -> io::Result<()> {
+    const BED_GRAPH: u8 = 1;
+    const VARIABLE_STEP: u8 = 2;
+    const FIXED_STEP: u8 = 3;
+
+    let reference_sequence_id = src.read_u32::<LittleEndian>()?;
+    let section_start = src.read_u32::<LittleEndian>()?;
+    let _section_end = src.read_u32::<LittleEndian>()?;
+    let item_step = src.read_u32::<LittleEndian>()?;
+    let item_span = src.read_u32::<LittleEndian>()?;
+    let ty = src.read_u8()?;
+    let _reserved = src.read_u8()?;
+    let item_count = src.read_u16::<LittleEndian>()?;
+
+    for i in 0..u32::from(item_count) {
+        let (start, end) = match ty {
+            BED_GRAPH => {
+                let start = src.read_u32::<LittleEndian>()?;
+                let end = src.read_u32::<LittleEndian>()?;
+                (start, end)
+            }
+            VARIABLE_STEP => {
+                let start = src.read_u32::<LittleEndian>()?;
+                (start, add_span(start, item_span)?)
+            }
+            FIXED_STEP => {
+                let start = i
+                    .checked_mul(item_step)
+                    .and_then(|n| n.checked_add(section_start))
+                    .ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidData, "invalid item start")
+                    })?;
+
+                (start, add_span(start, item_span)?)
+            }
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid section type: {ty}"),
+                ))
+            }
+        };
+
+        let value = src.read_f32::<LittleEndian>()?;
+
+        if target.intersects(reference_sequence_id, start, end) {
+            let (start, end) = parse_interval(start, end)?;
+
+            dst.push(Record {
+                reference_sequence_id: reference_sequence_id as usize,
+                start,
+                end,
+                value,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn add_span(start: u32, span: u32) -> io::Result<u32> {
+    start
+        .checked_add(span)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid item end"))
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    fn build_section(
+        ty: u8,
+        start: u32,
+        step: u32,
+        span: u32,
+        items: &[(u32, u32, f32)],
+    ) -> Vec<u8> {
+        let mut buf = Vec::new();
+
+        buf.extend(0u32.to_le_bytes()); // chromId
+        buf.extend(start.to_le_bytes()); // chromStart
+        buf.extend(0u32.to_le_bytes()); // chromEnd
+        buf.extend(step.to_le_bytes()); // itemStep
+        buf.extend(span.to_le_bytes()); // itemSpan
+        buf.extend([ty, 0]); // type, reserved
+        buf.extend((items.len() as u16).to_le_bytes()); // itemCount
+
+        for &(start, end, value) in items {
+            match ty {
+                1 => {
+                    buf.extend(start.to_le_bytes());
+                    buf.extend(end.to_le_bytes());
+                }
+                2 => buf.extend(start.to_le_bytes()),
+                _ => {}
+            }
+
+            buf.extend(value.to_le_bytes());
+        }
+
+        buf
+    }
+
+    #[test]
+    fn test_read_block() -> Result<(), Box<dyn std::error::Error>> {
+        let target = Target {
+            reference_sequence_id: 0,
+            start: 15,
+            end: 35,
+        };
+
+        let expected = [
+            Record {
+                reference_sequence_id: 0,
+                start: Position::try_from(11)?,
+                end: Position::try_from(20)?,
+                value: 1.0,
+            },
+            Record {
+                reference_sequence_id: 0,
+                start: Position::try_from(31)?,
+                end: Position::try_from(40)?,
+                value: 3.0,
+            },
+        ];
+
+        let items = [(10, 20, 1.0), (20, 30, 2.0), (30, 40, 3.0), (40, 50, 4.0)];
+
+        // bedGraph
+        let src = build_section(1, 10, 0, 0, &[items[0], items[2], items[3]]);
+        let mut records = Vec::new();
+        read_block(&src, target, &mut records)?;
+        assert_eq!(records, expected);
+
+        // variableStep
+        let src = build_section(2, 10, 0, 10, &[items[0], items[2], items[3]]);
+        records.clear();
+        read_block(&src, target, &mut records)?;
+        assert_eq!(records, expected);
+
+        // fixedStep
+        let src = build_section(3, 10, 20, 10, &[items[0], items[2], items[3]]);
+        records.clear();
+        read_block(&src, target, &mut records)?;
+        assert_eq!(records, expected);
+
+        let src = build_section(4, 10, 0, 0, &[items[0]]);
+        assert!(matches!(
+            read_block(&src, target, &mut records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use std::{
+    io::{self, Read, Seek},
+    vec,
+};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use super::{parse_interval, Target};
+use crate::{io::reader::blocks::Blocks, zoom::Record, Summary};
+
+/// An iterator over zoom records that intersect a given region.
+///
+/// This is created by calling [`super::super::Reader::query_zoom`].
+pub struct ZoomQuery<'r, R> {
+    blocks: Blocks<'r, R>,
+    target: Target,
+    records: vec::IntoIter<Record>,
+}
+
+impl<'r, R> ZoomQuery<'r, R>
+where
+    R: Read + Seek,
+{
+    pub(in crate::io::reader) fn new(blocks: Blocks<'r, R>, target: Target) -> Self {
+        Self {
+            blocks,
+            target,
+            records: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<R> Iterator for ZoomQuery<'_, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.records.next() {
+                return Some(Ok(record));
+            }
+
+            let mut records = Vec::new();
+
+            match self.blocks.next_block() {
+                Ok(Some(src)) => {
+                    if let Err(e) = read_block(src, self.target, &mut records) {
+                        return Some(Err(e));
+                    }
+                }
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+
+            self.records = records.into_iter();
+        }
+    }
+}
+
+fn read_block(mut src: &[u8], target: Target, dst: &mut Vec<Record>) -> io::Result<()> {
+    while !src.is_empty() {
+        let reference_sequence_id = src.read_u32::<LittleEndian>()?;
+        let start = src.read_u32::<LittleEndian>()?;
+        let end = src.read_u32::<LittleEndian>()?;
+        let valid_count = src.read_u32::<LittleEndian>()?;
+        let min = src.read_f32::<LittleEndian>()?;
+        let max = src.read_f32::<LittleEndian>()?;
+        let sum = src.read_f32::<LittleEndian>()?;
+        let sum_squares = src.read_f32::<LittleEndian>()?;
+
+        if target.intersects(reference_sequence_id, start, end) {
+            let (start, end) = parse_interval(start, end)?;
+
+            dst.push(Record {
+                reference_sequence_id: reference_sequence_id as usize,
+                start,
+                end,
+                summary: Summary::new(
+                    u64::from(valid_count),
+                    f64::from(min),
+                    f64::from(max),
+                    f64::from(sum),
+                    f64::from(sum_squares),
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn test_read_block() -> Result<(), Box<dyn std::error::Error>> {
+        let target = Target {
+            reference_sequence_id: 1,
+            start: 0,
+            end: 100,
+        };
+
+        let mut src = Vec::new();
+
+        for id in [0u32, 1] {
+            src.extend(id.to_le_bytes()); // chromId
+            src.extend(0u32.to_le_bytes()); // start
+            src.extend(50u32.to_le_bytes()); // end
+            src.extend(25u32.to_le_bytes()); // validCount
+            src.extend(1.0f32.to_le_bytes()); // minVal
+            src.extend(3.0f32.to_le_bytes()); // maxVal
+            src.extend(50.0f32.to_le_bytes()); // sumData
+            src.extend(125.0f32.to_le_bytes()); // sumSquares
+        }
+
+        let mut records = Vec::new();
+        read_block(&src, target, &mut records)?;
+
+        assert_eq!(
+            records,
+            [Record {
+                reference_sequence_id: 1,
+                start: Position::MIN,
+                end: Position::try_from(50)?,
+                summary: Summary::new(25, 1.0, 3.0, 50.0, 125.0),
+            }]
+        );
+
+        Ok(())
+    }
+}
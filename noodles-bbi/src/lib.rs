@@ -0,0 +1,14 @@
+#![warn(missing_docs)]
+
+//! **noodles-bbi** handles the reading of the big binary indexed (BBI) formats, bigBed and
+//! bigWig.
+//!
+//! Only little-endian files are supported.
+
+pub mod bigwig;
+pub mod header;
+pub mod io;
+mod summary;
+pub mod zoom;
+
+pub use self::{header::Header, summary::Summary};
@@ -0,0 +1,119 @@
+/// A BBI data summary.
+///
+/// This is used for both the total summary of a file and the summaries of zoom records.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Summary {
+    pub(crate) bases_covered: u64,
+    pub(crate) min: f64,
+    pub(crate) max: f64,
+    pub(crate) sum: f64,
+    pub(crate) sum_squares: f64,
+}
+
+impl Summary {
+    /// Creates a summary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bbi as bbi;
+    /// let summary = bbi::Summary::new(2, 1.0, 3.0, 4.0, 10.0);
+    /// ```
+    pub fn new(bases_covered: u64, min: f64, max: f64, sum: f64, sum_squares: f64) -> Self {
+        Self {
+            bases_covered,
+            min,
+            max,
+            sum,
+            sum_squares,
+        }
+    }
+
+    /// Returns the number of bases with data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bbi as bbi;
+    /// let summary = bbi::Summary::new(2, 1.0, 3.0, 4.0, 10.0);
+    /// assert_eq!(summary.bases_covered(), 2);
+    /// ```
+    pub fn bases_covered(&self) -> u64 {
+        self.bases_covered
+    }
+
+    /// Returns the minimum value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bbi as bbi;
+    /// let summary = bbi::Summary::new(2, 1.0, 3.0, 4.0, 10.0);
+    /// assert_eq!(summary.min(), 1.0);
+    /// ```
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the maximum value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bbi as bbi;
+    /// let summary = bbi::Summary::new(2, 1.0, 3.0, 4.0, 10.0);
+    /// assert_eq!(summary.max(), 3.0);
+    /// ```
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Returns the sum of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bbi as bbi;
+    /// let summary = bbi::Summary::new(2, 1.0, 3.0, 4.0, 10.0);
+    /// assert_eq!(summary.sum(), 4.0);
+    /// ```
+    pub fn sum(&self) -> f64 {
+        self.sum
+    }
+
+    /// Returns the sum of squares of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bbi as bbi;
+    /// let summary = bbi::Summary::new(2, 1.0, 3.0, 4.0, 10.0);
+    /// assert_eq!(summary.sum_squares(), 10.0);
+    /// ```
+    pub fn sum_squares(&self) -> f64 {
+        self.sum_squares
+    }
+
+    /// Returns the mean value.
+    ///
+    /// This returns `None` if no bases are covered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bbi as bbi;
+    ///
+    /// let summary = bbi::Summary::new(2, 1.0, 3.0, 4.0, 10.0);
+    /// assert_eq!(summary.mean(), Some(2.0));
+    ///
+    /// let summary = bbi::Summary::default();
+    /// assert!(summary.mean().is_none());
+    /// ```
+    pub fn mean(&self) -> Option<f64> {
+        if self.bases_covered == 0 {
+            None
+        } else {
+            Some(self.sum / self.bases_covered as f64)
+        }
+    }
+}
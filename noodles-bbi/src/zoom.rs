@@ -0,0 +1,5 @@
+//! BBI zoom levels.
+
+mod record;
+
+pub use self::record::Record;
@@ -0,0 +1,41 @@
+use noodles_core::{region::Interval, Position};
+
+use crate::Summary;
+
+/// A BBI zoom record.
+///
+/// This is a precomputed summary over an interval of a reference sequence.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Record {
+    pub(crate) reference_sequence_id: usize,
+    pub(crate) start: Position,
+    pub(crate) end: Position,
+    pub(crate) summary: Summary,
+}
+
+impl Record {
+    /// Returns the reference sequence ID.
+    pub fn reference_sequence_id(&self) -> usize {
+        self.reference_sequence_id
+    }
+
+    /// Returns the start position.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position.
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the interval.
+    pub fn interval(&self) -> Interval {
+        (self.start..=self.end).into()
+    }
+
+    /// Returns the summary.
+    pub fn summary(&self) -> &Summary {
+        &self.summary
+    }
+}
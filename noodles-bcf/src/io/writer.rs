@@ -226,4 +226,43 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_variant_record_with_telomeric_start() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use noodles_vcf::{
+            header::record::value::{map::Contig, Map},
+            variant::{io::Write, record_buf::AlternateBases, RecordBuf},
+        };
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        let mut record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_reference_bases("N")
+            .set_alternate_bases(AlternateBases::from(vec![String::from(".[sq0:5[")]))
+            .build();
+
+        *record.variant_start_mut() = None;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_variant_record(&header, &record)?;
+        let buf = writer.into_inner().finish()?;
+
+        let mut reader = crate::io::Reader::new(&buf[..]);
+        let header = reader.read_header()?;
+        let mut record = crate::Record::default();
+        reader.read_record(&mut record)?;
+
+        assert!(record.variant_start().is_none());
+        assert_eq!(record.end()?, Position::MIN);
+
+        let record = RecordBuf::try_from_variant_record(&header, &record)?;
+        assert!(record.variant_start().is_none());
+
+        Ok(())
+    }
 }
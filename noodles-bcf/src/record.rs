@@ -112,7 +112,8 @@ impl Record {
 
     /// Returns the end position of this record.
     ///
-    /// This position is 1-based, inclusive.
+    /// This position is 1-based, inclusive. If the record represents the start of a telomeric
+    /// breakend, i.e., it has no start position, its span is counted from the first position.
     ///
     /// # Examples
     ///
@@ -124,12 +125,14 @@ impl Record {
     /// # Ok::<_, std::io::Error>(())
     /// ```
     pub fn end(&self) -> io::Result<Position> {
-        let Some(start) = self.variant_start().transpose()? else {
-            todo!();
-        };
+        let start = self.variant_start().transpose()?.unwrap_or(Position::MIN);
 
         let len = self.rlen()?;
 
+        if len == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid rlen"));
+        }
+
         start.checked_add(len - 1).ok_or_else(|| {
             io::Error::new(
                 io::ErrorKind::InvalidData,
@@ -294,3 +297,35 @@ impl vcf::variant::Record for Record {
             .map(|samples| Box::new(samples) as Box<dyn vcf::variant::record::Samples>)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_end() -> Result<(), Box<dyn std::error::Error>> {
+        fn set_site(record: &mut Record, pos: i32, rlen: i32) {
+            let site_buf = record.fields_mut().site_buf_mut();
+            site_buf[4..8].copy_from_slice(&pos.to_le_bytes());
+            site_buf[8..12].copy_from_slice(&rlen.to_le_bytes());
+        }
+
+        let mut record = Record::default();
+
+        set_site(&mut record, 7, 6);
+        assert_eq!(record.end()?, Position::try_from(13)?);
+
+        // telomeric breakend (VCF `POS` = 0)
+        set_site(&mut record, -1, 1);
+        assert!(record.variant_start().is_none());
+        assert_eq!(record.end()?, Position::MIN);
+
+        set_site(&mut record, 7, 0);
+        assert!(matches!(
+            record.end(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -7,7 +7,7 @@ use noodles_vcf::{
 
 use crate::record::codec::{
     decoder::value,
-    value::{Array, Float, Int16, Int32, Int8},
+    value::{array::Values, Array, Float, Int16, Int32, Int8},
     Value,
 };
 
@@ -43,9 +43,12 @@ pub(super) fn read_value(
 fn resolve_integer_value(value: Option<Value<'_>>) -> Result<Option<ValueBuf>, DecodeError> {
     match value {
         None
-        | Some(Value::Int8(None | Some(Int8::Missing)))
-        | Some(Value::Int16(None | Some(Int16::Missing)))
-        | Some(Value::Int32(None | Some(Int32::Missing))) => Ok(None),
+        | Some(Value::Int8(None | Some(Int8::Missing | Int8::EndOfVector)))
+        | Some(Value::Int16(None | Some(Int16::Missing | Int16::EndOfVector)))
+        | Some(Value::Int32(None | Some(Int32::Missing | Int32::EndOfVector))) => Ok(None),
+        Some(Value::Int8(Some(Int8::Reserved(_))))
+        | Some(Value::Int16(Some(Int16::Reserved(_))))
+        | Some(Value::Int32(Some(Int32::Reserved(_)))) => Err(DecodeError::UnexpectedReservedValue),
         Some(Value::Int8(Some(Int8::Value(n)))) => Ok(Some(ValueBuf::from(i32::from(n)))),
         Some(Value::Int16(Some(Int16::Value(n)))) => Ok(Some(ValueBuf::from(i32::from(n)))),
         Some(Value::Int32(Some(Int32::Value(n)))) => Ok(Some(ValueBuf::from(n))),
@@ -56,59 +59,116 @@ fn resolve_integer_value(value: Option<Value<'_>>) -> Result<Option<ValueBuf>, D
 fn resolve_integer_array_value(value: Option<Value<'_>>) -> Result<Option<ValueBuf>, DecodeError> {
     match value {
         None
-        | Some(Value::Int8(None | Some(Int8::Missing)))
-        | Some(Value::Int16(None | Some(Int16::Missing)))
-        | Some(Value::Int32(None | Some(Int32::Missing))) => Ok(None),
+        | Some(Value::Int8(None | Some(Int8::Missing | Int8::EndOfVector)))
+        | Some(Value::Int16(None | Some(Int16::Missing | Int16::EndOfVector)))
+        | Some(Value::Int32(None | Some(Int32::Missing | Int32::EndOfVector))) => Ok(None),
+        Some(Value::Int8(Some(Int8::Reserved(_))))
+        | Some(Value::Int16(Some(Int16::Reserved(_))))
+        | Some(Value::Int32(Some(Int32::Reserved(_)))) => Err(DecodeError::UnexpectedReservedValue),
         Some(Value::Int8(Some(Int8::Value(n)))) => {
             Ok(Some(ValueBuf::from(vec![Some(i32::from(n))])))
         }
-        Some(Value::Array(Array::Int8(values))) => Ok(Some(ValueBuf::from(
-            values
-                .iter()
-                .map(|result| {
-                    result.map(Int8::from).map(|value| match value {
-                        Int8::Value(n) => Some(i32::from(n)),
-                        Int8::Missing => None,
-                        _ => todo!("unhandled i8 array value: {:?}", value),
-                    })
-                })
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|_| DecodeError::UnexpectedEof)?,
-        ))),
+        Some(Value::Array(Array::Int8(values))) => resolve_int8_array_values(values.as_ref()),
         Some(Value::Int16(Some(Int16::Value(n)))) => {
             Ok(Some(ValueBuf::from(vec![Some(i32::from(n))])))
         }
-        Some(Value::Array(Array::Int16(values))) => Ok(Some(ValueBuf::from(
-            values
-                .iter()
-                .map(|result| {
-                    result.map(Int16::from).map(|value| match value {
-                        Int16::Value(n) => Some(i32::from(n)),
-                        Int16::Missing => None,
-                        _ => todo!("unhandled i16 array value: {:?}", value),
-                    })
-                })
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|_| DecodeError::UnexpectedEof)?,
-        ))),
+        Some(Value::Array(Array::Int16(values))) => resolve_int16_array_values(values.as_ref()),
         Some(Value::Int32(Some(Int32::Value(n)))) => Ok(Some(ValueBuf::from(vec![Some(n)]))),
-        Some(Value::Array(Array::Int32(values))) => Ok(Some(ValueBuf::from(
-            values
-                .iter()
-                .map(|result| {
-                    result.map(Int32::from).map(|value| match value {
-                        Int32::Value(n) => Some(n),
-                        Int32::Missing => None,
-                        _ => todo!("unhandled i32 array value: {:?}", value),
-                    })
-                })
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|_| DecodeError::UnexpectedEof)?,
-        ))),
+        Some(Value::Array(Array::Int32(values))) => resolve_int32_array_values(values.as_ref()),
         v => Err(type_mismatch_error(v, Type::Integer)),
     }
 }
 
+fn resolve_int8_array_values(values: &dyn Values<'_, i8>) -> Result<Option<ValueBuf>, DecodeError> {
+    let mut vs = Vec::with_capacity(values.len());
+
+    for result in values.iter() {
+        match result
+            .map(Int8::from)
+            .map_err(|_| DecodeError::UnexpectedEof)?
+        {
+            Int8::Value(n) => vs.push(Some(i32::from(n))),
+            Int8::Missing => vs.push(None),
+            Int8::EndOfVector => break,
+            Int8::Reserved(_) => return Err(DecodeError::UnexpectedReservedValue),
+        }
+    }
+
+    Ok(build_array_value(vs))
+}
+
+fn resolve_int16_array_values(
+    values: &dyn Values<'_, i16>,
+) -> Result<Option<ValueBuf>, DecodeError> {
+    let mut vs = Vec::with_capacity(values.len());
+
+    for result in values.iter() {
+        match result
+            .map(Int16::from)
+            .map_err(|_| DecodeError::UnexpectedEof)?
+        {
+            Int16::Value(n) => vs.push(Some(i32::from(n))),
+            Int16::Missing => vs.push(None),
+            Int16::EndOfVector => break,
+            Int16::Reserved(_) => return Err(DecodeError::UnexpectedReservedValue),
+        }
+    }
+
+    Ok(build_array_value(vs))
+}
+
+fn resolve_int32_array_values(
+    values: &dyn Values<'_, i32>,
+) -> Result<Option<ValueBuf>, DecodeError> {
+    let mut vs = Vec::with_capacity(values.len());
+
+    for result in values.iter() {
+        match result
+            .map(Int32::from)
+            .map_err(|_| DecodeError::UnexpectedEof)?
+        {
+            Int32::Value(n) => vs.push(Some(n)),
+            Int32::Missing => vs.push(None),
+            Int32::EndOfVector => break,
+            Int32::Reserved(_) => return Err(DecodeError::UnexpectedReservedValue),
+        }
+    }
+
+    Ok(build_array_value(vs))
+}
+
+fn resolve_float_array_values(
+    values: &dyn Values<'_, f32>,
+) -> Result<Option<ValueBuf>, DecodeError> {
+    let mut vs = Vec::with_capacity(values.len());
+
+    for result in values.iter() {
+        match result
+            .map(Float::from)
+            .map_err(|_| DecodeError::UnexpectedEof)?
+        {
+            Float::Value(n) => vs.push(Some(n)),
+            Float::Missing => vs.push(None),
+            Float::EndOfVector => break,
+            Float::Reserved(_) => return Err(DecodeError::UnexpectedReservedValue),
+        }
+    }
+
+    Ok(build_array_value(vs))
+}
+
+// A vector that ends before its first value is missing.
+fn build_array_value<N>(values: Vec<Option<N>>) -> Option<ValueBuf>
+where
+    ValueBuf: From<Vec<Option<N>>>,
+{
+    if values.is_empty() {
+        None
+    } else {
+        Some(ValueBuf::from(values))
+    }
+}
+
 fn resolve_flag_value(value: Option<Value<'_>>) -> Result<Option<ValueBuf>, DecodeError> {
     match value {
         None | Some(Value::Int8(Some(Int8::Value(1)))) => Ok(Some(ValueBuf::Flag)),
@@ -118,7 +178,8 @@ fn resolve_flag_value(value: Option<Value<'_>>) -> Result<Option<ValueBuf>, Deco
 
 fn resolve_float_value(value: Option<Value<'_>>) -> Result<Option<ValueBuf>, DecodeError> {
     match value {
-        None | Some(Value::Float(None | Some(Float::Missing))) => Ok(None),
+        None | Some(Value::Float(None | Some(Float::Missing | Float::EndOfVector))) => Ok(None),
+        Some(Value::Float(Some(Float::Reserved(_)))) => Err(DecodeError::UnexpectedReservedValue),
         Some(Value::Float(Some(Float::Value(n)))) => Ok(Some(ValueBuf::from(n))),
         v => Err(type_mismatch_error(v, Type::Float)),
     }
@@ -126,21 +187,10 @@ fn resolve_float_value(value: Option<Value<'_>>) -> Result<Option<ValueBuf>, Dec
 
 fn resolve_float_array_value(value: Option<Value<'_>>) -> Result<Option<ValueBuf>, DecodeError> {
     match value {
-        None | Some(Value::Float(None | Some(Float::Missing))) => Ok(None),
+        None | Some(Value::Float(None | Some(Float::Missing | Float::EndOfVector))) => Ok(None),
+        Some(Value::Float(Some(Float::Reserved(_)))) => Err(DecodeError::UnexpectedReservedValue),
         Some(Value::Float(Some(Float::Value(n)))) => Ok(Some(ValueBuf::from(vec![Some(n)]))),
-        Some(Value::Array(Array::Float(values))) => Ok(Some(ValueBuf::from(
-            values
-                .iter()
-                .map(|result| {
-                    result.map(Float::from).map(|value| match value {
-                        Float::Value(n) => Some(n),
-                        Float::Missing => None,
-                        _ => todo!("unhandled float array value: {:?}", value),
-                    })
-                })
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|_| DecodeError::UnexpectedEof)?,
-        ))),
+        Some(Value::Array(Array::Float(values))) => resolve_float_array_values(values.as_ref()),
         v => Err(type_mismatch_error(v, Type::Float)),
     }
 }
@@ -233,6 +283,7 @@ pub enum DecodeError {
     },
     MissingCharacter,
     InvalidCharacter,
+    UnexpectedReservedValue,
 }
 
 impl error::Error for DecodeError {
@@ -257,6 +308,7 @@ impl fmt::Display for DecodeError {
             }
             Self::MissingCharacter => write!(f, "missing character"),
             Self::InvalidCharacter => write!(f, "invalid character"),
+            Self::UnexpectedReservedValue => write!(f, "unexpected reserved value"),
         }
     }
 }
@@ -329,6 +381,17 @@ mod tests {
             &[0x23, 0x37, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80],
             Some(vec![Some(55), None]),
         );
+
+        // Some(Value::IntegerArray([Some(8)]))
+        t(&[0x21, 0x08, 0x81], Some(vec![Some(8)]));
+        // None
+        t(&[0x21, 0x81, 0x81], None);
+
+        let mut src = &[0x21, 0x08, 0x82][..];
+        assert_eq!(
+            read_value(&mut src, Number::Count(2), Type::Integer),
+            Err(DecodeError::UnexpectedReservedValue)
+        );
     }
 
     #[test]
@@ -382,6 +445,11 @@ mod tests {
             &[0x25, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x80, 0x7f],
             Some(vec![Some(0.0), None]),
         );
+        // Some(Value::FloatArray([0.0]))
+        t(
+            &[0x25, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x80, 0x7f],
+            Some(vec![Some(0.0)]),
+        );
     }
 
     #[test]
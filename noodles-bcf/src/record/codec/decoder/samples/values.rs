@@ -77,8 +77,8 @@ fn read_i8_values(src: &mut &[u8], sample_count: usize) -> Result<Vec<Option<Val
 
         match value {
             Int8::Value(n) => values.push(Some(Value::from(i32::from(n)))),
-            Int8::Missing => values.push(None),
-            _ => todo!("unhandled i8 value: {:?}", value),
+            Int8::Missing | Int8::EndOfVector => values.push(None),
+            Int8::Reserved(_) => return Err(DecodeError::UnexpectedReservedValue),
         }
     }
 
@@ -95,22 +95,18 @@ fn read_i8_array_values(
     for _ in 0..sample_count {
         let buf = read_i8s(src, len).map_err(DecodeError::InvalidRawValue)?;
 
-        let vs: Vec<_> = buf
-            .into_iter()
-            .map(Int8::from)
-            .filter_map(|value| match value {
-                Int8::Value(n) => Some(Some(i32::from(n))),
-                Int8::Missing => Some(None),
-                Int8::EndOfVector => None,
-                _ => todo!("unhandled i8 array value: {:?}", value),
-            })
-            .collect();
-
-        if vs.len() == 1 && vs[0].is_none() {
-            values.push(None);
-        } else {
-            values.push(Some(Value::from(vs)));
+        let mut vs = Vec::with_capacity(len);
+
+        for value in buf.into_iter().map(Int8::from) {
+            match value {
+                Int8::Value(n) => vs.push(Some(i32::from(n))),
+                Int8::Missing => vs.push(None),
+                Int8::EndOfVector => break,
+                Int8::Reserved(_) => return Err(DecodeError::UnexpectedReservedValue),
+            }
         }
+
+        values.push(build_array_value(vs));
     }
 
     Ok(values)
@@ -129,8 +125,8 @@ fn read_i16_values(
 
         match value {
             Int16::Value(n) => values.push(Some(Value::from(i32::from(n)))),
-            Int16::Missing => values.push(None),
-            _ => todo!("unhandled i16 value: {:?}", value),
+            Int16::Missing | Int16::EndOfVector => values.push(None),
+            Int16::Reserved(_) => return Err(DecodeError::UnexpectedReservedValue),
         }
     }
 
@@ -147,22 +143,18 @@ fn read_i16_array_values(
     for _ in 0..sample_count {
         let buf = read_i16s(src, len).map_err(DecodeError::InvalidRawValue)?;
 
-        let vs: Vec<_> = buf
-            .into_iter()
-            .map(Int16::from)
-            .filter_map(|value| match value {
-                Int16::Value(n) => Some(Some(i32::from(n))),
-                Int16::Missing => Some(None),
-                Int16::EndOfVector => None,
-                _ => todo!("unhandled i16 array value: {:?}", value),
-            })
-            .collect();
-
-        if vs.len() == 1 && vs[0].is_none() {
-            values.push(None);
-        } else {
-            values.push(Some(Value::from(vs)));
+        let mut vs = Vec::with_capacity(len);
+
+        for value in buf.into_iter().map(Int16::from) {
+            match value {
+                Int16::Value(n) => vs.push(Some(i32::from(n))),
+                Int16::Missing => vs.push(None),
+                Int16::EndOfVector => break,
+                Int16::Reserved(_) => return Err(DecodeError::UnexpectedReservedValue),
+            }
         }
+
+        values.push(build_array_value(vs));
     }
 
     Ok(values)
@@ -181,8 +173,8 @@ fn read_i32_values(
 
         match value {
             Int32::Value(n) => values.push(Some(Value::from(n))),
-            Int32::Missing => values.push(None),
-            _ => todo!("unhandled i32 value: {:?}", value),
+            Int32::Missing | Int32::EndOfVector => values.push(None),
+            Int32::Reserved(_) => return Err(DecodeError::UnexpectedReservedValue),
         }
     }
 
@@ -199,22 +191,18 @@ fn read_i32_array_values(
     for _ in 0..sample_count {
         let buf = read_i32s(src, len).map_err(DecodeError::InvalidRawValue)?;
 
-        let vs: Vec<_> = buf
-            .into_iter()
-            .map(Int32::from)
-            .filter_map(|value| match value {
-                Int32::Value(n) => Some(Some(n)),
-                Int32::Missing => Some(None),
-                Int32::EndOfVector => None,
-                _ => todo!("unhandled i32 array value: {:?}", value),
-            })
-            .collect();
-
-        if vs.len() == 1 && vs[0].is_none() {
-            values.push(None);
-        } else {
-            values.push(Some(Value::from(vs)));
+        let mut vs = Vec::with_capacity(len);
+
+        for value in buf.into_iter().map(Int32::from) {
+            match value {
+                Int32::Value(n) => vs.push(Some(n)),
+                Int32::Missing => vs.push(None),
+                Int32::EndOfVector => break,
+                Int32::Reserved(_) => return Err(DecodeError::UnexpectedReservedValue),
+            }
         }
+
+        values.push(build_array_value(vs));
     }
 
     Ok(values)
@@ -233,8 +221,8 @@ fn read_f32_values(
 
         match value {
             Float::Value(n) => values.push(Some(Value::from(n))),
-            Float::Missing => values.push(None),
-            _ => todo!("unhandled f32 value: {:?}", value),
+            Float::Missing | Float::EndOfVector => values.push(None),
+            Float::Reserved(_) => return Err(DecodeError::UnexpectedReservedValue),
         }
     }
 
@@ -251,27 +239,34 @@ fn read_f32_array_values(
     for _ in 0..sample_count {
         let buf = read_f32s(src, len).map_err(DecodeError::InvalidRawValue)?;
 
-        let vs: Vec<_> = buf
-            .into_iter()
-            .map(Float::from)
-            .filter_map(|value| match value {
-                Float::Value(n) => Some(Some(n)),
-                Float::Missing => Some(None),
-                Float::EndOfVector => None,
-                _ => todo!("unhandled f32 array value: {:?}", value),
-            })
-            .collect();
-
-        if vs.len() == 1 && vs[0].is_none() {
-            values.push(None);
-        } else {
-            values.push(Some(Value::from(vs)));
+        let mut vs = Vec::with_capacity(len);
+
+        for value in buf.into_iter().map(Float::from) {
+            match value {
+                Float::Value(n) => vs.push(Some(n)),
+                Float::Missing => vs.push(None),
+                Float::EndOfVector => break,
+                Float::Reserved(_) => return Err(DecodeError::UnexpectedReservedValue),
+            }
         }
+
+        values.push(build_array_value(vs));
     }
 
     Ok(values)
 }
 
+// A vector that ends before its first value or only holds a missing value is missing.
+fn build_array_value<N>(values: Vec<Option<N>>) -> Option<Value>
+where
+    Value: From<Vec<Option<N>>>,
+{
+    match values[..] {
+        [] | [None] => None,
+        _ => Some(Value::from(values)),
+    }
+}
+
 fn read_string_until_nul<'a>(src: &mut &'a [u8], len: usize) -> Result<&'a str, DecodeError> {
     const NUL: u8 = 0x00;
 
@@ -473,6 +468,7 @@ pub enum DecodeError {
     InvalidRawValue(raw_value::DecodeError),
     InvalidString(str::Utf8Error),
     InvalidGenotype,
+    UnexpectedReservedValue,
 }
 
 impl error::Error for DecodeError {
@@ -494,6 +490,7 @@ impl fmt::Display for DecodeError {
             Self::InvalidRawValue(_) => write!(f, "invalid raw value"),
             Self::InvalidString(_) => write!(f, "invalid string"),
             Self::InvalidGenotype => write!(f, "invalid genotype"),
+            Self::UnexpectedReservedValue => write!(f, "unexpected reserved value"),
         }
     }
 }
@@ -538,6 +535,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_values_with_end_of_vector_and_reserved_values() {
+        let mut src = &[
+            0x11, // Some(Type::Int8(1))
+            0x05, // Some(5)
+            0x81, // None
+        ][..];
+
+        assert_eq!(
+            read_values(&mut src, Number::Count(1), format::Type::Integer, 2),
+            Ok(vec![Some(Value::from(5)), None])
+        );
+
+        let mut src = &[
+            0x31, // Some(Type::Int8(3))
+            0x05, 0x08, 0x0d, // Some([Some(5), Some(8), Some(13)])
+            0x15, 0x81, 0x81, // Some([Some(21)])
+            0x81, 0x81, 0x81, // None
+        ][..];
+
+        assert_eq!(
+            read_values(&mut src, Number::Count(3), format::Type::Integer, 3),
+            Ok(vec![
+                Some(Value::from(vec![Some(5), Some(8), Some(13)])),
+                Some(Value::from(vec![Some(21)])),
+                None,
+            ])
+        );
+
+        let mut src = &[
+            0x11, // Some(Type::Int8(1))
+            0x82, // reserved
+        ][..];
+
+        assert_eq!(
+            read_values(&mut src, Number::Count(1), format::Type::Integer, 1),
+            Err(DecodeError::UnexpectedReservedValue)
+        );
+
+        let mut src = &[
+            0x21, // Some(Type::Int8(2))
+            0x05, 0x87, // [Some(5), reserved]
+        ][..];
+
+        assert_eq!(
+            read_values(&mut src, Number::Count(2), format::Type::Integer, 1),
+            Err(DecodeError::UnexpectedReservedValue)
+        );
+    }
+
     #[test]
     fn test_read_values_with_int16_values() {
         let mut src = &[
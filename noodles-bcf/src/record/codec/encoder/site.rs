@@ -77,9 +77,9 @@ pub(crate) fn write_rlen<W>(
 where
     W: Write,
 {
-    let Some(start) = start else {
-        todo!();
-    };
+    // A telomeric breakend (VCF `POS` = 0) has no start position. Its span is counted from the
+    // first position, which is how `bcf::Record::end` decodes it.
+    let start = start.unwrap_or(Position::MIN);
 
     if start > end {
         return Err(io::Error::new(
@@ -166,6 +166,14 @@ mod tests {
             &[0x06, 0x00, 0x00, 0x00],
         )?;
 
+        t(&mut buf, None, Position::MIN, &[0x01, 0x00, 0x00, 0x00])?;
+        t(
+            &mut buf,
+            None,
+            Position::try_from(3)?,
+            &[0x03, 0x00, 0x00, 0x00],
+        )?;
+
         buf.clear();
         assert!(matches!(
             write_rlen(&mut buf, Some(Position::try_from(13)?), Position::try_from(8)?),
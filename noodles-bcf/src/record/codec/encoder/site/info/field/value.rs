@@ -37,10 +37,17 @@ where
         Some(field::Value::Array(field::value::Array::String(values))) => {
             write_string_array_value(writer, values)
         }
-        _ => todo!("unhandled INFO field value: {:?}", value),
+        None => write_missing_value(writer),
     }
 }
 
+fn write_missing_value<W>(writer: &mut W) -> io::Result<()>
+where
+    W: Write,
+{
+    value::write_value(writer, None)
+}
+
 fn write_integer_value<W>(writer: &mut W, n: i32) -> io::Result<()>
 where
     W: Write,
@@ -163,7 +170,10 @@ where
             match v {
                 Int8::Value(n) => Ok(n),
                 Int8::Missing => Ok(i8::from(v)),
-                _ => todo!("unhandled i16 array value: {:?}", v),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid info field integer array value: {v:?}"),
+                )),
             }
         })
         .collect::<io::Result<_>>()?;
@@ -191,7 +201,10 @@ where
             match v {
                 Int16::Value(n) => Ok(n),
                 Int16::Missing => Ok(i16::from(v)),
-                _ => todo!("unhandled i16 array value: {:?}", v),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid info field integer array value: {v:?}"),
+                )),
             }
         })
         .collect::<io::Result<_>>()?;
@@ -217,7 +230,10 @@ where
             match v {
                 Int32::Value(n) => Ok(n),
                 Int32::Missing => Ok(i32::from(v)),
-                _ => todo!("unhandled i32 array value: {:?}", v),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid info field integer array value: {v:?}"),
+                )),
             }
         })
         .collect::<io::Result<_>>()?;
@@ -243,7 +259,10 @@ where
             match v {
                 Float::Value(n) => Ok(n),
                 Float::Missing => Ok(f32::from(v)),
-                _ => todo!("unhandled f32 array value: {:?}", v),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid info field float array value: {v:?}"),
+                )),
             }
         })
         .collect::<io::Result<_>>()?;
@@ -382,6 +401,14 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_value_with_missing_value() -> io::Result<()> {
+        let mut buf = Vec::new();
+        write_value(&mut buf, None)?;
+        assert_eq!(buf, [0x00]);
+        Ok(())
+    }
+
     #[test]
     fn test_write_value_with_character_value() -> io::Result<()> {
         let mut buf = Vec::new();
@@ -46,9 +46,12 @@ pub(super) fn read_value<'a>(
 fn read_integer_value<'a>(src: &mut &'a [u8]) -> io::Result<Option<Value<'a>>> {
     match read_typed_value(src)? {
         None
-        | Some(TypedValue::Int8(None | Some(Int8::Missing)))
-        | Some(TypedValue::Int16(None | Some(Int16::Missing)))
-        | Some(TypedValue::Int32(None | Some(Int32::Missing))) => Ok(None),
+        | Some(TypedValue::Int8(None | Some(Int8::Missing | Int8::EndOfVector)))
+        | Some(TypedValue::Int16(None | Some(Int16::Missing | Int16::EndOfVector)))
+        | Some(TypedValue::Int32(None | Some(Int32::Missing | Int32::EndOfVector))) => Ok(None),
+        Some(TypedValue::Int8(Some(Int8::Reserved(_))))
+        | Some(TypedValue::Int16(Some(Int16::Reserved(_))))
+        | Some(TypedValue::Int32(Some(Int32::Reserved(_)))) => Err(reserved_value_error()),
         Some(TypedValue::Int8(Some(Int8::Value(n)))) => Ok(Some(Value::Integer(i32::from(n)))),
         Some(TypedValue::Int16(Some(Int16::Value(n)))) => Ok(Some(Value::Integer(i32::from(n)))),
         Some(TypedValue::Int32(Some(Int32::Value(n)))) => Ok(Some(Value::Integer(n))),
@@ -80,9 +83,12 @@ where
 fn read_integer_array_value<'a>(src: &mut &'a [u8]) -> io::Result<Option<Value<'a>>> {
     match read_typed_value(src)? {
         None
-        | Some(TypedValue::Int8(None | Some(Int8::Missing)))
-        | Some(TypedValue::Int16(None | Some(Int16::Missing)))
-        | Some(TypedValue::Int32(None | Some(Int32::Missing))) => Ok(None),
+        | Some(TypedValue::Int8(None | Some(Int8::Missing | Int8::EndOfVector)))
+        | Some(TypedValue::Int16(None | Some(Int16::Missing | Int16::EndOfVector)))
+        | Some(TypedValue::Int32(None | Some(Int32::Missing | Int32::EndOfVector))) => Ok(None),
+        Some(TypedValue::Int8(Some(Int8::Reserved(_))))
+        | Some(TypedValue::Int16(Some(Int16::Reserved(_))))
+        | Some(TypedValue::Int32(Some(Int32::Reserved(_)))) => Err(reserved_value_error()),
         Some(TypedValue::Int8(Some(Int8::Value(n)))) => Ok(Some(Value::Array(Array::Integer(
             Box::new(Once::new(i32::from(n))),
         )))),
@@ -110,7 +116,10 @@ fn read_flag_value<'a>(src: &mut &'a [u8]) -> io::Result<Option<Value<'a>>> {
 
 fn read_float_value<'a>(src: &mut &'a [u8]) -> io::Result<Option<Value<'a>>> {
     match read_typed_value(src)? {
-        None | Some(TypedValue::Float(None | Some(Float::Missing))) => Ok(None),
+        None | Some(TypedValue::Float(None | Some(Float::Missing | Float::EndOfVector))) => {
+            Ok(None)
+        }
+        Some(TypedValue::Float(Some(Float::Reserved(_)))) => Err(reserved_value_error()),
         Some(TypedValue::Float(Some(Float::Value(n)))) => Ok(Some(Value::Float(n))),
         v => Err(type_mismatch_error(v, Type::Float)),
     }
@@ -118,7 +127,10 @@ fn read_float_value<'a>(src: &mut &'a [u8]) -> io::Result<Option<Value<'a>>> {
 
 fn read_float_array_value<'a>(src: &mut &'a [u8]) -> io::Result<Option<Value<'a>>> {
     match read_typed_value(src)? {
-        None | Some(TypedValue::Float(None | Some(Float::Missing))) => Ok(None),
+        None | Some(TypedValue::Float(None | Some(Float::Missing | Float::EndOfVector))) => {
+            Ok(None)
+        }
+        Some(TypedValue::Float(Some(Float::Reserved(_)))) => Err(reserved_value_error()),
         Some(TypedValue::Float(Some(Float::Value(n)))) => {
             Ok(Some(Value::Array(Array::Float(Box::new(Once::new(n))))))
         }
@@ -167,6 +179,10 @@ fn read_string_array_value<'a>(src: &mut &'a [u8]) -> io::Result<Option<Value<'a
     }
 }
 
+fn reserved_value_error() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "unexpected reserved value")
+}
+
 fn type_mismatch_error(value: Option<TypedValue>, expected: Type) -> io::Error {
     let actual = value.map(|v| match v {
         TypedValue::Int8(_) | TypedValue::Int16(_) | TypedValue::Int32(_) => Type::Integer,
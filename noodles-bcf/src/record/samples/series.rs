@@ -100,11 +100,7 @@ impl<'r> Series<'r> {
             _ => todo!("unhandled type"),
         };
 
-        match value {
-            Some(Some(value)) => Some(Some(Ok(value))),
-            Some(None) => Some(None),
-            None => None,
-        }
+        value.map(|result| result.transpose())
     }
 }
 
@@ -181,27 +177,32 @@ fn range<N>(i: usize, len: usize) -> Range<usize> {
     start..end
 }
 
-fn get_i8_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_i8_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     use crate::record::codec::value::Int8;
 
     let src = src.get(range::<i8>(i, len))?;
 
     let value = match Int8::from(src[0] as i8) {
         Int8::Value(n) => Some(Value::Integer(i32::from(n))),
-        Int8::Missing => None,
-        Int8::EndOfVector | Int8::Reserved(_) => todo!(),
+        Int8::Missing | Int8::EndOfVector => None,
+        Int8::Reserved(_) => return Some(Err(reserved_value_error())),
     };
 
-    Some(value)
+    Some(Ok(value))
 }
 
-fn get_i8_array_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_i8_array_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     let src = src.get(range::<i8>(i, len))?;
     let values = Values::<'_, i8>::new(src);
-    Some(Some(Value::Array(Array::Integer(Box::new(values)))))
+
+    if values.is_missing() {
+        Some(Ok(None))
+    } else {
+        Some(Ok(Some(Value::Array(Array::Integer(Box::new(values))))))
+    }
 }
 
-fn get_i16_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_i16_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     use crate::record::codec::value::Int16;
 
     let src = src.get(range::<i16>(i, len))?;
@@ -209,20 +210,25 @@ fn get_i16_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>>
     // SAFETY: `src` is 2 bytes.
     let value = match Int16::from(i16::from_le_bytes(src.try_into().unwrap())) {
         Int16::Value(n) => Some(Value::Integer(i32::from(n))),
-        Int16::Missing => None,
-        Int16::EndOfVector | Int16::Reserved(_) => todo!(),
+        Int16::Missing | Int16::EndOfVector => None,
+        Int16::Reserved(_) => return Some(Err(reserved_value_error())),
     };
 
-    Some(value)
+    Some(Ok(value))
 }
 
-fn get_i16_array_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_i16_array_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     let src = src.get(range::<i16>(i, len))?;
     let values = Values::<'_, i16>::new(src);
-    Some(Some(Value::Array(Array::Integer(Box::new(values)))))
+
+    if values.is_missing() {
+        Some(Ok(None))
+    } else {
+        Some(Ok(Some(Value::Array(Array::Integer(Box::new(values))))))
+    }
 }
 
-fn get_i32_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_i32_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     use crate::record::codec::value::Int32;
 
     let src = src.get(range::<i32>(i, len))?;
@@ -230,20 +236,25 @@ fn get_i32_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>>
     // SAFETY: `src` is 2 bytes.
     let value = match Int32::from(i32::from_le_bytes(src.try_into().unwrap())) {
         Int32::Value(n) => Some(Value::Integer(n)),
-        Int32::Missing => None,
-        Int32::EndOfVector | Int32::Reserved(_) => todo!(),
+        Int32::Missing | Int32::EndOfVector => None,
+        Int32::Reserved(_) => return Some(Err(reserved_value_error())),
     };
 
-    Some(value)
+    Some(Ok(value))
 }
 
-fn get_i32_array_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_i32_array_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     let src = src.get(range::<i32>(i, len))?;
     let values = Values::<'_, i32>::new(src);
-    Some(Some(Value::Array(Array::Integer(Box::new(values)))))
+
+    if values.is_missing() {
+        Some(Ok(None))
+    } else {
+        Some(Ok(Some(Value::Array(Array::Integer(Box::new(values))))))
+    }
 }
 
-fn get_f32_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_f32_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     use crate::record::codec::value::Float;
 
     let src = src.get(range::<f32>(i, len))?;
@@ -251,17 +262,22 @@ fn get_f32_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>>
     // SAFETY: `src` is 2 bytes.
     let value = match Float::from(f32::from_le_bytes(src.try_into().unwrap())) {
         Float::Value(n) => Some(Value::Float(n)),
-        Float::Missing => None,
-        Float::EndOfVector | Float::Reserved(_) => todo!(),
+        Float::Missing | Float::EndOfVector => None,
+        Float::Reserved(_) => return Some(Err(reserved_value_error())),
     };
 
-    Some(value)
+    Some(Ok(value))
 }
 
-fn get_f32_array_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_f32_array_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     let src = src.get(range::<f32>(i, len))?;
     let values = Values::<'_, f32>::new(src);
-    Some(Some(Value::Array(Array::Float(Box::new(values)))))
+
+    if values.is_missing() {
+        Some(Ok(None))
+    } else {
+        Some(Ok(Some(Value::Array(Array::Float(Box::new(values))))))
+    }
 }
 
 fn get_string(src: &[u8], len: usize, i: usize) -> Option<&str> {
@@ -281,7 +297,7 @@ fn get_string(src: &[u8], len: usize, i: usize) -> Option<&str> {
     )
 }
 
-fn get_char_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_char_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     const MISSING: char = '.';
 
     let s = get_string(src, len, i)?;
@@ -290,28 +306,36 @@ fn get_char_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>>
     let c = s.chars().next().unwrap();
 
     match c {
-        MISSING => Some(None),
-        _ => Some(Some(Value::Character(c))),
+        MISSING => Some(Ok(None)),
+        _ => Some(Ok(Some(Value::Character(c)))),
     }
 }
 
-fn get_char_array_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_char_array_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     let s = get_string(src, len, i)?;
-    Some(Some(Value::Array(Array::Character(Box::new(s)))))
+    Some(Ok(Some(Value::Array(Array::Character(Box::new(s))))))
 }
 
-fn get_string_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_string_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     const MISSING: &str = ".";
 
     match get_string(src, len, i)? {
-        MISSING => Some(None),
-        s => Some(Some(Value::String(Cow::from(s)))),
+        MISSING => Some(Ok(None)),
+        s => Some(Ok(Some(Value::String(Cow::from(s))))),
     }
 }
 
-fn get_string_array_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_string_array_value(
+    src: &[u8],
+    len: usize,
+    i: usize,
+) -> Option<io::Result<Option<Value<'_>>>> {
     let s = get_string(src, len, i)?;
-    Some(Some(Value::Array(Array::String(Box::new(s)))))
+    Some(Ok(Some(Value::Array(Array::String(Box::new(s))))))
+}
+
+fn reserved_value_error() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "unexpected reserved value")
 }
 
 fn get_genotype_value<'r>(
@@ -396,6 +420,30 @@ mod tests {
         assert!(series.get(&header, 3).is_none());
     }
 
+    #[test]
+    fn test_get_with_int8_end_of_vector_and_reserved_values() {
+        let header = build_header_with_format(NAME, Number::Count(1), format::Type::Integer);
+        let id = header.string_maps().strings().get_index_of(NAME).unwrap();
+        let src = &[
+            0x81, // None
+            0x82, // reserved
+        ];
+
+        let series = Series {
+            id,
+            ty: Type::Int8(1),
+            sample_count: 2,
+            src,
+        };
+
+        assert!(series.get(&header, 0).unwrap().is_none());
+
+        assert!(matches!(
+            series.get(&header, 1),
+            Some(Some(Err(e))) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
     #[test]
     fn test_get_with_int8_array_values() {
         fn t(
@@ -434,7 +482,7 @@ mod tests {
         t(&series, &header, 0, Some(&[Some(5), Some(8)]));
         t(&series, &header, 1, Some(&[Some(13), None]));
         t(&series, &header, 2, Some(&[Some(21)]));
-        t(&series, &header, 3, Some(&[None])); // FIXME
+        t(&series, &header, 3, None);
 
         assert!(series.get(&header, 4).is_none());
     }
@@ -511,7 +559,7 @@ mod tests {
         t(&series, &header, 0, Some(&[Some(5), Some(8)]));
         t(&series, &header, 1, Some(&[Some(13), None]));
         t(&series, &header, 2, Some(&[Some(21)]));
-        t(&series, &header, 3, Some(&[None])); // FIXME
+        t(&series, &header, 3, None);
 
         assert!(series.get(&header, 4).is_none());
     }
@@ -588,7 +636,7 @@ mod tests {
         t(&series, &header, 0, Some(&[Some(5), Some(8)]));
         t(&series, &header, 1, Some(&[Some(13), None]));
         t(&series, &header, 2, Some(&[Some(21)]));
-        t(&series, &header, 3, Some(&[None])); // FIXME
+        t(&series, &header, 3, None);
 
         assert!(series.get(&header, 4).is_none());
     }
@@ -665,7 +713,7 @@ mod tests {
         t(&series, &header, 0, Some(&[Some(0.0), Some(1.0)]));
         t(&series, &header, 1, Some(&[Some(0.0), None]));
         t(&series, &header, 2, Some(&[Some(0.0)]));
-        t(&series, &header, 3, Some(&[None])); // FIXME
+        t(&series, &header, 3, None);
 
         assert!(series.get(&header, 4).is_none());
     }
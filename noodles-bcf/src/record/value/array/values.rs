@@ -28,33 +28,47 @@ impl Values<'_, i8> {
     pub(crate) fn iter(&self) -> impl Iterator<Item = Int8> + '_ {
         self.src.iter().map(|&n| Int8::from(n as i8))
     }
+
+    // Values following an end-of-vector marker are padding.
+    fn values(&self) -> impl Iterator<Item = Int8> + '_ {
+        self.iter()
+            .take_while(|value| !matches!(value, Int8::EndOfVector))
+    }
+
+    /// Returns whether the vector is empty or only holds a missing value.
+    pub(crate) fn is_missing(&self) -> bool {
+        let mut values = self.values();
+        matches!(
+            (values.next(), values.next()),
+            (None, _) | (Some(Int8::Missing), None)
+        )
+    }
 }
 
 impl<'a> vcf::variant::record::info::field::value::array::Values<'a, i32> for Values<'a, i8> {
     fn len(&self) -> usize {
-        self.src.len()
+        self.values().count()
     }
 
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<i32>>> + '_> {
-        Box::new(self.iter().map(|value| match value {
+        Box::new(self.values().map(|value| match value {
             Int8::Value(n) => Ok(Some(i32::from(n))),
             Int8::Missing => Ok(None),
-            _ => Err(io::Error::from(io::ErrorKind::InvalidData)),
+            _ => Err(reserved_value_error()),
         }))
     }
 }
 
 impl<'a> vcf::variant::record::samples::series::value::array::Values<'a, i32> for Values<'a, i8> {
     fn len(&self) -> usize {
-        self.src.len()
+        self.values().count()
     }
 
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<i32>>> + '_> {
-        Box::new(self.iter().filter_map(|value| match value {
-            Int8::Value(n) => Some(Ok(Some(i32::from(n)))),
-            Int8::Missing => Some(Ok(None)),
-            Int8::EndOfVector => None,
-            Int8::Reserved(_) => Some(Err(io::Error::from(io::ErrorKind::InvalidData))),
+        Box::new(self.values().map(|value| match value {
+            Int8::Value(n) => Ok(Some(i32::from(n))),
+            Int8::Missing => Ok(None),
+            _ => Err(reserved_value_error()),
         }))
     }
 }
@@ -67,33 +81,47 @@ impl Values<'_, i16> {
             Int16::from(n)
         })
     }
+
+    // Values following an end-of-vector marker are padding.
+    fn values(&self) -> impl Iterator<Item = Int16> + '_ {
+        self.iter()
+            .take_while(|value| !matches!(value, Int16::EndOfVector))
+    }
+
+    /// Returns whether the vector is empty or only holds a missing value.
+    pub(crate) fn is_missing(&self) -> bool {
+        let mut values = self.values();
+        matches!(
+            (values.next(), values.next()),
+            (None, _) | (Some(Int16::Missing), None)
+        )
+    }
 }
 
 impl<'a> vcf::variant::record::info::field::value::array::Values<'a, i32> for Values<'a, i16> {
     fn len(&self) -> usize {
-        self.src.len() / mem::size_of::<i16>()
+        self.values().count()
     }
 
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<i32>>> + '_> {
-        Box::new(self.iter().map(|value| match value {
+        Box::new(self.values().map(|value| match value {
             Int16::Value(n) => Ok(Some(i32::from(n))),
             Int16::Missing => Ok(None),
-            _ => Err(io::Error::from(io::ErrorKind::InvalidData)),
+            _ => Err(reserved_value_error()),
         }))
     }
 }
 
 impl<'a> vcf::variant::record::samples::series::value::array::Values<'a, i32> for Values<'a, i16> {
     fn len(&self) -> usize {
-        self.src.len() / mem::size_of::<i16>()
+        self.values().count()
     }
 
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<i32>>> + '_> {
-        Box::new(self.iter().filter_map(|value| match value {
-            Int16::Value(n) => Some(Ok(Some(i32::from(n)))),
-            Int16::Missing => Some(Ok(None)),
-            Int16::EndOfVector => None,
-            Int16::Reserved(_) => Some(Err(io::Error::from(io::ErrorKind::InvalidData))),
+        Box::new(self.values().map(|value| match value {
+            Int16::Value(n) => Ok(Some(i32::from(n))),
+            Int16::Missing => Ok(None),
+            _ => Err(reserved_value_error()),
         }))
     }
 }
@@ -106,33 +134,47 @@ impl Values<'_, i32> {
             Int32::from(n)
         })
     }
+
+    // Values following an end-of-vector marker are padding.
+    fn values(&self) -> impl Iterator<Item = Int32> + '_ {
+        self.iter()
+            .take_while(|value| !matches!(value, Int32::EndOfVector))
+    }
+
+    /// Returns whether the vector is empty or only holds a missing value.
+    pub(crate) fn is_missing(&self) -> bool {
+        let mut values = self.values();
+        matches!(
+            (values.next(), values.next()),
+            (None, _) | (Some(Int32::Missing), None)
+        )
+    }
 }
 
 impl<'a> vcf::variant::record::info::field::value::array::Values<'a, i32> for Values<'a, i32> {
     fn len(&self) -> usize {
-        self.src.len() / mem::size_of::<i32>()
+        self.values().count()
     }
 
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<i32>>> + '_> {
-        Box::new(self.iter().map(|value| match value {
+        Box::new(self.values().map(|value| match value {
             Int32::Value(n) => Ok(Some(n)),
             Int32::Missing => Ok(None),
-            _ => Err(io::Error::from(io::ErrorKind::InvalidData)),
+            _ => Err(reserved_value_error()),
         }))
     }
 }
 
 impl<'a> vcf::variant::record::samples::series::value::array::Values<'a, i32> for Values<'a, i32> {
     fn len(&self) -> usize {
-        self.src.len() / mem::size_of::<i32>()
+        self.values().count()
     }
 
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<i32>>> + '_> {
-        Box::new(self.iter().filter_map(|value| match value {
-            Int32::Value(n) => Some(Ok(Some(n))),
-            Int32::Missing => Some(Ok(None)),
-            Int32::EndOfVector => None,
-            Int32::Reserved(_) => Some(Err(io::Error::from(io::ErrorKind::InvalidData))),
+        Box::new(self.values().map(|value| match value {
+            Int32::Value(n) => Ok(Some(n)),
+            Int32::Missing => Ok(None),
+            _ => Err(reserved_value_error()),
         }))
     }
 }
@@ -145,33 +187,51 @@ impl Values<'_, f32> {
             Float::from(n)
         })
     }
+
+    // Values following an end-of-vector marker are padding.
+    fn values(&self) -> impl Iterator<Item = Float> + '_ {
+        self.iter()
+            .take_while(|value| !matches!(value, Float::EndOfVector))
+    }
+
+    /// Returns whether the vector is empty or only holds a missing value.
+    pub(crate) fn is_missing(&self) -> bool {
+        let mut values = self.values();
+        matches!(
+            (values.next(), values.next()),
+            (None, _) | (Some(Float::Missing), None)
+        )
+    }
 }
 
 impl<'a> vcf::variant::record::info::field::value::array::Values<'a, f32> for Values<'a, f32> {
     fn len(&self) -> usize {
-        self.src.len() / mem::size_of::<f32>()
+        self.values().count()
     }
 
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<f32>>> + '_> {
-        Box::new(self.iter().map(|value| match value {
+        Box::new(self.values().map(|value| match value {
             Float::Value(n) => Ok(Some(n)),
             Float::Missing => Ok(None),
-            _ => Err(io::Error::from(io::ErrorKind::InvalidData)),
+            _ => Err(reserved_value_error()),
         }))
     }
 }
 
 impl<'a> vcf::variant::record::samples::series::value::array::Values<'a, f32> for Values<'a, f32> {
     fn len(&self) -> usize {
-        self.src.len() / mem::size_of::<f32>()
+        self.values().count()
     }
 
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<f32>>> + '_> {
-        Box::new(self.iter().filter_map(|value| match value {
-            Float::Value(n) => Some(Ok(Some(n))),
-            Float::Missing => Some(Ok(None)),
-            Float::EndOfVector => None,
-            Float::Reserved(_) => Some(Err(io::Error::from(io::ErrorKind::InvalidData))),
+        Box::new(self.values().map(|value| match value {
+            Float::Value(n) => Ok(Some(n)),
+            Float::Missing => Ok(None),
+            _ => Err(reserved_value_error()),
         }))
     }
 }
+
+fn reserved_value_error() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "unexpected reserved value")
+}
@@ -1,5 +1,7 @@
 //! Feature record.
 
+pub(crate) mod blocks;
+mod color;
 pub mod other_fields;
 mod strand;
 
@@ -8,7 +10,7 @@ use std::io;
 use bstr::BStr;
 use noodles_core::Position;
 
-pub use self::{other_fields::OtherFields, strand::Strand};
+pub use self::{color::Color, other_fields::OtherFields, strand::Strand};
 
 /// A feature record.
 pub trait Record<const N: usize> {
@@ -35,6 +37,28 @@ pub trait Record<const N: usize> {
     /// Returns the strand.
     fn strand(&self) -> Option<io::Result<Option<Strand>>>;
 
+    /// Returns the thick start.
+    fn thick_start(&self) -> Option<io::Result<Position>> {
+        None
+    }
+
+    /// Returns the thick end.
+    fn thick_end(&self) -> Option<Option<io::Result<Position>>> {
+        None
+    }
+
+    /// Returns the color.
+    fn color(&self) -> Option<io::Result<Option<Color>>> {
+        None
+    }
+
+    /// Returns the blocks.
+    ///
+    /// Each block is a (start, size) pair, where the start is relative to the feature start.
+    fn blocks(&self) -> Option<io::Result<Vec<(usize, usize)>>> {
+        None
+    }
+
     /// Returns the other fields.
     fn other_fields(&self) -> Box<dyn OtherFields + '_>;
 }
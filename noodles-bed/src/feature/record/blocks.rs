@@ -0,0 +1,75 @@
+use noodles_core::Position;
+
+/// Returns whether the blocks lie within the feature bounds.
+///
+/// Blocks are (start, size) pairs, where each start is relative to the feature start. They must
+/// be sorted, must not overlap, and must not extend past the feature end.
+pub(crate) fn is_valid(
+    feature_start: Position,
+    feature_end: Option<Position>,
+    blocks: &[(usize, usize)],
+) -> bool {
+    // The feature start is 1-based, and the feature end is 1-based, inclusive. The difference is
+    // the 0-based, half-open length of the feature.
+    let feature_len = feature_end
+        .map(usize::from)
+        .unwrap_or_default()
+        .saturating_sub(usize::from(feature_start) - 1);
+
+    let mut prev_end = 0;
+
+    for &(start, size) in blocks {
+        let Some(end) = start.checked_add(size) else {
+            return false;
+        };
+
+        if start < prev_end || end > feature_len {
+            return false;
+        }
+
+        prev_end = end;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid() -> Result<(), noodles_core::position::TryFromIntError> {
+        let feature_start = Position::try_from(1001)?;
+        let feature_end = Position::try_from(2000).map(Some)?;
+
+        assert!(is_valid(feature_start, feature_end, &[]));
+        assert!(is_valid(
+            feature_start,
+            feature_end,
+            &[(0, 100), (900, 100)]
+        ));
+        assert!(is_valid(feature_start, feature_end, &[(0, 1000)]));
+
+        // out of bounds
+        assert!(!is_valid(feature_start, feature_end, &[(0, 1001)]));
+        assert!(!is_valid(feature_start, feature_end, &[(950, 100)]));
+        assert!(!is_valid(feature_start, None, &[(0, 1)]));
+
+        // unsorted and overlapping
+        assert!(!is_valid(
+            feature_start,
+            feature_end,
+            &[(900, 100), (0, 100)]
+        ));
+        assert!(!is_valid(
+            feature_start,
+            feature_end,
+            &[(0, 100), (50, 100)]
+        ));
+
+        // overflow
+        assert!(!is_valid(feature_start, feature_end, &[(usize::MAX, 1)]));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+/// A BED record feature color (`itemRgb`).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Color {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+impl Color {
+    /// Creates a color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::feature::record::Color;
+    /// let color = Color::new(255, 0, 0);
+    /// ```
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Returns the red component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::feature::record::Color;
+    /// let color = Color::new(255, 128, 0);
+    /// assert_eq!(color.red(), 255);
+    /// ```
+    pub const fn red(&self) -> u8 {
+        self.red
+    }
+
+    /// Returns the green component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::feature::record::Color;
+    /// let color = Color::new(255, 128, 0);
+    /// assert_eq!(color.green(), 128);
+    /// ```
+    pub const fn green(&self) -> u8 {
+        self.green
+    }
+
+    /// Returns the blue component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::feature::record::Color;
+    /// let color = Color::new(255, 128, 0);
+    /// assert_eq!(color.blue(), 0);
+    /// ```
+    pub const fn blue(&self) -> u8 {
+        self.blue
+    }
+}
@@ -10,7 +10,7 @@ use bstr::{BStr, BString};
 use noodles_core::Position;
 
 pub use self::{builder::Builder, other_fields::OtherFields};
-use crate::feature::record::Strand;
+use crate::feature::record::{Color, Strand};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct StandardFields<const N: usize> {
@@ -20,6 +20,10 @@ struct StandardFields<const N: usize> {
     name: Option<BString>,
     score: u16,
     strand: Option<Strand>,
+    thick_start: Position,
+    thick_end: Option<Position>,
+    color: Option<Color>,
+    blocks: Vec<(usize, usize)>,
 }
 
 impl<const N: usize> StandardFields<N> {
@@ -37,6 +41,10 @@ impl<const N: usize> Default for StandardFields<N> {
             name: None,
             score: 0,
             strand: None,
+            thick_start: Position::MIN,
+            thick_end: None,
+            color: None,
+            blocks: Vec::new(),
         }
     }
 }
@@ -372,3 +380,513 @@ impl super::Record<6> for RecordBuf<6> {
         Box::new(self.other_fields())
     }
 }
+
+impl RecordBuf<7> {
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &BStr {
+        self.standard_fields.reference_sequence_name.as_ref()
+    }
+
+    /// Returns a mutable reference to the reference sequence name.
+    pub fn reference_sequence_name_mut(&mut self) -> &mut BString {
+        &mut self.standard_fields.reference_sequence_name
+    }
+
+    /// Returns the feature start.
+    pub fn feature_start(&self) -> Position {
+        self.standard_fields.feature_start
+    }
+
+    /// Returns a mutable reference to the feature start.
+    pub fn feature_start_mut(&mut self) -> &mut Position {
+        &mut self.standard_fields.feature_start
+    }
+
+    /// Returns the feature end.
+    pub fn feature_end(&self) -> Option<Position> {
+        self.standard_fields.feature_end
+    }
+
+    /// Returns a mutable reference to the feature end.
+    pub fn feature_end_mut(&mut self) -> &mut Option<Position> {
+        &mut self.standard_fields.feature_end
+    }
+
+    /// Returns the name.
+    pub fn name(&self) -> Option<&BStr> {
+        self.standard_fields.name.as_ref().map(|name| name.as_ref())
+    }
+
+    /// Returns a mutable reference to the name.
+    pub fn name_mut(&mut self) -> &mut Option<BString> {
+        &mut self.standard_fields.name
+    }
+
+    /// Returns the score.
+    pub fn score(&self) -> u16 {
+        self.standard_fields.score
+    }
+
+    /// Returns a mutable reference to the score.
+    pub fn score_mut(&mut self) -> &mut u16 {
+        &mut self.standard_fields.score
+    }
+
+    /// Returns the strand.
+    pub fn strand(&self) -> Option<Strand> {
+        self.standard_fields.strand
+    }
+
+    /// Returns a mutable reference to the strand.
+    pub fn strand_mut(&mut self) -> &mut Option<Strand> {
+        &mut self.standard_fields.strand
+    }
+
+    /// Returns the thick start.
+    pub fn thick_start(&self) -> Position {
+        self.standard_fields.thick_start
+    }
+
+    /// Returns a mutable reference to the thick start.
+    pub fn thick_start_mut(&mut self) -> &mut Position {
+        &mut self.standard_fields.thick_start
+    }
+}
+
+impl super::Record<7> for RecordBuf<7> {
+    fn reference_sequence_name(&self) -> &BStr {
+        self.reference_sequence_name()
+    }
+
+    fn feature_start(&self) -> io::Result<Position> {
+        Ok(self.feature_start())
+    }
+
+    fn feature_end(&self) -> Option<io::Result<Position>> {
+        self.feature_end().map(Ok)
+    }
+
+    fn name(&self) -> Option<Option<&BStr>> {
+        Some(self.name())
+    }
+
+    fn score(&self) -> Option<io::Result<u16>> {
+        Some(Ok(self.score()))
+    }
+
+    fn strand(&self) -> Option<io::Result<Option<Strand>>> {
+        Some(Ok(self.strand()))
+    }
+
+    fn thick_start(&self) -> Option<io::Result<Position>> {
+        Some(Ok(self.thick_start()))
+    }
+
+    fn other_fields(&self) -> Box<dyn super::record::OtherFields + '_> {
+        Box::new(self.other_fields())
+    }
+}
+
+impl RecordBuf<8> {
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &BStr {
+        self.standard_fields.reference_sequence_name.as_ref()
+    }
+
+    /// Returns a mutable reference to the reference sequence name.
+    pub fn reference_sequence_name_mut(&mut self) -> &mut BString {
+        &mut self.standard_fields.reference_sequence_name
+    }
+
+    /// Returns the feature start.
+    pub fn feature_start(&self) -> Position {
+        self.standard_fields.feature_start
+    }
+
+    /// Returns a mutable reference to the feature start.
+    pub fn feature_start_mut(&mut self) -> &mut Position {
+        &mut self.standard_fields.feature_start
+    }
+
+    /// Returns the feature end.
+    pub fn feature_end(&self) -> Option<Position> {
+        self.standard_fields.feature_end
+    }
+
+    /// Returns a mutable reference to the feature end.
+    pub fn feature_end_mut(&mut self) -> &mut Option<Position> {
+        &mut self.standard_fields.feature_end
+    }
+
+    /// Returns the name.
+    pub fn name(&self) -> Option<&BStr> {
+        self.standard_fields.name.as_ref().map(|name| name.as_ref())
+    }
+
+    /// Returns a mutable reference to the name.
+    pub fn name_mut(&mut self) -> &mut Option<BString> {
+        &mut self.standard_fields.name
+    }
+
+    /// Returns the score.
+    pub fn score(&self) -> u16 {
+        self.standard_fields.score
+    }
+
+    /// Returns a mutable reference to the score.
+    pub fn score_mut(&mut self) -> &mut u16 {
+        &mut self.standard_fields.score
+    }
+
+    /// Returns the strand.
+    pub fn strand(&self) -> Option<Strand> {
+        self.standard_fields.strand
+    }
+
+    /// Returns a mutable reference to the strand.
+    pub fn strand_mut(&mut self) -> &mut Option<Strand> {
+        &mut self.standard_fields.strand
+    }
+
+    /// Returns the thick start.
+    pub fn thick_start(&self) -> Position {
+        self.standard_fields.thick_start
+    }
+
+    /// Returns a mutable reference to the thick start.
+    pub fn thick_start_mut(&mut self) -> &mut Position {
+        &mut self.standard_fields.thick_start
+    }
+
+    /// Returns the thick end.
+    pub fn thick_end(&self) -> Option<Position> {
+        self.standard_fields.thick_end
+    }
+
+    /// Returns a mutable reference to the thick end.
+    pub fn thick_end_mut(&mut self) -> &mut Option<Position> {
+        &mut self.standard_fields.thick_end
+    }
+}
+
+impl super::Record<8> for RecordBuf<8> {
+    fn reference_sequence_name(&self) -> &BStr {
+        self.reference_sequence_name()
+    }
+
+    fn feature_start(&self) -> io::Result<Position> {
+        Ok(self.feature_start())
+    }
+
+    fn feature_end(&self) -> Option<io::Result<Position>> {
+        self.feature_end().map(Ok)
+    }
+
+    fn name(&self) -> Option<Option<&BStr>> {
+        Some(self.name())
+    }
+
+    fn score(&self) -> Option<io::Result<u16>> {
+        Some(Ok(self.score()))
+    }
+
+    fn strand(&self) -> Option<io::Result<Option<Strand>>> {
+        Some(Ok(self.strand()))
+    }
+
+    fn thick_start(&self) -> Option<io::Result<Position>> {
+        Some(Ok(self.thick_start()))
+    }
+
+    fn thick_end(&self) -> Option<Option<io::Result<Position>>> {
+        Some(self.thick_end().map(Ok))
+    }
+
+    fn other_fields(&self) -> Box<dyn super::record::OtherFields + '_> {
+        Box::new(self.other_fields())
+    }
+}
+
+impl RecordBuf<9> {
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &BStr {
+        self.standard_fields.reference_sequence_name.as_ref()
+    }
+
+    /// Returns a mutable reference to the reference sequence name.
+    pub fn reference_sequence_name_mut(&mut self) -> &mut BString {
+        &mut self.standard_fields.reference_sequence_name
+    }
+
+    /// Returns the feature start.
+    pub fn feature_start(&self) -> Position {
+        self.standard_fields.feature_start
+    }
+
+    /// Returns a mutable reference to the feature start.
+    pub fn feature_start_mut(&mut self) -> &mut Position {
+        &mut self.standard_fields.feature_start
+    }
+
+    /// Returns the feature end.
+    pub fn feature_end(&self) -> Option<Position> {
+        self.standard_fields.feature_end
+    }
+
+    /// Returns a mutable reference to the feature end.
+    pub fn feature_end_mut(&mut self) -> &mut Option<Position> {
+        &mut self.standard_fields.feature_end
+    }
+
+    /// Returns the name.
+    pub fn name(&self) -> Option<&BStr> {
+        self.standard_fields.name.as_ref().map(|name| name.as_ref())
+    }
+
+    /// Returns a mutable reference to the name.
+    pub fn name_mut(&mut self) -> &mut Option<BString> {
+        &mut self.standard_fields.name
+    }
+
+    /// Returns the score.
+    pub fn score(&self) -> u16 {
+        self.standard_fields.score
+    }
+
+    /// Returns a mutable reference to the score.
+    pub fn score_mut(&mut self) -> &mut u16 {
+        &mut self.standard_fields.score
+    }
+
+    /// Returns the strand.
+    pub fn strand(&self) -> Option<Strand> {
+        self.standard_fields.strand
+    }
+
+    /// Returns a mutable reference to the strand.
+    pub fn strand_mut(&mut self) -> &mut Option<Strand> {
+        &mut self.standard_fields.strand
+    }
+
+    /// Returns the thick start.
+    pub fn thick_start(&self) -> Position {
+        self.standard_fields.thick_start
+    }
+
+    /// Returns a mutable reference to the thick start.
+    pub fn thick_start_mut(&mut self) -> &mut Position {
+        &mut self.standard_fields.thick_start
+    }
+
+    /// Returns the thick end.
+    pub fn thick_end(&self) -> Option<Position> {
+        self.standard_fields.thick_end
+    }
+
+    /// Returns a mutable reference to the thick end.
+    pub fn thick_end_mut(&mut self) -> &mut Option<Position> {
+        &mut self.standard_fields.thick_end
+    }
+
+    /// Returns the color.
+    pub fn color(&self) -> Option<Color> {
+        self.standard_fields.color
+    }
+
+    /// Returns a mutable reference to the color.
+    pub fn color_mut(&mut self) -> &mut Option<Color> {
+        &mut self.standard_fields.color
+    }
+}
+
+impl super::Record<9> for RecordBuf<9> {
+    fn reference_sequence_name(&self) -> &BStr {
+        self.reference_sequence_name()
+    }
+
+    fn feature_start(&self) -> io::Result<Position> {
+        Ok(self.feature_start())
+    }
+
+    fn feature_end(&self) -> Option<io::Result<Position>> {
+        self.feature_end().map(Ok)
+    }
+
+    fn name(&self) -> Option<Option<&BStr>> {
+        Some(self.name())
+    }
+
+    fn score(&self) -> Option<io::Result<u16>> {
+        Some(Ok(self.score()))
+    }
+
+    fn strand(&self) -> Option<io::Result<Option<Strand>>> {
+        Some(Ok(self.strand()))
+    }
+
+    fn thick_start(&self) -> Option<io::Result<Position>> {
+        Some(Ok(self.thick_start()))
+    }
+
+    fn thick_end(&self) -> Option<Option<io::Result<Position>>> {
+        Some(self.thick_end().map(Ok))
+    }
+
+    fn color(&self) -> Option<io::Result<Option<Color>>> {
+        Some(Ok(self.color()))
+    }
+
+    fn other_fields(&self) -> Box<dyn super::record::OtherFields + '_> {
+        Box::new(self.other_fields())
+    }
+}
+
+impl RecordBuf<12> {
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &BStr {
+        self.standard_fields.reference_sequence_name.as_ref()
+    }
+
+    /// Returns a mutable reference to the reference sequence name.
+    pub fn reference_sequence_name_mut(&mut self) -> &mut BString {
+        &mut self.standard_fields.reference_sequence_name
+    }
+
+    /// Returns the feature start.
+    pub fn feature_start(&self) -> Position {
+        self.standard_fields.feature_start
+    }
+
+    /// Returns a mutable reference to the feature start.
+    pub fn feature_start_mut(&mut self) -> &mut Position {
+        &mut self.standard_fields.feature_start
+    }
+
+    /// Returns the feature end.
+    pub fn feature_end(&self) -> Option<Position> {
+        self.standard_fields.feature_end
+    }
+
+    /// Returns a mutable reference to the feature end.
+    pub fn feature_end_mut(&mut self) -> &mut Option<Position> {
+        &mut self.standard_fields.feature_end
+    }
+
+    /// Returns the name.
+    pub fn name(&self) -> Option<&BStr> {
+        self.standard_fields.name.as_ref().map(|name| name.as_ref())
+    }
+
+    /// Returns a mutable reference to the name.
+    pub fn name_mut(&mut self) -> &mut Option<BString> {
+        &mut self.standard_fields.name
+    }
+
+    /// Returns the score.
+    pub fn score(&self) -> u16 {
+        self.standard_fields.score
+    }
+
+    /// Returns a mutable reference to the score.
+    pub fn score_mut(&mut self) -> &mut u16 {
+        &mut self.standard_fields.score
+    }
+
+    /// Returns the strand.
+    pub fn strand(&self) -> Option<Strand> {
+        self.standard_fields.strand
+    }
+
+    /// Returns a mutable reference to the strand.
+    pub fn strand_mut(&mut self) -> &mut Option<Strand> {
+        &mut self.standard_fields.strand
+    }
+
+    /// Returns the thick start.
+    pub fn thick_start(&self) -> Position {
+        self.standard_fields.thick_start
+    }
+
+    /// Returns a mutable reference to the thick start.
+    pub fn thick_start_mut(&mut self) -> &mut Position {
+        &mut self.standard_fields.thick_start
+    }
+
+    /// Returns the thick end.
+    pub fn thick_end(&self) -> Option<Position> {
+        self.standard_fields.thick_end
+    }
+
+    /// Returns a mutable reference to the thick end.
+    pub fn thick_end_mut(&mut self) -> &mut Option<Position> {
+        &mut self.standard_fields.thick_end
+    }
+
+    /// Returns the color.
+    pub fn color(&self) -> Option<Color> {
+        self.standard_fields.color
+    }
+
+    /// Returns a mutable reference to the color.
+    pub fn color_mut(&mut self) -> &mut Option<Color> {
+        &mut self.standard_fields.color
+    }
+
+    /// Returns the blocks.
+    ///
+    /// Each block is a (start, size) pair, where the start is relative to the feature start.
+    pub fn blocks(&self) -> &[(usize, usize)] {
+        &self.standard_fields.blocks
+    }
+
+    /// Returns a mutable reference to the blocks.
+    pub fn blocks_mut(&mut self) -> &mut Vec<(usize, usize)> {
+        &mut self.standard_fields.blocks
+    }
+}
+
+impl super::Record<12> for RecordBuf<12> {
+    fn reference_sequence_name(&self) -> &BStr {
+        self.reference_sequence_name()
+    }
+
+    fn feature_start(&self) -> io::Result<Position> {
+        Ok(self.feature_start())
+    }
+
+    fn feature_end(&self) -> Option<io::Result<Position>> {
+        self.feature_end().map(Ok)
+    }
+
+    fn name(&self) -> Option<Option<&BStr>> {
+        Some(self.name())
+    }
+
+    fn score(&self) -> Option<io::Result<u16>> {
+        Some(Ok(self.score()))
+    }
+
+    fn strand(&self) -> Option<io::Result<Option<Strand>>> {
+        Some(Ok(self.strand()))
+    }
+
+    fn thick_start(&self) -> Option<io::Result<Position>> {
+        Some(Ok(self.thick_start()))
+    }
+
+    fn thick_end(&self) -> Option<Option<io::Result<Position>>> {
+        Some(self.thick_end().map(Ok))
+    }
+
+    fn color(&self) -> Option<io::Result<Option<Color>>> {
+        Some(Ok(self.color()))
+    }
+
+    fn blocks(&self) -> Option<io::Result<Vec<(usize, usize)>>> {
+        Some(Ok(self.blocks().to_vec()))
+    }
+
+    fn other_fields(&self) -> Box<dyn super::record::OtherFields + '_> {
+        Box::new(self.other_fields())
+    }
+}
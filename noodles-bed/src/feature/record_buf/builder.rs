@@ -3,7 +3,7 @@
 use bstr::BString;
 use noodles_core::Position;
 
-use super::{OtherFields, RecordBuf, StandardFields, Strand};
+use super::{Color, OtherFields, RecordBuf, StandardFields, Strand};
 
 /// A feature record builder.
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -14,20 +14,30 @@ pub struct Builder<const N: usize> {
     name: Option<BString>,
     score: u16,
     strand: Option<Strand>,
+    thick_start: Option<Position>,
+    thick_end: Option<Position>,
+    color: Option<Color>,
+    blocks: Vec<(usize, usize)>,
     other_fields: OtherFields,
 }
 
 impl<const N: usize> Builder<N> {
     /// Builds a feature record buffer.
     pub fn build(self) -> RecordBuf<N> {
+        let feature_start = self.feature_start.unwrap_or(Position::MIN);
+
         RecordBuf {
             standard_fields: StandardFields {
                 reference_sequence_name: self.reference_sequence_name,
-                feature_start: self.feature_start.unwrap_or(Position::MIN),
+                feature_start,
                 feature_end: self.feature_end,
                 name: self.name,
                 score: self.score,
                 strand: self.strand,
+                thick_start: self.thick_start.unwrap_or(feature_start),
+                thick_end: self.thick_end,
+                color: self.color,
+                blocks: self.blocks,
             },
             other_fields: self.other_fields,
         }
@@ -194,3 +204,273 @@ impl Builder<6> {
         self
     }
 }
+
+impl Builder<7> {
+    /// Sets the reference sequence name.
+    pub fn set_reference_sequence_name<M>(mut self, reference_sequence_name: M) -> Self
+    where
+        M: Into<BString>,
+    {
+        self.reference_sequence_name = reference_sequence_name.into();
+        self
+    }
+
+    /// Sets the feature start position.
+    pub fn set_feature_start(mut self, feature_start: Position) -> Self {
+        self.feature_start = Some(feature_start);
+        self
+    }
+
+    /// Sets the feature end position.
+    pub fn set_feature_end(mut self, feature_end: Position) -> Self {
+        self.feature_end = Some(feature_end);
+        self
+    }
+
+    /// Sets the name.
+    pub fn set_name<M>(mut self, name: M) -> Self
+    where
+        M: Into<BString>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the score.
+    pub fn set_score(mut self, score: u16) -> Self {
+        self.score = score;
+        self
+    }
+
+    /// Sets the strand.
+    pub fn set_strand(mut self, strand: Strand) -> Self {
+        self.strand = Some(strand);
+        self
+    }
+
+    /// Sets the thick start position.
+    ///
+    /// This defaults to the feature start position.
+    pub fn set_thick_start(mut self, thick_start: Position) -> Self {
+        self.thick_start = Some(thick_start);
+        self
+    }
+
+    /// Sets the list of raw optional fields.
+    pub fn set_other_fields(mut self, other_fields: OtherFields) -> Self {
+        self.other_fields = other_fields;
+        self
+    }
+}
+
+impl Builder<8> {
+    /// Sets the reference sequence name.
+    pub fn set_reference_sequence_name<M>(mut self, reference_sequence_name: M) -> Self
+    where
+        M: Into<BString>,
+    {
+        self.reference_sequence_name = reference_sequence_name.into();
+        self
+    }
+
+    /// Sets the feature start position.
+    pub fn set_feature_start(mut self, feature_start: Position) -> Self {
+        self.feature_start = Some(feature_start);
+        self
+    }
+
+    /// Sets the feature end position.
+    pub fn set_feature_end(mut self, feature_end: Position) -> Self {
+        self.feature_end = Some(feature_end);
+        self
+    }
+
+    /// Sets the name.
+    pub fn set_name<M>(mut self, name: M) -> Self
+    where
+        M: Into<BString>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the score.
+    pub fn set_score(mut self, score: u16) -> Self {
+        self.score = score;
+        self
+    }
+
+    /// Sets the strand.
+    pub fn set_strand(mut self, strand: Strand) -> Self {
+        self.strand = Some(strand);
+        self
+    }
+
+    /// Sets the thick start position.
+    ///
+    /// This defaults to the feature start position.
+    pub fn set_thick_start(mut self, thick_start: Position) -> Self {
+        self.thick_start = Some(thick_start);
+        self
+    }
+
+    /// Sets the thick end position.
+    pub fn set_thick_end(mut self, thick_end: Position) -> Self {
+        self.thick_end = Some(thick_end);
+        self
+    }
+
+    /// Sets the list of raw optional fields.
+    pub fn set_other_fields(mut self, other_fields: OtherFields) -> Self {
+        self.other_fields = other_fields;
+        self
+    }
+}
+
+impl Builder<9> {
+    /// Sets the reference sequence name.
+    pub fn set_reference_sequence_name<M>(mut self, reference_sequence_name: M) -> Self
+    where
+        M: Into<BString>,
+    {
+        self.reference_sequence_name = reference_sequence_name.into();
+        self
+    }
+
+    /// Sets the feature start position.
+    pub fn set_feature_start(mut self, feature_start: Position) -> Self {
+        self.feature_start = Some(feature_start);
+        self
+    }
+
+    /// Sets the feature end position.
+    pub fn set_feature_end(mut self, feature_end: Position) -> Self {
+        self.feature_end = Some(feature_end);
+        self
+    }
+
+    /// Sets the name.
+    pub fn set_name<M>(mut self, name: M) -> Self
+    where
+        M: Into<BString>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the score.
+    pub fn set_score(mut self, score: u16) -> Self {
+        self.score = score;
+        self
+    }
+
+    /// Sets the strand.
+    pub fn set_strand(mut self, strand: Strand) -> Self {
+        self.strand = Some(strand);
+        self
+    }
+
+    /// Sets the thick start position.
+    ///
+    /// This defaults to the feature start position.
+    pub fn set_thick_start(mut self, thick_start: Position) -> Self {
+        self.thick_start = Some(thick_start);
+        self
+    }
+
+    /// Sets the thick end position.
+    pub fn set_thick_end(mut self, thick_end: Position) -> Self {
+        self.thick_end = Some(thick_end);
+        self
+    }
+
+    /// Sets the color.
+    pub fn set_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the list of raw optional fields.
+    pub fn set_other_fields(mut self, other_fields: OtherFields) -> Self {
+        self.other_fields = other_fields;
+        self
+    }
+}
+
+impl Builder<12> {
+    /// Sets the reference sequence name.
+    pub fn set_reference_sequence_name<M>(mut self, reference_sequence_name: M) -> Self
+    where
+        M: Into<BString>,
+    {
+        self.reference_sequence_name = reference_sequence_name.into();
+        self
+    }
+
+    /// Sets the feature start position.
+    pub fn set_feature_start(mut self, feature_start: Position) -> Self {
+        self.feature_start = Some(feature_start);
+        self
+    }
+
+    /// Sets the feature end position.
+    pub fn set_feature_end(mut self, feature_end: Position) -> Self {
+        self.feature_end = Some(feature_end);
+        self
+    }
+
+    /// Sets the name.
+    pub fn set_name<M>(mut self, name: M) -> Self
+    where
+        M: Into<BString>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the score.
+    pub fn set_score(mut self, score: u16) -> Self {
+        self.score = score;
+        self
+    }
+
+    /// Sets the strand.
+    pub fn set_strand(mut self, strand: Strand) -> Self {
+        self.strand = Some(strand);
+        self
+    }
+
+    /// Sets the thick start position.
+    ///
+    /// This defaults to the feature start position.
+    pub fn set_thick_start(mut self, thick_start: Position) -> Self {
+        self.thick_start = Some(thick_start);
+        self
+    }
+
+    /// Sets the thick end position.
+    pub fn set_thick_end(mut self, thick_end: Position) -> Self {
+        self.thick_end = Some(thick_end);
+        self
+    }
+
+    /// Sets the color.
+    pub fn set_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the blocks.
+    ///
+    /// Each block is a (start, size) pair, where the start is relative to the feature start.
+    pub fn set_blocks(mut self, blocks: Vec<(usize, usize)>) -> Self {
+        self.blocks = blocks;
+        self
+    }
+
+    /// Sets the list of raw optional fields.
+    pub fn set_other_fields(mut self, other_fields: OtherFields) -> Self {
+        self.other_fields = other_fields;
+        self
+    }
+}
@@ -114,3 +114,179 @@ impl RecordBuf<6> {
         Ok(builder.build())
     }
 }
+
+impl RecordBuf<7> {
+    /// Converts a BED7+ feature record to a BED7+ record buffer.
+    pub fn try_from_feature_record<R>(record: &R) -> io::Result<Self>
+    where
+        R: Record<7>,
+    {
+        let mut builder = RecordBuf::<7>::builder()
+            .set_reference_sequence_name(record.reference_sequence_name())
+            .set_feature_start(record.feature_start()?);
+
+        if let Some(feature_end) = record.feature_end().transpose()? {
+            builder = builder.set_feature_end(feature_end);
+        }
+
+        if let Some(Some(name)) = record.name() {
+            builder = builder.set_name(name);
+        }
+
+        if let Some(score) = record.score().transpose()? {
+            builder = builder.set_score(score);
+        }
+
+        if let Some(Some(strand)) = record.strand().transpose()? {
+            builder = builder.set_strand(strand);
+        }
+
+        if let Some(thick_start) = record.thick_start().transpose()? {
+            builder = builder.set_thick_start(thick_start);
+        }
+
+        let values: Vec<_> = record.other_fields().iter().map(Value::from).collect();
+        let other_fields = OtherFields::from(values);
+        builder = builder.set_other_fields(other_fields);
+
+        Ok(builder.build())
+    }
+}
+
+impl RecordBuf<8> {
+    /// Converts a BED8+ feature record to a BED8+ record buffer.
+    pub fn try_from_feature_record<R>(record: &R) -> io::Result<Self>
+    where
+        R: Record<8>,
+    {
+        let mut builder = RecordBuf::<8>::builder()
+            .set_reference_sequence_name(record.reference_sequence_name())
+            .set_feature_start(record.feature_start()?);
+
+        if let Some(feature_end) = record.feature_end().transpose()? {
+            builder = builder.set_feature_end(feature_end);
+        }
+
+        if let Some(Some(name)) = record.name() {
+            builder = builder.set_name(name);
+        }
+
+        if let Some(score) = record.score().transpose()? {
+            builder = builder.set_score(score);
+        }
+
+        if let Some(Some(strand)) = record.strand().transpose()? {
+            builder = builder.set_strand(strand);
+        }
+
+        if let Some(thick_start) = record.thick_start().transpose()? {
+            builder = builder.set_thick_start(thick_start);
+        }
+
+        if let Some(Some(thick_end)) = record.thick_end().map(Option::transpose).transpose()? {
+            builder = builder.set_thick_end(thick_end);
+        }
+
+        let values: Vec<_> = record.other_fields().iter().map(Value::from).collect();
+        let other_fields = OtherFields::from(values);
+        builder = builder.set_other_fields(other_fields);
+
+        Ok(builder.build())
+    }
+}
+
+impl RecordBuf<9> {
+    /// Converts a BED9+ feature record to a BED9+ record buffer.
+    pub fn try_from_feature_record<R>(record: &R) -> io::Result<Self>
+    where
+        R: Record<9>,
+    {
+        let mut builder = RecordBuf::<9>::builder()
+            .set_reference_sequence_name(record.reference_sequence_name())
+            .set_feature_start(record.feature_start()?);
+
+        if let Some(feature_end) = record.feature_end().transpose()? {
+            builder = builder.set_feature_end(feature_end);
+        }
+
+        if let Some(Some(name)) = record.name() {
+            builder = builder.set_name(name);
+        }
+
+        if let Some(score) = record.score().transpose()? {
+            builder = builder.set_score(score);
+        }
+
+        if let Some(Some(strand)) = record.strand().transpose()? {
+            builder = builder.set_strand(strand);
+        }
+
+        if let Some(thick_start) = record.thick_start().transpose()? {
+            builder = builder.set_thick_start(thick_start);
+        }
+
+        if let Some(Some(thick_end)) = record.thick_end().map(Option::transpose).transpose()? {
+            builder = builder.set_thick_end(thick_end);
+        }
+
+        if let Some(Some(color)) = record.color().transpose()? {
+            builder = builder.set_color(color);
+        }
+
+        let values: Vec<_> = record.other_fields().iter().map(Value::from).collect();
+        let other_fields = OtherFields::from(values);
+        builder = builder.set_other_fields(other_fields);
+
+        Ok(builder.build())
+    }
+}
+
+impl RecordBuf<12> {
+    /// Converts a BED12+ feature record to a BED12+ record buffer.
+    pub fn try_from_feature_record<R>(record: &R) -> io::Result<Self>
+    where
+        R: Record<12>,
+    {
+        let mut builder = RecordBuf::<12>::builder()
+            .set_reference_sequence_name(record.reference_sequence_name())
+            .set_feature_start(record.feature_start()?);
+
+        if let Some(feature_end) = record.feature_end().transpose()? {
+            builder = builder.set_feature_end(feature_end);
+        }
+
+        if let Some(Some(name)) = record.name() {
+            builder = builder.set_name(name);
+        }
+
+        if let Some(score) = record.score().transpose()? {
+            builder = builder.set_score(score);
+        }
+
+        if let Some(Some(strand)) = record.strand().transpose()? {
+            builder = builder.set_strand(strand);
+        }
+
+        if let Some(thick_start) = record.thick_start().transpose()? {
+            builder = builder.set_thick_start(thick_start);
+        }
+
+        if let Some(Some(thick_end)) = record.thick_end().map(Option::transpose).transpose()? {
+            builder = builder.set_thick_end(thick_end);
+        }
+
+        if let Some(Some(color)) = record.color().transpose()? {
+            builder = builder.set_color(color);
+        }
+
+        if let Some(blocks) = record.blocks().transpose()? {
+            builder = builder.set_blocks(blocks);
+        }
+
+        let values: Vec<_> = record.other_fields().iter().map(Value::from).collect();
+        let other_fields = OtherFields::from(values);
+        builder = builder.set_other_fields(other_fields);
+
+        Ok(builder.build())
+    }
+}
@@ -6,7 +6,10 @@ mod record;
 pub use self::builder::Builder;
 use std::io::{self, BufRead};
 
-use self::record::{read_record_3, read_record_4, read_record_5, read_record_6};
+use self::record::{
+    read_record_12, read_record_3, read_record_4, read_record_5, read_record_6, read_record_7,
+    read_record_8, read_record_9,
+};
 use crate::Record;
 
 /// A BED reader.
@@ -159,3 +162,87 @@ where
         read_record_6(&mut self.inner, record)
     }
 }
+
+impl<R> Reader<7, R>
+where
+    R: BufRead,
+{
+    /// Reads a BED7+ record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    /// let mut reader = bed::io::Reader::<7, _>::new(io::empty());
+    /// let mut record = bed::Record::default();
+    /// reader.read_record(&mut record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_record(&mut self, record: &mut Record<7>) -> io::Result<usize> {
+        read_record_7(&mut self.inner, record)
+    }
+}
+
+impl<R> Reader<8, R>
+where
+    R: BufRead,
+{
+    /// Reads a BED8+ record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    /// let mut reader = bed::io::Reader::<8, _>::new(io::empty());
+    /// let mut record = bed::Record::default();
+    /// reader.read_record(&mut record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_record(&mut self, record: &mut Record<8>) -> io::Result<usize> {
+        read_record_8(&mut self.inner, record)
+    }
+}
+
+impl<R> Reader<9, R>
+where
+    R: BufRead,
+{
+    /// Reads a BED9+ record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    /// let mut reader = bed::io::Reader::<9, _>::new(io::empty());
+    /// let mut record = bed::Record::default();
+    /// reader.read_record(&mut record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_record(&mut self, record: &mut Record<9>) -> io::Result<usize> {
+        read_record_9(&mut self.inner, record)
+    }
+}
+
+impl<R> Reader<12, R>
+where
+    R: BufRead,
+{
+    /// Reads a BED12+ record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    /// let mut reader = bed::io::Reader::<12, _>::new(io::empty());
+    /// let mut record = bed::Record::default();
+    /// reader.read_record(&mut record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_record(&mut self, record: &mut Record<12>) -> io::Result<usize> {
+        read_record_12(&mut self.inner, record)
+    }
+}
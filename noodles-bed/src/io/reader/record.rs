@@ -144,6 +144,64 @@ where
     Ok(len)
 }
 
+pub(super) fn read_record_7<R>(reader: &mut R, record: &mut Record<7>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    read_record(reader, record)
+}
+
+pub(super) fn read_record_8<R>(reader: &mut R, record: &mut Record<8>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    read_record(reader, record)
+}
+
+pub(super) fn read_record_9<R>(reader: &mut R, record: &mut Record<9>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    read_record(reader, record)
+}
+
+pub(super) fn read_record_12<R>(reader: &mut R, record: &mut Record<12>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    read_record(reader, record)
+}
+
+fn read_record<R, const N: usize>(reader: &mut R, record: &mut Record<N>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    let fields = &mut record.0;
+
+    let dst = &mut fields.buf;
+    dst.clear();
+
+    let bounds = &mut fields.bounds;
+    bounds.other_fields_ends.clear();
+
+    let mut len = 0;
+
+    for end in &mut bounds.standard_fields_ends[..N - 1] {
+        len += read_required_field(reader, dst)?;
+        *end = dst.len();
+    }
+
+    let (n, is_eol) = read_field(reader, dst)?;
+    len += n;
+    bounds.standard_fields_ends[N - 1] = dst.len();
+
+    if !is_eol {
+        len += read_other_fields(reader, dst, bounds)?;
+    }
+
+    Ok(len)
+}
+
 fn read_other_fields<R, const N: usize>(
     reader: &mut R,
     dst: &mut Vec<u8>,
@@ -254,4 +312,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_record_12() -> io::Result<()> {
+        let mut record = Record::default();
+
+        let mut src = &b"sq0\t0\t1\t.\t0\t.\t0\t1\t0\t1\t1,\t0,\n"[..];
+        read_record_12(&mut src, &mut record)?;
+        assert_eq!(record.0.buf, b"sq001.0.01011,0,");
+        assert_eq!(
+            record.0.bounds.standard_fields_ends,
+            [3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 14, 16]
+        );
+        assert!(record.0.bounds.other_fields_ends.is_empty());
+
+        let mut src = &b"sq0\t0\t1\t.\t0\t.\t0\t1\t0\t1\t1\n"[..];
+        assert!(matches!(
+            read_record_12(&mut src, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
@@ -6,7 +6,10 @@ mod record;
 use std::io::{self, Write};
 
 pub use self::builder::Builder;
-use self::record::{write_record_3, write_record_4, write_record_5, write_record_6};
+use self::record::{
+    write_record_12, write_record_3, write_record_4, write_record_5, write_record_6,
+    write_record_7, write_record_8, write_record_9,
+};
 use crate::Record;
 
 /// A BED writer.
@@ -235,3 +238,163 @@ where
         write_record_6(&mut self.inner, record)
     }
 }
+
+impl<W> Writer<7, W>
+where
+    W: Write,
+{
+    /// Writes a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    /// let mut writer = bed::io::Writer::<7, _>::new(io::sink());
+    /// let record = bed::Record::default();
+    /// writer.write_record(&record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record<7>) -> io::Result<()> {
+        self.write_feature_record(record)
+    }
+
+    /// Writes a feature record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    /// let mut writer = bed::io::Writer::<7, _>::new(io::sink());
+    /// let record = bed::Record::default();
+    /// writer.write_feature_record(&record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_feature_record<R>(&mut self, record: &R) -> io::Result<()>
+    where
+        R: crate::feature::Record<7>,
+    {
+        write_record_7(&mut self.inner, record)
+    }
+}
+
+impl<W> Writer<8, W>
+where
+    W: Write,
+{
+    /// Writes a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    /// let mut writer = bed::io::Writer::<8, _>::new(io::sink());
+    /// let record = bed::Record::default();
+    /// writer.write_record(&record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record<8>) -> io::Result<()> {
+        self.write_feature_record(record)
+    }
+
+    /// Writes a feature record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    /// let mut writer = bed::io::Writer::<8, _>::new(io::sink());
+    /// let record = bed::Record::default();
+    /// writer.write_feature_record(&record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_feature_record<R>(&mut self, record: &R) -> io::Result<()>
+    where
+        R: crate::feature::Record<8>,
+    {
+        write_record_8(&mut self.inner, record)
+    }
+}
+
+impl<W> Writer<9, W>
+where
+    W: Write,
+{
+    /// Writes a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    /// let mut writer = bed::io::Writer::<9, _>::new(io::sink());
+    /// let record = bed::Record::default();
+    /// writer.write_record(&record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record<9>) -> io::Result<()> {
+        self.write_feature_record(record)
+    }
+
+    /// Writes a feature record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    /// let mut writer = bed::io::Writer::<9, _>::new(io::sink());
+    /// let record = bed::Record::default();
+    /// writer.write_feature_record(&record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_feature_record<R>(&mut self, record: &R) -> io::Result<()>
+    where
+        R: crate::feature::Record<9>,
+    {
+        write_record_9(&mut self.inner, record)
+    }
+}
+
+impl<W> Writer<12, W>
+where
+    W: Write,
+{
+    /// Writes a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    /// let mut writer = bed::io::Writer::<12, _>::new(io::sink());
+    /// let record = bed::Record::default();
+    /// writer.write_record(&record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record<12>) -> io::Result<()> {
+        self.write_feature_record(record)
+    }
+
+    /// Writes a feature record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bed as bed;
+    /// let mut writer = bed::io::Writer::<12, _>::new(io::sink());
+    /// let record = bed::Record::default();
+    /// writer.write_feature_record(&record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_feature_record<R>(&mut self, record: &R) -> io::Result<()>
+    where
+        R: crate::feature::Record<12>,
+    {
+        write_record_12(&mut self.inner, record)
+    }
+}
@@ -1,3 +1,5 @@
+mod blocks;
+mod color;
 mod feature_end;
 mod feature_start;
 mod name;
@@ -9,9 +11,10 @@ mod strand;
 use std::io::{self, Write};
 
 use self::{
-    feature_end::write_feature_end, feature_start::write_feature_start, name::write_name,
-    other_fields::write_other_fields, reference_sequence_name::write_reference_sequence_name,
-    score::write_score, strand::write_strand,
+    blocks::write_blocks, color::write_color, feature_end::write_feature_end,
+    feature_start::write_feature_start, name::write_name, other_fields::write_other_fields,
+    reference_sequence_name::write_reference_sequence_name, score::write_score,
+    strand::write_strand,
 };
 use crate::feature::Record;
 
@@ -142,6 +145,252 @@ where
     Ok(())
 }
 
+pub(super) fn write_record_7<W, R>(writer: &mut W, record: &R) -> io::Result<()>
+where
+    W: Write,
+    R: Record<7>,
+{
+    write_reference_sequence_name(writer, record.reference_sequence_name())?;
+
+    write_separator(writer)?;
+    let feature_start = record.feature_start()?;
+    write_feature_start(writer, feature_start)?;
+
+    write_separator(writer)?;
+    let feature_end = record.feature_end().transpose()?;
+    write_feature_end(writer, feature_end)?;
+
+    write_separator(writer)?;
+    let name = record
+        .name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing name"))?;
+    write_name(writer, name)?;
+
+    write_separator(writer)?;
+    let score = record
+        .score()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing score"))?;
+    write_score(writer, score)?;
+
+    write_separator(writer)?;
+    let strand = record
+        .strand()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing strand"))?;
+    write_strand(writer, strand)?;
+
+    write_separator(writer)?;
+    let thick_start = record
+        .thick_start()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing thick start"))?;
+    write_feature_start(writer, thick_start)?;
+
+    write_other_fields(writer, record.other_fields().as_ref())?;
+
+    write_newline(writer)?;
+
+    Ok(())
+}
+
+pub(super) fn write_record_8<W, R>(writer: &mut W, record: &R) -> io::Result<()>
+where
+    W: Write,
+    R: Record<8>,
+{
+    write_reference_sequence_name(writer, record.reference_sequence_name())?;
+
+    write_separator(writer)?;
+    let feature_start = record.feature_start()?;
+    write_feature_start(writer, feature_start)?;
+
+    write_separator(writer)?;
+    let feature_end = record.feature_end().transpose()?;
+    write_feature_end(writer, feature_end)?;
+
+    write_separator(writer)?;
+    let name = record
+        .name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing name"))?;
+    write_name(writer, name)?;
+
+    write_separator(writer)?;
+    let score = record
+        .score()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing score"))?;
+    write_score(writer, score)?;
+
+    write_separator(writer)?;
+    let strand = record
+        .strand()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing strand"))?;
+    write_strand(writer, strand)?;
+
+    write_separator(writer)?;
+    let thick_start = record
+        .thick_start()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing thick start"))?;
+    write_feature_start(writer, thick_start)?;
+
+    write_separator(writer)?;
+    let thick_end = record
+        .thick_end()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing thick end"))?
+        .transpose()?;
+    write_feature_end(writer, thick_end)?;
+
+    write_other_fields(writer, record.other_fields().as_ref())?;
+
+    write_newline(writer)?;
+
+    Ok(())
+}
+
+pub(super) fn write_record_9<W, R>(writer: &mut W, record: &R) -> io::Result<()>
+where
+    W: Write,
+    R: Record<9>,
+{
+    write_reference_sequence_name(writer, record.reference_sequence_name())?;
+
+    write_separator(writer)?;
+    let feature_start = record.feature_start()?;
+    write_feature_start(writer, feature_start)?;
+
+    write_separator(writer)?;
+    let feature_end = record.feature_end().transpose()?;
+    write_feature_end(writer, feature_end)?;
+
+    write_separator(writer)?;
+    let name = record
+        .name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing name"))?;
+    write_name(writer, name)?;
+
+    write_separator(writer)?;
+    let score = record
+        .score()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing score"))?;
+    write_score(writer, score)?;
+
+    write_separator(writer)?;
+    let strand = record
+        .strand()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing strand"))?;
+    write_strand(writer, strand)?;
+
+    write_separator(writer)?;
+    let thick_start = record
+        .thick_start()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing thick start"))?;
+    write_feature_start(writer, thick_start)?;
+
+    write_separator(writer)?;
+    let thick_end = record
+        .thick_end()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing thick end"))?
+        .transpose()?;
+    write_feature_end(writer, thick_end)?;
+
+    write_separator(writer)?;
+    let color = record
+        .color()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing color"))?;
+    write_color(writer, color)?;
+
+    write_other_fields(writer, record.other_fields().as_ref())?;
+
+    write_newline(writer)?;
+
+    Ok(())
+}
+
+pub(super) fn write_record_12<W, R>(writer: &mut W, record: &R) -> io::Result<()>
+where
+    W: Write,
+    R: Record<12>,
+{
+    write_reference_sequence_name(writer, record.reference_sequence_name())?;
+
+    write_separator(writer)?;
+    let feature_start = record.feature_start()?;
+    write_feature_start(writer, feature_start)?;
+
+    write_separator(writer)?;
+    let feature_end = record.feature_end().transpose()?;
+    write_feature_end(writer, feature_end)?;
+
+    write_separator(writer)?;
+    let name = record
+        .name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing name"))?;
+    write_name(writer, name)?;
+
+    write_separator(writer)?;
+    let score = record
+        .score()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing score"))?;
+    write_score(writer, score)?;
+
+    write_separator(writer)?;
+    let strand = record
+        .strand()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing strand"))?;
+    write_strand(writer, strand)?;
+
+    write_separator(writer)?;
+    let thick_start = record
+        .thick_start()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing thick start"))?;
+    write_feature_start(writer, thick_start)?;
+
+    write_separator(writer)?;
+    let thick_end = record
+        .thick_end()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing thick end"))?
+        .transpose()?;
+    write_feature_end(writer, thick_end)?;
+
+    write_separator(writer)?;
+    let color = record
+        .color()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing color"))?;
+    write_color(writer, color)?;
+
+    write_separator(writer)?;
+    let blocks = record
+        .blocks()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing blocks"))?;
+
+    if !crate::feature::record::blocks::is_valid(feature_start, feature_end, &blocks) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "blocks out of feature bounds",
+        ));
+    }
+
+    write_blocks(writer, &blocks)?;
+
+    write_other_fields(writer, record.other_fields().as_ref())?;
+
+    write_newline(writer)?;
+
+    Ok(())
+}
+
 fn write_separator<W>(writer: &mut W) -> io::Result<()>
 where
     W: Write,
@@ -197,4 +446,76 @@ mod tests {
         assert_eq!(buf, b"sq0\t0\t1\t.\t0\t.\n");
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_7() -> io::Result<()> {
+        let mut buf = Vec::new();
+        let record = crate::Record::<7>::default();
+        write_record_7(&mut buf, &record)?;
+        assert_eq!(buf, b"sq0\t0\t1\t.\t0\t.\t0\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_8() -> io::Result<()> {
+        let mut buf = Vec::new();
+        let record = crate::Record::<8>::default();
+        write_record_8(&mut buf, &record)?;
+        assert_eq!(buf, b"sq0\t0\t1\t.\t0\t.\t0\t1\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_9() -> io::Result<()> {
+        let mut buf = Vec::new();
+        let record = crate::Record::<9>::default();
+        write_record_9(&mut buf, &record)?;
+        assert_eq!(buf, b"sq0\t0\t1\t.\t0\t.\t0\t1\t0\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_12() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        use crate::feature::{record::Color, RecordBuf};
+
+        let mut buf = Vec::new();
+        let record = crate::Record::<12>::default();
+        write_record_12(&mut buf, &record)?;
+        assert_eq!(buf, b"sq0\t0\t1\t.\t0\t.\t0\t1\t0\t1\t1\t0\n");
+
+        let record = RecordBuf::<12>::builder()
+            .set_reference_sequence_name("sq0")
+            .set_feature_start(Position::try_from(1001)?)
+            .set_feature_end(Position::try_from(2000)?)
+            .set_name("ndls0")
+            .set_thick_start(Position::try_from(1101)?)
+            .set_thick_end(Position::try_from(1900)?)
+            .set_color(Color::new(255, 0, 0))
+            .set_blocks(vec![(0, 200), (800, 200)])
+            .build();
+
+        buf.clear();
+        write_record_12(&mut buf, &record)?;
+        assert_eq!(
+            buf,
+            b"sq0\t1000\t2000\tndls0\t0\t.\t1100\t1900\t255,0,0\t2\t200,200\t0,800\n"
+        );
+
+        let record = RecordBuf::<12>::builder()
+            .set_reference_sequence_name("sq0")
+            .set_feature_start(Position::try_from(1001)?)
+            .set_feature_end(Position::try_from(2000)?)
+            .set_blocks(vec![(0, 200), (900, 200)])
+            .build();
+
+        buf.clear();
+        assert!(matches!(
+            write_record_12(&mut buf, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,71 @@
+use std::io::{self, Write};
+
+use lexical_core::FormattedSize;
+
+use super::write_separator;
+
+pub(super) fn write_blocks<W>(writer: &mut W, blocks: &[(usize, usize)]) -> io::Result<()>
+where
+    W: Write,
+{
+    write_usize(writer, blocks.len())?;
+
+    write_separator(writer)?;
+    write_list(writer, blocks.iter().map(|(_, size)| *size))?;
+
+    write_separator(writer)?;
+    write_list(writer, blocks.iter().map(|(start, _)| *start))?;
+
+    Ok(())
+}
+
+fn write_list<W, I>(writer: &mut W, values: I) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = usize>,
+{
+    const DELIMITER: &[u8] = b",";
+
+    for (i, n) in values.into_iter().enumerate() {
+        if i > 0 {
+            writer.write_all(DELIMITER)?;
+        }
+
+        write_usize(writer, n)?;
+    }
+
+    Ok(())
+}
+
+fn write_usize<W>(writer: &mut W, n: usize) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut dst = [0; usize::FORMATTED_SIZE_DECIMAL];
+    let buf = lexical_core::write(n, &mut dst);
+    writer.write_all(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_blocks() -> io::Result<()> {
+        let mut buf = Vec::new();
+
+        buf.clear();
+        write_blocks(&mut buf, &[])?;
+        assert_eq!(buf, b"0\t\t");
+
+        buf.clear();
+        write_blocks(&mut buf, &[(0, 2)])?;
+        assert_eq!(buf, b"1\t2\t0");
+
+        buf.clear();
+        write_blocks(&mut buf, &[(0, 2), (8, 3)])?;
+        assert_eq!(buf, b"2\t2,3\t0,8");
+
+        Ok(())
+    }
+}
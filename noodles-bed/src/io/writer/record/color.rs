@@ -0,0 +1,47 @@
+use std::io::{self, Write};
+
+use lexical_core::FormattedSize;
+
+use crate::feature::record::Color;
+
+pub(super) fn write_color<W>(writer: &mut W, color: Option<Color>) -> io::Result<()>
+where
+    W: Write,
+{
+    const MISSING: &[u8] = b"0";
+    const DELIMITER: &[u8] = b",";
+
+    let Some(color) = color else {
+        return writer.write_all(MISSING);
+    };
+
+    let mut dst = [0; u8::FORMATTED_SIZE_DECIMAL];
+
+    writer.write_all(lexical_core::write(color.red(), &mut dst))?;
+    writer.write_all(DELIMITER)?;
+    writer.write_all(lexical_core::write(color.green(), &mut dst))?;
+    writer.write_all(DELIMITER)?;
+    writer.write_all(lexical_core::write(color.blue(), &mut dst))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_color() -> io::Result<()> {
+        let mut buf = Vec::new();
+
+        buf.clear();
+        write_color(&mut buf, None)?;
+        assert_eq!(buf, b"0");
+
+        buf.clear();
+        write_color(&mut buf, Some(Color::new(255, 128, 0)))?;
+        assert_eq!(buf, b"255,128,0");
+
+        Ok(())
+    }
+}
@@ -8,7 +8,7 @@ use noodles_core::Position;
 
 use self::fields::Fields;
 pub use self::other_fields::OtherFields;
-use crate::feature::record::Strand;
+use crate::feature::record::{Color, Strand};
 
 /// A BED record.
 #[derive(Clone, Eq, PartialEq)]
@@ -124,6 +124,186 @@ impl Record<6> {
     }
 }
 
+impl Record<7> {
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &BStr {
+        self.0.reference_sequence_name()
+    }
+
+    /// Returns the feature start.
+    pub fn feature_start(&self) -> io::Result<Position> {
+        self.0.feature_start()
+    }
+
+    /// Returns the feature end.
+    pub fn feature_end(&self) -> Option<io::Result<Position>> {
+        self.0.feature_end()
+    }
+
+    /// Returns the name.
+    pub fn name(&self) -> Option<&BStr> {
+        self.0.name()
+    }
+
+    /// Returns the score.
+    pub fn score(&self) -> io::Result<u16> {
+        self.0.score()
+    }
+
+    /// Returns the strand.
+    pub fn strand(&self) -> io::Result<Option<Strand>> {
+        self.0.strand()
+    }
+
+    /// Returns the thick start.
+    pub fn thick_start(&self) -> io::Result<Position> {
+        self.0.thick_start()
+    }
+}
+
+impl Record<8> {
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &BStr {
+        self.0.reference_sequence_name()
+    }
+
+    /// Returns the feature start.
+    pub fn feature_start(&self) -> io::Result<Position> {
+        self.0.feature_start()
+    }
+
+    /// Returns the feature end.
+    pub fn feature_end(&self) -> Option<io::Result<Position>> {
+        self.0.feature_end()
+    }
+
+    /// Returns the name.
+    pub fn name(&self) -> Option<&BStr> {
+        self.0.name()
+    }
+
+    /// Returns the score.
+    pub fn score(&self) -> io::Result<u16> {
+        self.0.score()
+    }
+
+    /// Returns the strand.
+    pub fn strand(&self) -> io::Result<Option<Strand>> {
+        self.0.strand()
+    }
+
+    /// Returns the thick start.
+    pub fn thick_start(&self) -> io::Result<Position> {
+        self.0.thick_start()
+    }
+
+    /// Returns the thick end.
+    pub fn thick_end(&self) -> Option<io::Result<Position>> {
+        self.0.thick_end()
+    }
+}
+
+impl Record<9> {
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &BStr {
+        self.0.reference_sequence_name()
+    }
+
+    /// Returns the feature start.
+    pub fn feature_start(&self) -> io::Result<Position> {
+        self.0.feature_start()
+    }
+
+    /// Returns the feature end.
+    pub fn feature_end(&self) -> Option<io::Result<Position>> {
+        self.0.feature_end()
+    }
+
+    /// Returns the name.
+    pub fn name(&self) -> Option<&BStr> {
+        self.0.name()
+    }
+
+    /// Returns the score.
+    pub fn score(&self) -> io::Result<u16> {
+        self.0.score()
+    }
+
+    /// Returns the strand.
+    pub fn strand(&self) -> io::Result<Option<Strand>> {
+        self.0.strand()
+    }
+
+    /// Returns the thick start.
+    pub fn thick_start(&self) -> io::Result<Position> {
+        self.0.thick_start()
+    }
+
+    /// Returns the thick end.
+    pub fn thick_end(&self) -> Option<io::Result<Position>> {
+        self.0.thick_end()
+    }
+
+    /// Returns the color.
+    pub fn color(&self) -> io::Result<Option<Color>> {
+        self.0.color()
+    }
+}
+
+impl Record<12> {
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &BStr {
+        self.0.reference_sequence_name()
+    }
+
+    /// Returns the feature start.
+    pub fn feature_start(&self) -> io::Result<Position> {
+        self.0.feature_start()
+    }
+
+    /// Returns the feature end.
+    pub fn feature_end(&self) -> Option<io::Result<Position>> {
+        self.0.feature_end()
+    }
+
+    /// Returns the name.
+    pub fn name(&self) -> Option<&BStr> {
+        self.0.name()
+    }
+
+    /// Returns the score.
+    pub fn score(&self) -> io::Result<u16> {
+        self.0.score()
+    }
+
+    /// Returns the strand.
+    pub fn strand(&self) -> io::Result<Option<Strand>> {
+        self.0.strand()
+    }
+
+    /// Returns the thick start.
+    pub fn thick_start(&self) -> io::Result<Position> {
+        self.0.thick_start()
+    }
+
+    /// Returns the thick end.
+    pub fn thick_end(&self) -> Option<io::Result<Position>> {
+        self.0.thick_end()
+    }
+
+    /// Returns the color.
+    pub fn color(&self) -> io::Result<Option<Color>> {
+        self.0.color()
+    }
+
+    /// Returns the blocks.
+    ///
+    /// Each block is a (start, size) pair, where the start is relative to the feature start.
+    pub fn blocks(&self) -> io::Result<Vec<(usize, usize)>> {
+        self.0.blocks()
+    }
+}
+
 impl fmt::Debug for Record<3> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Record")
@@ -174,6 +354,72 @@ impl fmt::Debug for Record<6> {
     }
 }
 
+impl fmt::Debug for Record<7> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Record")
+            .field("reference_sequence_name", &self.reference_sequence_name())
+            .field("feature_start", &self.feature_start())
+            .field("feature_end", &self.feature_end())
+            .field("name", &self.name())
+            .field("score", &self.score())
+            .field("strand", &self.strand())
+            .field("thick_start", &self.thick_start())
+            .field("other_fields", &self.other_fields())
+            .finish()
+    }
+}
+
+impl fmt::Debug for Record<8> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Record")
+            .field("reference_sequence_name", &self.reference_sequence_name())
+            .field("feature_start", &self.feature_start())
+            .field("feature_end", &self.feature_end())
+            .field("name", &self.name())
+            .field("score", &self.score())
+            .field("strand", &self.strand())
+            .field("thick_start", &self.thick_start())
+            .field("thick_end", &self.thick_end())
+            .field("other_fields", &self.other_fields())
+            .finish()
+    }
+}
+
+impl fmt::Debug for Record<9> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Record")
+            .field("reference_sequence_name", &self.reference_sequence_name())
+            .field("feature_start", &self.feature_start())
+            .field("feature_end", &self.feature_end())
+            .field("name", &self.name())
+            .field("score", &self.score())
+            .field("strand", &self.strand())
+            .field("thick_start", &self.thick_start())
+            .field("thick_end", &self.thick_end())
+            .field("color", &self.color())
+            .field("other_fields", &self.other_fields())
+            .finish()
+    }
+}
+
+impl fmt::Debug for Record<12> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Record")
+            .field("reference_sequence_name", &self.reference_sequence_name())
+            .field("feature_start", &self.feature_start())
+            .field("feature_end", &self.feature_end())
+            .field("name", &self.name())
+            .field("score", &self.score())
+            .field("strand", &self.strand())
+            .field("thick_start", &self.thick_start())
+            .field("thick_end", &self.thick_end())
+            .field("color", &self.color())
+            .field("blocks", &self.blocks())
+            .field("other_fields", &self.other_fields())
+            .finish()
+    }
+}
+
 impl Default for Record<3> {
     fn default() -> Self {
         Self(Fields::default())
@@ -198,6 +444,30 @@ impl Default for Record<6> {
     }
 }
 
+impl Default for Record<7> {
+    fn default() -> Self {
+        Self(Fields::default())
+    }
+}
+
+impl Default for Record<8> {
+    fn default() -> Self {
+        Self(Fields::default())
+    }
+}
+
+impl Default for Record<9> {
+    fn default() -> Self {
+        Self(Fields::default())
+    }
+}
+
+impl Default for Record<12> {
+    fn default() -> Self {
+        Self(Fields::default())
+    }
+}
+
 impl crate::feature::Record<3> for Record<3> {
     fn reference_sequence_name(&self) -> &BStr {
         self.reference_sequence_name()
@@ -317,3 +587,163 @@ impl crate::feature::Record<6> for Record<6> {
         Box::new(self.other_fields())
     }
 }
+
+impl crate::feature::Record<7> for Record<7> {
+    fn reference_sequence_name(&self) -> &BStr {
+        self.reference_sequence_name()
+    }
+
+    fn feature_start(&self) -> io::Result<Position> {
+        self.feature_start()
+    }
+
+    fn feature_end(&self) -> Option<io::Result<Position>> {
+        self.feature_end()
+    }
+
+    fn name(&self) -> Option<Option<&BStr>> {
+        Some(self.name())
+    }
+
+    fn score(&self) -> Option<io::Result<u16>> {
+        Some(self.score())
+    }
+
+    fn strand(&self) -> Option<io::Result<Option<Strand>>> {
+        Some(self.strand())
+    }
+
+    fn thick_start(&self) -> Option<io::Result<Position>> {
+        Some(self.thick_start())
+    }
+
+    fn other_fields(&self) -> Box<dyn crate::feature::record::OtherFields + '_> {
+        Box::new(self.other_fields())
+    }
+}
+
+impl crate::feature::Record<8> for Record<8> {
+    fn reference_sequence_name(&self) -> &BStr {
+        self.reference_sequence_name()
+    }
+
+    fn feature_start(&self) -> io::Result<Position> {
+        self.feature_start()
+    }
+
+    fn feature_end(&self) -> Option<io::Result<Position>> {
+        self.feature_end()
+    }
+
+    fn name(&self) -> Option<Option<&BStr>> {
+        Some(self.name())
+    }
+
+    fn score(&self) -> Option<io::Result<u16>> {
+        Some(self.score())
+    }
+
+    fn strand(&self) -> Option<io::Result<Option<Strand>>> {
+        Some(self.strand())
+    }
+
+    fn thick_start(&self) -> Option<io::Result<Position>> {
+        Some(self.thick_start())
+    }
+
+    fn thick_end(&self) -> Option<Option<io::Result<Position>>> {
+        Some(self.thick_end())
+    }
+
+    fn other_fields(&self) -> Box<dyn crate::feature::record::OtherFields + '_> {
+        Box::new(self.other_fields())
+    }
+}
+
+impl crate::feature::Record<9> for Record<9> {
+    fn reference_sequence_name(&self) -> &BStr {
+        self.reference_sequence_name()
+    }
+
+    fn feature_start(&self) -> io::Result<Position> {
+        self.feature_start()
+    }
+
+    fn feature_end(&self) -> Option<io::Result<Position>> {
+        self.feature_end()
+    }
+
+    fn name(&self) -> Option<Option<&BStr>> {
+        Some(self.name())
+    }
+
+    fn score(&self) -> Option<io::Result<u16>> {
+        Some(self.score())
+    }
+
+    fn strand(&self) -> Option<io::Result<Option<Strand>>> {
+        Some(self.strand())
+    }
+
+    fn thick_start(&self) -> Option<io::Result<Position>> {
+        Some(self.thick_start())
+    }
+
+    fn thick_end(&self) -> Option<Option<io::Result<Position>>> {
+        Some(self.thick_end())
+    }
+
+    fn color(&self) -> Option<io::Result<Option<Color>>> {
+        Some(self.color())
+    }
+
+    fn other_fields(&self) -> Box<dyn crate::feature::record::OtherFields + '_> {
+        Box::new(self.other_fields())
+    }
+}
+
+impl crate::feature::Record<12> for Record<12> {
+    fn reference_sequence_name(&self) -> &BStr {
+        self.reference_sequence_name()
+    }
+
+    fn feature_start(&self) -> io::Result<Position> {
+        self.feature_start()
+    }
+
+    fn feature_end(&self) -> Option<io::Result<Position>> {
+        self.feature_end()
+    }
+
+    fn name(&self) -> Option<Option<&BStr>> {
+        Some(self.name())
+    }
+
+    fn score(&self) -> Option<io::Result<u16>> {
+        Some(self.score())
+    }
+
+    fn strand(&self) -> Option<io::Result<Option<Strand>>> {
+        Some(self.strand())
+    }
+
+    fn thick_start(&self) -> Option<io::Result<Position>> {
+        Some(self.thick_start())
+    }
+
+    fn thick_end(&self) -> Option<Option<io::Result<Position>>> {
+        Some(self.thick_end())
+    }
+
+    fn color(&self) -> Option<io::Result<Option<Color>>> {
+        Some(self.color())
+    }
+
+    fn blocks(&self) -> Option<io::Result<Vec<(usize, usize)>>> {
+        Some(self.blocks())
+    }
+
+    fn other_fields(&self) -> Box<dyn crate::feature::record::OtherFields + '_> {
+        Box::new(self.other_fields())
+    }
+}
@@ -7,7 +7,7 @@ use lexical_core::FromLexical;
 use noodles_core::Position;
 
 pub(crate) use self::bounds::Bounds;
-use crate::feature::record::Strand;
+use crate::feature::record::{blocks, Color, Strand};
 
 #[derive(Clone, Eq, PartialEq)]
 pub(crate) struct Fields<const N: usize> {
@@ -119,6 +119,211 @@ impl Fields<6> {
     }
 }
 
+impl Fields<7> {
+    pub(super) fn reference_sequence_name(&self) -> &BStr {
+        let src = &self.buf[self.bounds.reference_sequence_name_range()];
+        parse_reference_sequence_name(src)
+    }
+
+    pub(super) fn feature_start(&self) -> io::Result<Position> {
+        let src = &self.buf[self.bounds.feature_start_range()];
+        parse_feature_start(src)
+    }
+
+    pub(super) fn feature_end(&self) -> Option<io::Result<Position>> {
+        let src = &self.buf[self.bounds.feature_end_range()];
+        parse_feature_end(src)
+    }
+
+    pub(super) fn name(&self) -> Option<&BStr> {
+        let src = &self.buf[self.bounds.name_range()];
+        parse_name(src)
+    }
+
+    pub(super) fn score(&self) -> io::Result<u16> {
+        let src = &self.buf[self.bounds.score_range()];
+        parse_int(src)
+    }
+
+    pub(super) fn strand(&self) -> io::Result<Option<Strand>> {
+        let src = &self.buf[self.bounds.strand_range()];
+        parse_strand(src)
+    }
+
+    pub(super) fn thick_start(&self) -> io::Result<Position> {
+        let src = &self.buf[self.bounds.thick_start_range()];
+        parse_feature_start(src)
+    }
+}
+
+impl Fields<8> {
+    pub(super) fn reference_sequence_name(&self) -> &BStr {
+        let src = &self.buf[self.bounds.reference_sequence_name_range()];
+        parse_reference_sequence_name(src)
+    }
+
+    pub(super) fn feature_start(&self) -> io::Result<Position> {
+        let src = &self.buf[self.bounds.feature_start_range()];
+        parse_feature_start(src)
+    }
+
+    pub(super) fn feature_end(&self) -> Option<io::Result<Position>> {
+        let src = &self.buf[self.bounds.feature_end_range()];
+        parse_feature_end(src)
+    }
+
+    pub(super) fn name(&self) -> Option<&BStr> {
+        let src = &self.buf[self.bounds.name_range()];
+        parse_name(src)
+    }
+
+    pub(super) fn score(&self) -> io::Result<u16> {
+        let src = &self.buf[self.bounds.score_range()];
+        parse_int(src)
+    }
+
+    pub(super) fn strand(&self) -> io::Result<Option<Strand>> {
+        let src = &self.buf[self.bounds.strand_range()];
+        parse_strand(src)
+    }
+
+    pub(super) fn thick_start(&self) -> io::Result<Position> {
+        let src = &self.buf[self.bounds.thick_start_range()];
+        parse_feature_start(src)
+    }
+
+    pub(super) fn thick_end(&self) -> Option<io::Result<Position>> {
+        let src = &self.buf[self.bounds.thick_end_range()];
+        parse_feature_end(src)
+    }
+}
+
+impl Fields<9> {
+    pub(super) fn reference_sequence_name(&self) -> &BStr {
+        let src = &self.buf[self.bounds.reference_sequence_name_range()];
+        parse_reference_sequence_name(src)
+    }
+
+    pub(super) fn feature_start(&self) -> io::Result<Position> {
+        let src = &self.buf[self.bounds.feature_start_range()];
+        parse_feature_start(src)
+    }
+
+    pub(super) fn feature_end(&self) -> Option<io::Result<Position>> {
+        let src = &self.buf[self.bounds.feature_end_range()];
+        parse_feature_end(src)
+    }
+
+    pub(super) fn name(&self) -> Option<&BStr> {
+        let src = &self.buf[self.bounds.name_range()];
+        parse_name(src)
+    }
+
+    pub(super) fn score(&self) -> io::Result<u16> {
+        let src = &self.buf[self.bounds.score_range()];
+        parse_int(src)
+    }
+
+    pub(super) fn strand(&self) -> io::Result<Option<Strand>> {
+        let src = &self.buf[self.bounds.strand_range()];
+        parse_strand(src)
+    }
+
+    pub(super) fn thick_start(&self) -> io::Result<Position> {
+        let src = &self.buf[self.bounds.thick_start_range()];
+        parse_feature_start(src)
+    }
+
+    pub(super) fn thick_end(&self) -> Option<io::Result<Position>> {
+        let src = &self.buf[self.bounds.thick_end_range()];
+        parse_feature_end(src)
+    }
+
+    pub(super) fn color(&self) -> io::Result<Option<Color>> {
+        let src = &self.buf[self.bounds.color_range()];
+        parse_color(src)
+    }
+}
+
+impl Fields<12> {
+    pub(super) fn reference_sequence_name(&self) -> &BStr {
+        let src = &self.buf[self.bounds.reference_sequence_name_range()];
+        parse_reference_sequence_name(src)
+    }
+
+    pub(super) fn feature_start(&self) -> io::Result<Position> {
+        let src = &self.buf[self.bounds.feature_start_range()];
+        parse_feature_start(src)
+    }
+
+    pub(super) fn feature_end(&self) -> Option<io::Result<Position>> {
+        let src = &self.buf[self.bounds.feature_end_range()];
+        parse_feature_end(src)
+    }
+
+    pub(super) fn name(&self) -> Option<&BStr> {
+        let src = &self.buf[self.bounds.name_range()];
+        parse_name(src)
+    }
+
+    pub(super) fn score(&self) -> io::Result<u16> {
+        let src = &self.buf[self.bounds.score_range()];
+        parse_int(src)
+    }
+
+    pub(super) fn strand(&self) -> io::Result<Option<Strand>> {
+        let src = &self.buf[self.bounds.strand_range()];
+        parse_strand(src)
+    }
+
+    pub(super) fn thick_start(&self) -> io::Result<Position> {
+        let src = &self.buf[self.bounds.thick_start_range()];
+        parse_feature_start(src)
+    }
+
+    pub(super) fn thick_end(&self) -> Option<io::Result<Position>> {
+        let src = &self.buf[self.bounds.thick_end_range()];
+        parse_feature_end(src)
+    }
+
+    pub(super) fn color(&self) -> io::Result<Option<Color>> {
+        let src = &self.buf[self.bounds.color_range()];
+        parse_color(src)
+    }
+
+    pub(super) fn blocks(&self) -> io::Result<Vec<(usize, usize)>> {
+        let src = &self.buf[self.bounds.block_count_range()];
+        let block_count = parse_int(src)?;
+
+        let src = &self.buf[self.bounds.block_sizes_range()];
+        let block_sizes = parse_list(src)?;
+
+        let src = &self.buf[self.bounds.block_starts_range()];
+        let block_starts = parse_list(src)?;
+
+        if block_sizes.len() != block_count || block_starts.len() != block_count {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "block count mismatch",
+            ));
+        }
+
+        let blocks: Vec<_> = block_starts.into_iter().zip(block_sizes).collect();
+
+        let feature_start = self.feature_start()?;
+        let feature_end = self.feature_end().transpose()?;
+
+        if blocks::is_valid(feature_start, feature_end, &blocks) {
+            Ok(blocks)
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "blocks out of feature bounds",
+            ))
+        }
+    }
+}
+
 impl Default for Fields<3> {
     fn default() -> Self {
         Self {
@@ -155,6 +360,42 @@ impl Default for Fields<6> {
     }
 }
 
+impl Default for Fields<7> {
+    fn default() -> Self {
+        Self {
+            buf: Vec::from(*b"sq001.0.0"),
+            bounds: Bounds::default(),
+        }
+    }
+}
+
+impl Default for Fields<8> {
+    fn default() -> Self {
+        Self {
+            buf: Vec::from(*b"sq001.0.01"),
+            bounds: Bounds::default(),
+        }
+    }
+}
+
+impl Default for Fields<9> {
+    fn default() -> Self {
+        Self {
+            buf: Vec::from(*b"sq001.0.010"),
+            bounds: Bounds::default(),
+        }
+    }
+}
+
+impl Default for Fields<12> {
+    fn default() -> Self {
+        Self {
+            buf: Vec::from(*b"sq001.0.010110"),
+            bounds: Bounds::default(),
+        }
+    }
+}
+
 fn parse_int<N: FromLexical>(buf: &[u8]) -> io::Result<N> {
     lexical_core::parse(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
 }
@@ -208,6 +449,47 @@ fn parse_strand(buf: &[u8]) -> io::Result<Option<Strand>> {
     }
 }
 
+fn parse_color(buf: &[u8]) -> io::Result<Option<Color>> {
+    const MISSING: &[u8] = b"0";
+    const DELIMITER: u8 = b',';
+
+    if buf == MISSING {
+        return Ok(None);
+    }
+
+    let mut components = buf.split(|&b| b == DELIMITER);
+
+    let mut next_component = || {
+        components
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid color"))
+            .and_then(parse_int::<u8>)
+    };
+
+    let red = next_component()?;
+    let green = next_component()?;
+    let blue = next_component()?;
+
+    if components.next().is_some() {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid color"));
+    }
+
+    Ok(Some(Color::new(red, green, blue)))
+}
+
+fn parse_list(buf: &[u8]) -> io::Result<Vec<usize>> {
+    const DELIMITER: u8 = b',';
+
+    // Lists are commonly written with a trailing delimiter.
+    let buf = buf.strip_suffix(&[DELIMITER]).unwrap_or(buf);
+
+    if buf.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    buf.split(|&b| b == DELIMITER).map(parse_int).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -222,4 +504,56 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_color() -> io::Result<()> {
+        assert!(parse_color(b"0")?.is_none());
+        assert_eq!(parse_color(b"255,128,0")?, Some(Color::new(255, 128, 0)));
+
+        for src in [
+            &b""[..],
+            b"255",
+            b"255,128",
+            b"255,128,0,0",
+            b"256,0,0",
+            b"r,g,b",
+        ] {
+            assert!(matches!(parse_color(src), Err(e) if e.kind() == io::ErrorKind::InvalidData));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_list() -> io::Result<()> {
+        assert!(parse_list(b"")?.is_empty());
+        assert_eq!(parse_list(b"8")?, [8]);
+        assert_eq!(parse_list(b"8,13")?, [8, 13]);
+        assert_eq!(parse_list(b"8,13,")?, [8, 13]);
+
+        assert!(matches!(parse_list(b"8,,13"), Err(e) if e.kind() == io::ErrorKind::InvalidData));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_blocks() -> io::Result<()> {
+        let mut fields = Fields::<12>::default();
+        assert_eq!(fields.blocks()?, [(0, 1)]);
+
+        fields.buf = Vec::from(*b"sq00100.0.01022,30,8");
+        fields.bounds.standard_fields_ends = [3, 4, 7, 8, 9, 10, 11, 12, 13, 14, 17, 20];
+        assert_eq!(fields.blocks()?, [(0, 2), (8, 3)]);
+
+        // block count mismatch
+        fields.buf = Vec::from(*b"sq00100.0.01032,30,8");
+        assert!(matches!(fields.blocks(), Err(e) if e.kind() == io::ErrorKind::InvalidData));
+
+        // out of bounds
+        fields.buf = Vec::from(*b"sq00100.0.01022,30,98");
+        fields.bounds.standard_fields_ends = [3, 4, 7, 8, 9, 10, 11, 12, 13, 14, 17, 21];
+        assert!(matches!(fields.blocks(), Err(e) if e.kind() == io::ErrorKind::InvalidData));
+
+        Ok(())
+    }
 }
@@ -99,6 +99,158 @@ impl Bounds<6> {
     }
 }
 
+impl Bounds<7> {
+    pub fn reference_sequence_name_range(&self) -> Range<usize> {
+        0..self.standard_fields_ends[0]
+    }
+
+    pub fn feature_start_range(&self) -> Range<usize> {
+        self.standard_fields_ends[0]..self.standard_fields_ends[1]
+    }
+
+    pub fn feature_end_range(&self) -> Range<usize> {
+        self.standard_fields_ends[1]..self.standard_fields_ends[2]
+    }
+
+    pub fn name_range(&self) -> Range<usize> {
+        self.standard_fields_ends[2]..self.standard_fields_ends[3]
+    }
+
+    pub fn score_range(&self) -> Range<usize> {
+        self.standard_fields_ends[3]..self.standard_fields_ends[4]
+    }
+
+    pub fn strand_range(&self) -> Range<usize> {
+        self.standard_fields_ends[4]..self.standard_fields_ends[5]
+    }
+
+    pub fn thick_start_range(&self) -> Range<usize> {
+        self.standard_fields_ends[5]..self.standard_fields_ends[6]
+    }
+}
+
+impl Bounds<8> {
+    pub fn reference_sequence_name_range(&self) -> Range<usize> {
+        0..self.standard_fields_ends[0]
+    }
+
+    pub fn feature_start_range(&self) -> Range<usize> {
+        self.standard_fields_ends[0]..self.standard_fields_ends[1]
+    }
+
+    pub fn feature_end_range(&self) -> Range<usize> {
+        self.standard_fields_ends[1]..self.standard_fields_ends[2]
+    }
+
+    pub fn name_range(&self) -> Range<usize> {
+        self.standard_fields_ends[2]..self.standard_fields_ends[3]
+    }
+
+    pub fn score_range(&self) -> Range<usize> {
+        self.standard_fields_ends[3]..self.standard_fields_ends[4]
+    }
+
+    pub fn strand_range(&self) -> Range<usize> {
+        self.standard_fields_ends[4]..self.standard_fields_ends[5]
+    }
+
+    pub fn thick_start_range(&self) -> Range<usize> {
+        self.standard_fields_ends[5]..self.standard_fields_ends[6]
+    }
+
+    pub fn thick_end_range(&self) -> Range<usize> {
+        self.standard_fields_ends[6]..self.standard_fields_ends[7]
+    }
+}
+
+impl Bounds<9> {
+    pub fn reference_sequence_name_range(&self) -> Range<usize> {
+        0..self.standard_fields_ends[0]
+    }
+
+    pub fn feature_start_range(&self) -> Range<usize> {
+        self.standard_fields_ends[0]..self.standard_fields_ends[1]
+    }
+
+    pub fn feature_end_range(&self) -> Range<usize> {
+        self.standard_fields_ends[1]..self.standard_fields_ends[2]
+    }
+
+    pub fn name_range(&self) -> Range<usize> {
+        self.standard_fields_ends[2]..self.standard_fields_ends[3]
+    }
+
+    pub fn score_range(&self) -> Range<usize> {
+        self.standard_fields_ends[3]..self.standard_fields_ends[4]
+    }
+
+    pub fn strand_range(&self) -> Range<usize> {
+        self.standard_fields_ends[4]..self.standard_fields_ends[5]
+    }
+
+    pub fn thick_start_range(&self) -> Range<usize> {
+        self.standard_fields_ends[5]..self.standard_fields_ends[6]
+    }
+
+    pub fn thick_end_range(&self) -> Range<usize> {
+        self.standard_fields_ends[6]..self.standard_fields_ends[7]
+    }
+
+    pub fn color_range(&self) -> Range<usize> {
+        self.standard_fields_ends[7]..self.standard_fields_ends[8]
+    }
+}
+
+impl Bounds<12> {
+    pub fn reference_sequence_name_range(&self) -> Range<usize> {
+        0..self.standard_fields_ends[0]
+    }
+
+    pub fn feature_start_range(&self) -> Range<usize> {
+        self.standard_fields_ends[0]..self.standard_fields_ends[1]
+    }
+
+    pub fn feature_end_range(&self) -> Range<usize> {
+        self.standard_fields_ends[1]..self.standard_fields_ends[2]
+    }
+
+    pub fn name_range(&self) -> Range<usize> {
+        self.standard_fields_ends[2]..self.standard_fields_ends[3]
+    }
+
+    pub fn score_range(&self) -> Range<usize> {
+        self.standard_fields_ends[3]..self.standard_fields_ends[4]
+    }
+
+    pub fn strand_range(&self) -> Range<usize> {
+        self.standard_fields_ends[4]..self.standard_fields_ends[5]
+    }
+
+    pub fn thick_start_range(&self) -> Range<usize> {
+        self.standard_fields_ends[5]..self.standard_fields_ends[6]
+    }
+
+    pub fn thick_end_range(&self) -> Range<usize> {
+        self.standard_fields_ends[6]..self.standard_fields_ends[7]
+    }
+
+    pub fn color_range(&self) -> Range<usize> {
+        self.standard_fields_ends[7]..self.standard_fields_ends[8]
+    }
+
+    pub fn block_count_range(&self) -> Range<usize> {
+        self.standard_fields_ends[8]..self.standard_fields_ends[9]
+    }
+
+    pub fn block_sizes_range(&self) -> Range<usize> {
+        self.standard_fields_ends[9]..self.standard_fields_ends[10]
+    }
+
+    pub fn block_starts_range(&self) -> Range<usize> {
+        self.standard_fields_ends[10]..self.standard_fields_ends[11]
+    }
+}
+
 impl Default for Bounds<3> {
     fn default() -> Self {
         Self {
@@ -135,6 +287,42 @@ impl Default for Bounds<6> {
     }
 }
 
+impl Default for Bounds<7> {
+    fn default() -> Self {
+        Self {
+            standard_fields_ends: [3, 4, 5, 6, 7, 8, 9],
+            other_fields_ends: Vec::new(),
+        }
+    }
+}
+
+impl Default for Bounds<8> {
+    fn default() -> Self {
+        Self {
+            standard_fields_ends: [3, 4, 5, 6, 7, 8, 9, 10],
+            other_fields_ends: Vec::new(),
+        }
+    }
+}
+
+impl Default for Bounds<9> {
+    fn default() -> Self {
+        Self {
+            standard_fields_ends: [3, 4, 5, 6, 7, 8, 9, 10, 11],
+            other_fields_ends: Vec::new(),
+        }
+    }
+}
+
+impl Default for Bounds<12> {
+    fn default() -> Self {
+        Self {
+            standard_fields_ends: [3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14],
+            other_fields_ends: Vec::new(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,6 +307,28 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_read_with_multiple_workers() -> io::Result<()> {
+        use std::io::Write;
+
+        let data: Vec<u8> = (0..(1 << 20)).map(|i| (i % 251) as u8).collect();
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(&data)?;
+        let src = writer.finish()?;
+
+        let mut reader = Builder::default()
+            .set_worker_count(NonZeroUsize::try_from(4).unwrap())
+            .build_from_reader(&src[..]);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await?;
+
+        assert_eq!(buf, data);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_seek() -> Result<(), Box<dyn std::error::Error>> {
         #[rustfmt::skip]
@@ -15,6 +15,9 @@ pub struct Builder {
 impl Builder {
     /// Sets a worker count.
     ///
+    /// This is the maximum number of blocks that are decompressed in parallel on the blocking
+    /// thread pool. It also bounds the number of blocks read ahead of the consumer.
+    ///
     /// By default, the worker count is set to the number of available logical CPUs.
     ///
     /// # Examples
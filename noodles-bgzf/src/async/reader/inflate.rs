@@ -6,16 +6,17 @@ use std::{
 };
 
 use bytes::Bytes;
-use pin_project_lite::pin_project;
 use tokio::task::JoinHandle;
 
 use crate::Block;
 
-pin_project! {
-    pub struct Inflate {
-        #[pin]
-        handle: JoinHandle<io::Result<Block>>,
-    }
+/// A block inflate task.
+///
+/// The block is decompressed on the blocking thread pool. If the task is dropped, e.g., when the
+/// reader seeks, it is cancelled only if it is still queued. A decompression that has already
+/// started runs to completion, and its result is discarded.
+pub struct Inflate {
+    handle: JoinHandle<io::Result<Block>>,
 }
 
 impl Inflate {
@@ -26,11 +27,17 @@ impl Inflate {
     }
 }
 
+impl Drop for Inflate {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
 impl Future for Inflate {
     type Output = io::Result<Block>;
 
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        self.project().handle.poll(cx)?
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.handle).poll(cx)?
     }
 }
 
@@ -149,9 +149,7 @@ where
     R: Read + Seek,
 {
     fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
-        match pos {
-            SeekFrom::Start(pos) => self.inner.seek_by_uncompressed_position(&self.index, pos),
-            _ => unimplemented!(),
-        }
+        use crate::io::Seek;
+        self.inner.seek_with_index(&self.index, pos)
     }
 }
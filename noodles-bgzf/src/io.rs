@@ -2,6 +2,6 @@
 
 mod buf_read;
 mod read;
-mod seek;
+pub(crate) mod seek;
 
 pub use self::{buf_read::BufRead, read::Read, seek::Seek};
@@ -1,6 +1,6 @@
 use std::io::{self, SeekFrom};
 
-use crate::{gzi, VirtualPosition};
+use crate::{gzi, Block, VirtualPosition};
 
 /// A seekable BGZF reader.
 pub trait Seek {
@@ -10,3 +10,97 @@ pub trait Seek {
     /// Seeks the stream to the given position using an index.
     fn seek_with_index(&mut self, index: &gzi::Index, pos: SeekFrom) -> io::Result<u64>;
 }
+
+/// Returns the uncompressed position of the stream at the given block.
+///
+/// This is `None` when the block is empty and not indexed, i.e., the EOF marker.
+pub(crate) fn uncompressed_position(index: &gzi::Index, block: &Block) -> io::Result<Option<u64>> {
+    let block_uncompressed_position = if block.position() == 0 {
+        Some(0)
+    } else {
+        let entries = index.as_ref();
+
+        entries
+            .binary_search_by_key(&block.position(), |r| r.0)
+            .ok()
+            .map(|i| entries[i].1)
+    };
+
+    match block_uncompressed_position {
+        Some(pos) => Ok(Some(pos + block.data().position() as u64)),
+        None if block.data().len() == 0 => Ok(None),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "block position not in index",
+        )),
+    }
+}
+
+/// Resolves a seek position to an absolute uncompressed position.
+///
+/// `current_position` is the uncompressed position of the stream or `None` if the stream is at
+/// EOF. Resolving a position relative to the end of the stream moves the stream to the end of the
+/// last indexed block.
+pub(crate) fn resolve_uncompressed_position<R>(
+    reader: &mut R,
+    index: &gzi::Index,
+    current_position: Option<u64>,
+    pos: SeekFrom,
+) -> io::Result<u64>
+where
+    R: io::BufRead + Seek,
+{
+    match pos {
+        SeekFrom::Start(n) => Ok(n),
+        SeekFrom::Current(n) => {
+            let current_position = match current_position {
+                Some(pos) => pos,
+                None => read_uncompressed_size(reader, index)?,
+            };
+
+            offset(current_position, n)
+        }
+        SeekFrom::End(n) => {
+            let size = read_uncompressed_size(reader, index)?;
+            offset(size, n)
+        }
+    }
+}
+
+// The last index entry is the start of the final block(s), so only the tail of the stream is
+// decompressed to find its end.
+fn read_uncompressed_size<R>(reader: &mut R, index: &gzi::Index) -> io::Result<u64>
+where
+    R: io::BufRead + Seek,
+{
+    let (compressed_pos, uncompressed_pos) = index.as_ref().last().copied().unwrap_or_default();
+
+    let pos = VirtualPosition::try_from((compressed_pos, 0))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    reader.seek_to_virtual_position(pos)?;
+
+    let mut size = uncompressed_pos;
+
+    loop {
+        let n = reader.fill_buf()?.len();
+
+        if n == 0 {
+            break;
+        }
+
+        reader.consume(n);
+        size += n as u64;
+    }
+
+    Ok(size)
+}
+
+fn offset(pos: u64, n: i64) -> io::Result<u64> {
+    pos.checked_add_signed(n).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "invalid seek to a negative or overflowing position",
+        )
+    })
+}
@@ -2,6 +2,10 @@ use std::{
     io::{self, BufRead, Read, Seek, SeekFrom},
     mem,
     num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     thread::{self, JoinHandle},
 };
 
@@ -25,6 +29,7 @@ enum State<R> {
         inflater_handles: Vec<JoinHandle<()>>,
         read_rx: ReadRx,
         recycle_tx: RecycleTx,
+        is_cancelled: Arc<AtomicBool>,
     },
     Done,
 }
@@ -39,6 +44,10 @@ struct Buffer {
 ///
 /// This is a multithreaded BGZF reader that uses a thread pool to decompress block data. It places
 /// the inner reader on its own thread to read raw frames asynchronously.
+///
+/// When the inner reader is seekable, this implements [`crate::io::Seek`] and can be used as the
+/// decoder of indexed readers, e.g., `noodles_bam::io::IndexedReader`. Seeking cancels blocks that
+/// are read ahead or still being decompressed.
 pub struct MultithreadedReader<R> {
     state: State<R>,
     worker_count: NonZeroUsize,
@@ -94,10 +103,12 @@ impl<R> MultithreadedReader<R> {
             State::Running {
                 reader_handle,
                 mut inflater_handles,
+                read_rx,
                 recycle_tx,
                 ..
             } => {
                 drop(recycle_tx);
+                drop(read_rx);
 
                 for handle in inflater_handles.drain(..) {
                     handle.join().unwrap();
@@ -186,14 +197,25 @@ where
             recycle_tx.send(Buffer::default()).unwrap();
         }
 
-        let reader_handle = spawn_reader(inner, inflate_tx, read_tx, recycle_rx);
-        let inflater_handles = spawn_inflaters(self.worker_count, inflate_rx);
+        let is_cancelled = Arc::new(AtomicBool::new(false));
+
+        let reader_handle = spawn_reader(
+            inner,
+            inflate_tx,
+            read_tx,
+            recycle_rx,
+            Arc::clone(&is_cancelled),
+        );
+
+        let inflater_handles =
+            spawn_inflaters(self.worker_count, inflate_rx, Arc::clone(&is_cancelled));
 
         self.state = State::Running {
             reader_handle,
             inflater_handles,
             read_rx,
             recycle_tx,
+            is_cancelled,
         };
     }
 
@@ -207,14 +229,18 @@ where
         let State::Running {
             reader_handle,
             mut inflater_handles,
+            read_rx,
             recycle_tx,
-            ..
+            is_cancelled,
         } = state
         else {
             panic!("invalid state");
         };
 
+        // Stop reading ahead and discard blocks that have not yet been decompressed.
+        is_cancelled.store(true, Ordering::Relaxed);
         drop(recycle_tx);
+        drop(read_rx);
 
         for handle in inflater_handles.drain(..) {
             handle.join().unwrap();
@@ -335,9 +361,10 @@ where
     }
 
     fn seek_with_index(&mut self, index: &gzi::Index, pos: SeekFrom) -> io::Result<u64> {
-        let SeekFrom::Start(pos) = pos else {
-            unimplemented!();
-        };
+        let current_position = crate::io::seek::uncompressed_position(index, &self.buffer.block)?;
+
+        let pos =
+            crate::io::seek::resolve_uncompressed_position(self, index, current_position, pos)?;
 
         let virtual_position = index.query(pos)?;
         self.seek_to_virtual_position(virtual_position)?;
@@ -362,6 +389,7 @@ fn spawn_reader<R>(
     inflate_tx: InflateTx,
     read_tx: ReadTx,
     recycle_rx: RecycleRx,
+    is_cancelled: Arc<AtomicBool>,
 ) -> JoinHandle<Result<R, ReadError<R>>>
 where
     R: Read + Send + 'static,
@@ -370,6 +398,10 @@ where
 
     thread::spawn(move || {
         while let Ok(mut buffer) = recycle_rx.recv() {
+            if is_cancelled.load(Ordering::Relaxed) {
+                break;
+            }
+
             match read_frame_into(&mut reader, &mut buffer.buf) {
                 Ok(result) if result.is_none() => break,
                 Ok(_) => {}
@@ -378,25 +410,38 @@ where
 
             let (buffered_tx, buffered_rx) = crossbeam_channel::bounded(1);
 
-            inflate_tx.send((buffer, buffered_tx)).unwrap();
-            read_tx.send(buffered_rx).unwrap();
+            if inflate_tx.send((buffer, buffered_tx)).is_err() || read_tx.send(buffered_rx).is_err()
+            {
+                break;
+            }
         }
 
         Ok(reader)
     })
 }
 
-fn spawn_inflaters(worker_count: NonZeroUsize, inflate_rx: InflateRx) -> Vec<JoinHandle<()>> {
+fn spawn_inflaters(
+    worker_count: NonZeroUsize,
+    inflate_rx: InflateRx,
+    is_cancelled: Arc<AtomicBool>,
+) -> Vec<JoinHandle<()>> {
     use super::reader::frame::parse_block;
 
     (0..worker_count.get())
         .map(|_| {
             let inflate_rx = inflate_rx.clone();
+            let is_cancelled = Arc::clone(&is_cancelled);
 
             thread::spawn(move || {
                 while let Ok((mut buffer, buffered_tx)) = inflate_rx.recv() {
+                    if is_cancelled.load(Ordering::Relaxed) {
+                        continue;
+                    }
+
                     let result = parse_block(&buffer.buf, &mut buffer.block).map(|_| buffer);
-                    buffered_tx.send(result).unwrap();
+
+                    // The receiver is dropped when the reader is paused.
+                    buffered_tx.send(result).ok();
                 }
             })
         })
@@ -452,4 +497,108 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_seek_with_index() -> io::Result<()> {
+        use crate::io::Seek;
+
+        #[rustfmt::skip]
+        static DATA: &[u8] = &[
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // block 1 (b"bgzf")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1f, 0x00, 0x4b, 0x4a, 0xaf, 0x4a, 0x03, 0x00, 0x20, 0x68, 0xf2, 0x8c,
+            0x04, 0x00, 0x00, 0x00,
+            // EOF block
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let index = gzi::Index::from(vec![(35, 7)]);
+
+        let mut reader =
+            MultithreadedReader::with_worker_count(NonZeroUsize::MIN, Cursor::new(DATA));
+
+        assert_eq!(reader.seek_with_index(&index, SeekFrom::End(-4))?, 7);
+        let mut buf = [0; 2];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"bg");
+
+        assert_eq!(reader.seek_with_index(&index, SeekFrom::Current(-6))?, 3);
+        let mut buf = [0; 4];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"dles");
+
+        assert_eq!(reader.seek_with_index(&index, SeekFrom::Current(1))?, 8);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"gzf");
+
+        assert_eq!(reader.seek_with_index(&index, SeekFrom::Current(-1))?, 10);
+        assert_eq!(reader.seek_with_index(&index, SeekFrom::End(0))?, 11);
+
+        assert!(matches!(
+            reader.seek_with_index(&index, SeekFrom::Current(-12)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_to_virtual_position_with_multiple_workers() -> io::Result<()> {
+        use std::io::Write;
+
+        use crate::io::Seek;
+
+        let data: Vec<u8> = (0..(1 << 20)).map(|i| (i % 251) as u8).collect();
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(&data)?;
+        let src = writer.finish()?;
+
+        let worker_count = NonZeroUsize::try_from(4).unwrap();
+        let mut reader = MultithreadedReader::with_worker_count(worker_count, Cursor::new(src));
+
+        let mut buf = vec![0; 8];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(buf, data[..8]);
+
+        // Read ahead to fill the pipeline, then seek back to the start of a later block.
+        let mut blocks = Vec::new();
+        let mut offset = 0;
+
+        loop {
+            let n = reader.fill_buf()?.len();
+
+            if n == 0 {
+                break;
+            }
+
+            blocks.push((reader.position(), offset + n));
+            offset += n;
+            reader.consume(n);
+        }
+
+        // The start of block 5 is the end of block 4.
+        let (cpos, upos) = blocks[4];
+        let pos = VirtualPosition::try_from((cpos, 5))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        reader.seek_to_virtual_position(pos)?;
+
+        let offset = 8 + upos + 5;
+        reader.read_exact(&mut buf)?;
+        assert_eq!(buf, data[offset..offset + 8]);
+
+        reader.seek_to_virtual_position(VirtualPosition::MIN)?;
+
+        buf.clear();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, data);
+
+        Ok(())
+    }
 }
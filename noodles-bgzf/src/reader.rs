@@ -275,10 +275,12 @@ where
     }
 
     fn seek_with_index(&mut self, index: &gzi::Index, pos: SeekFrom) -> io::Result<u64> {
-        match pos {
-            SeekFrom::Start(pos) => self.seek_by_uncompressed_position(index, pos),
-            _ => unimplemented!(),
-        }
+        let current_position = crate::io::seek::uncompressed_position(index, &self.block)?;
+
+        let pos =
+            crate::io::seek::resolve_uncompressed_position(self, index, current_position, pos)?;
+
+        self.seek_by_uncompressed_position(index, pos)
     }
 }
 
@@ -406,4 +408,53 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_seek_with_index() -> io::Result<()> {
+        use crate::io::Seek;
+
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // block 1 (b"bgzf")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1f, 0x00, 0x4b, 0x4a, 0xaf, 0x4a, 0x03, 0x00, 0x20, 0x68, 0xf2, 0x8c,
+            0x04, 0x00, 0x00, 0x00,
+            // EOF block
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let index = gzi::Index::from(vec![(35, 7)]);
+
+        let mut reader = Reader::new(Cursor::new(&data));
+
+        assert_eq!(reader.seek_with_index(&index, SeekFrom::End(-4))?, 7);
+        let mut buf = [0; 2];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"bg");
+
+        assert_eq!(reader.seek_with_index(&index, SeekFrom::Current(-6))?, 3);
+        let mut buf = [0; 4];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"dles");
+
+        assert_eq!(reader.seek_with_index(&index, SeekFrom::Current(1))?, 8);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"gzf");
+
+        assert_eq!(reader.seek_with_index(&index, SeekFrom::Current(-1))?, 10);
+        assert_eq!(reader.seek_with_index(&index, SeekFrom::End(0))?, 11);
+
+        assert!(matches!(
+            reader.seek_with_index(&index, SeekFrom::Current(-12)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
 }
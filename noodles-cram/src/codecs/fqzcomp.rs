@@ -31,15 +31,18 @@ impl Models {
 mod tests {
     use std::io;
 
+    use noodles_sam as sam;
+
     use super::*;
 
     #[test]
     fn test_self() -> io::Result<()> {
         fn t(data: &[Vec<u8>]) -> io::Result<()> {
             let lens: Vec<_> = data.iter().map(|scores| scores.len()).collect();
+            let flags = vec![sam::alignment::record::Flags::empty(); lens.len()];
             let src: Vec<_> = data.iter().flatten().copied().collect();
 
-            let compressed_data = encode(&lens, &src)?;
+            let compressed_data = encode(&lens, &flags, &src)?;
 
             let mut reader = &compressed_data[..];
             let uncompressed_data = decode(&mut reader)?;
@@ -1,186 +1,504 @@
 use std::io::{self, Write};
 
 use byteorder::{LittleEndian, WriteBytesExt};
+use noodles_sam as sam;
 
 use super::{parameter, parameters, Models};
 use crate::{codecs::aac::RangeCoder, io::writer::num::write_uint7};
 
-pub fn encode(lens: &[usize], src: &[u8]) -> io::Result<Vec<u8>> {
+// § 6.5 "FQZComp quality codec" (2023-03-15): The context is 16 bits.
+const MAX_CONTEXT_BITS: u8 = 16;
+
+// Quality score alphabets with at most this many symbols are mapped to a dense alphabet
+// (`HAVE_QMAP`), e.g., for binned Illumina quality scores.
+const MAX_QMAP_SYMBOL_COUNT: usize = 16;
+
+// Deduplication (`DO_DEDUP`) is enabled when at least 1 in this many records is a duplicate of
+// its previous record.
+const DEDUP_RECORD_RATIO: usize = 256;
+
+/// A parameter selection strategy.
+///
+/// These mirror the strategies used by htslib/htscodecs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Strategy {
+    q_bits: u8,
+    q_shift: u8,
+    p_bits: u8,
+    // If unset, this is derived from the first record length.
+    p_shift: Option<u8>,
+    d_bits: u8,
+    d_shift: u8,
+    q_loc: u8,
+    s_loc: u8,
+    p_loc: u8,
+    d_loc: u8,
+    selector: Selector,
+}
+
+/// A record selector.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Selector {
+    /// All records use the same context.
+    None,
+    /// Records are split by whether they are the last segment of a template (READ2).
+    LastSegment,
+}
+
+const STRATEGIES: [Strategy; 4] = [
+    // basic
+    Strategy {
+        q_bits: 10,
+        q_shift: 5,
+        p_bits: 4,
+        p_shift: None,
+        d_bits: 2,
+        d_shift: 1,
+        q_loc: 0,
+        s_loc: 14,
+        p_loc: 10,
+        d_loc: 14,
+        selector: Selector::None,
+    },
+    // e.g., HiSeq 2000
+    Strategy {
+        q_bits: 9,
+        q_shift: 5,
+        p_bits: 7,
+        p_shift: Some(0),
+        d_bits: 0,
+        d_shift: 0,
+        q_loc: 0,
+        s_loc: 14,
+        p_loc: 8,
+        d_loc: 14,
+        selector: Selector::LastSegment,
+    },
+    // e.g., MiSeq
+    Strategy {
+        q_bits: 12,
+        q_shift: 6,
+        p_bits: 2,
+        p_shift: Some(0),
+        d_bits: 2,
+        d_shift: 3,
+        q_loc: 0,
+        s_loc: 9,
+        p_loc: 12,
+        d_loc: 14,
+        selector: Selector::None,
+    },
+    // e.g., Ion Torrent
+    Strategy {
+        q_bits: 12,
+        q_shift: 6,
+        p_bits: 0,
+        p_shift: Some(0),
+        d_bits: 0,
+        d_shift: 0,
+        q_loc: 0,
+        s_loc: 12,
+        p_loc: 0,
+        d_loc: 0,
+        selector: Selector::None,
+    },
+];
+
+/// Encodes quality scores using fqzcomp.
+///
+/// `lens` and `flags` describe each record, and `src` is the concatenated quality scores of all
+/// records.
+///
+/// Parameters are picked for each strategy, and the smallest encoding is kept.
+pub fn encode(
+    lens: &[usize],
+    flags: &[sam::alignment::record::Flags],
+    src: &[u8],
+) -> io::Result<Vec<u8>> {
+    if flags.len() != lens.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "record count mismatch",
+        ));
+    }
+
+    if lens.iter().sum::<usize>() != src.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "quality scores length mismatch",
+        ));
+    }
+
+    // Records without quality scores are not encoded.
+    let records: Vec<_> = lens
+        .iter()
+        .zip(flags)
+        .filter(|(len, _)| **len > 0)
+        .map(|(len, flags)| (*len, *flags))
+        .collect();
+
+    let stats = Stats::new(&records, src);
+
+    let mut dst: Option<Vec<u8>> = None;
+
+    for strategy in &STRATEGIES {
+        let parameters = build_parameters(strategy, &stats);
+        let buf = encode_with_parameters(&records, src, &parameters)?;
+
+        if dst.as_ref().map(|b| buf.len() < b.len()).unwrap_or(true) {
+            dst = Some(buf);
+        }
+    }
+
+    Ok(dst.unwrap_or_default())
+}
+
+fn encode_with_parameters(
+    records: &[(usize, sam::alignment::record::Flags)],
+    src: &[u8],
+    parameters: &Parameters,
+) -> io::Result<Vec<u8>> {
     let mut dst = Vec::new();
 
     let len =
         u32::try_from(src.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
     write_uint7(&mut dst, len)?;
 
-    let mut q_hist = [[0; 256]];
-
-    for (i, f) in q_hist[0].iter_mut().enumerate() {
-        *f = i as u8;
-    }
-
-    let parameters = build_parameters(lens, src);
-    fqz_encode_params(&mut dst, &parameters)?;
+    fqz_encode_params(&mut dst, parameters)?;
 
     let mut range_coder = RangeCoder::default();
-    let mut models = Models::new(parameters.max_sym, 1); // FIXME: max_sel
+    let mut models = Models::new(parameters.max_sym, parameters.max_sel);
 
-    let mut p = 0;
-    let mut rec_num = 0;
+    let mut i = 0;
+    let mut prev_len = 0;
 
-    let mut x = 0;
-    let mut last = 0;
-    let mut qlast: u32 = 0;
+    for (rec, &(len, flags)) in records.iter().enumerate() {
+        let sel = select(parameters.selector, flags);
 
-    for &q in src {
-        if p == 0 {
-            if parameters.gflags.contains(parameters::Flags::HAVE_S_TAB) {
-                todo!("have_s_tab");
-            }
+        if parameters.max_sel > 0 {
+            models.sel.encode(&mut dst, &mut range_coder, sel)?;
+        }
 
-            x = parameters.s_tab[0];
+        let x = if parameters.gflags.contains(parameters::Flags::HAVE_S_TAB) {
+            usize::from(parameters.s_tab[usize::from(sel)])
+        } else {
+            0
+        };
 
-            let is_fixed_len = parameters.params[usize::from(x)]
-                .flags
-                .contains(parameter::Flags::DO_LEN);
+        let param = &parameters.params[x];
 
-            let len = lens[rec_num];
+        let is_fixed_len = param.flags.contains(parameter::Flags::DO_LEN);
 
-            if !is_fixed_len || rec_num == 0 {
-                encode_length(&mut dst, &mut range_coder, &mut models, len)?;
-            }
+        if !is_fixed_len || rec == 0 {
+            encode_length(&mut dst, &mut range_coder, &mut models, len)?;
+        }
 
-            let param = &parameters.params[usize::from(x)];
+        let qual = &src[i..i + len];
 
-            if param.flags.contains(parameter::Flags::DO_DEDUP) {
-                todo!("do_dedup");
+        if param.flags.contains(parameter::Flags::DO_DEDUP) {
+            let is_dup = is_duplicate(src, i, len, prev_len);
+            models
+                .dup
+                .encode(&mut dst, &mut range_coder, u8::from(is_dup))?;
+
+            if is_dup {
+                i += len;
+                continue;
             }
+        }
+
+        let mut record = Record {
+            sel,
+            pos: len,
+            ..Default::default()
+        };
 
-            p = len;
-            last = u32::from(param.context);
-            qlast = 0;
+        let mut ctx = param.context;
 
-            rec_num += 1;
+        for &q in qual {
+            let sym = param.q_map_inverse[usize::from(q)];
+            models.qual[usize::from(ctx)].encode(&mut dst, &mut range_coder, sym)?;
+            ctx = fqz_update_context(param, sym, &mut record);
+            record.pos -= 1;
         }
 
-        let qq = q_hist[usize::from(x)][usize::from(q)];
-        models.qual[usize::from(last as u16)].encode(&mut dst, &mut range_coder, qq)?;
+        i += len;
+        prev_len = len;
+    }
+
+    range_coder.range_encode_end(&mut dst)?;
+
+    Ok(dst)
+}
+
+fn select(selector: Selector, flags: sam::alignment::record::Flags) -> u8 {
+    match selector {
+        Selector::None => 0,
+        Selector::LastSegment => u8::from(flags.is_last_segment()),
+    }
+}
+
+fn is_duplicate(src: &[u8], i: usize, len: usize, prev_len: usize) -> bool {
+    // The decoder copies the previous `len` quality scores, so the previous record must have the
+    // same length.
+    i > 0 && len == prev_len && src[i - len..i] == src[i..i + len]
+}
+
+#[derive(Debug, Default)]
+struct Record {
+    sel: u8,
+    pos: usize,
+    qctx: u32,
+    delta: u32,
+    prevq: u8,
+}
+
+fn fqz_update_context(param: &Parameter, q: u8, record: &mut Record) -> u16 {
+    use parameter::Flags;
+
+    let mut ctx = u32::from(param.context);
 
-        let param = &parameters.params[usize::from(x)];
+    record.qctx = (record.qctx << u32::from(param.q_shift))
+        .overflowing_add(u32::from(param.q_tab[usize::from(q)]))
+        .0;
 
-        qlast = (qlast << param.q_shift)
-            .overflowing_add(u32::from(param.q_tab[usize::from(qq)]))
-            .0;
-        last = u32::from(param.context);
-        last += (qlast & ((1 << param.q_bits) - 1)) << param.q_loc;
+    ctx += (record.qctx & ((1 << param.q_bits) - 1)) << param.q_loc;
 
-        if param.flags.contains(parameter::Flags::HAVE_PTAB) {
-            last += u32::from(param.p_tab[p.min(1023)] << param.p_loc);
+    if param.flags.contains(Flags::HAVE_PTAB) {
+        let p = record.pos.min(1023);
+        ctx += u32::from(param.p_tab[p]) << param.p_loc;
+    }
+
+    if param.flags.contains(Flags::HAVE_DTAB) {
+        let d = record.delta.min(255) as usize;
+        ctx += u32::from(param.d_tab[d]) << param.d_loc;
+
+        if record.prevq != q {
+            record.delta += 1;
         }
 
-        if param.flags.contains(parameter::Flags::HAVE_DTAB) {
-            todo!("have_dtab");
+        record.prevq = q;
+    }
+
+    if param.flags.contains(Flags::DO_SEL) {
+        ctx += u32::from(record.sel) << param.s_loc;
+    }
+
+    (ctx & 0xffff) as u16
+}
+
+/// Quality score statistics of a slice.
+struct Stats {
+    symbol_counts: [usize; 256],
+    first_len: usize,
+    is_fixed_len: bool,
+    duplicate_count: usize,
+    record_count: usize,
+}
+
+impl Stats {
+    fn new(records: &[(usize, sam::alignment::record::Flags)], src: &[u8]) -> Self {
+        let mut symbol_counts = [0; 256];
+
+        for &q in src {
+            symbol_counts[usize::from(q)] += 1;
         }
 
-        if param.flags.contains(parameter::Flags::DO_SEL) {
-            todo!("do_sel");
+        let first_len = records.first().map(|(len, _)| *len).unwrap_or_default();
+        let is_fixed_len = records.iter().all(|(len, _)| *len == first_len);
+
+        let mut duplicate_count = 0;
+        let mut i = 0;
+        let mut prev_len = 0;
+
+        for &(len, _) in records {
+            if is_duplicate(src, i, len, prev_len) {
+                duplicate_count += 1;
+            }
+
+            i += len;
+            prev_len = len;
         }
 
-        last &= 0xffff;
-        p -= 1;
+        Self {
+            symbol_counts,
+            first_len,
+            is_fixed_len,
+            duplicate_count,
+            record_count: records.len(),
+        }
     }
 
-    range_coder.range_encode_end(&mut dst)?;
+    fn symbols(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..=u8::MAX).filter(|&q| self.symbol_counts[usize::from(q)] > 0)
+    }
 
-    Ok(dst)
+    fn max_symbol(&self) -> u8 {
+        self.symbols().last().unwrap_or_default()
+    }
 }
 
 struct Parameters {
-    pub gflags: parameters::Flags,
-    pub max_sel: u8,
-    pub s_tab: Vec<u8>,
-    pub params: Vec<Parameter>,
-    pub max_sym: u8,
+    gflags: parameters::Flags,
+    max_sel: u8,
+    s_tab: Vec<u8>,
+    params: Vec<Parameter>,
+    max_sym: u8,
+    selector: Selector,
 }
 
 struct Parameter {
-    pub context: u16,
-    pub flags: parameter::Flags,
+    context: u16,
+    flags: parameter::Flags,
 
-    pub max_sym: u8,
+    max_sym: u8,
 
-    pub q_bits: u8,
-    pub q_shift: u8,
-    pub q_loc: u8,
+    q_bits: u8,
+    q_shift: u8,
+    q_loc: u8,
 
-    pub s_loc: u8,
+    s_loc: u8,
 
-    pub p_loc: u8,
+    p_loc: u8,
 
-    pub d_loc: u8,
+    d_loc: u8,
 
-    pub q_tab: Vec<u8>,
-    pub p_tab: Vec<u8>,
+    q_map: Vec<u8>,
+    // The inverse of `q_map`, i.e., quality score to symbol.
+    q_map_inverse: Vec<u8>,
+    q_tab: Vec<u8>,
+    p_tab: Vec<u8>,
+    d_tab: Vec<u8>,
 }
 
-fn build_parameters(lens: &[usize], src: &[u8]) -> Parameters {
-    let mut max_symbol = u8::MIN;
-    let mut symbol_counts = [0; 256];
+fn build_parameters(strategy: &Strategy, stats: &Stats) -> Parameters {
+    let mut flags = parameter::Flags::empty();
 
-    for &b in src {
-        let sym = usize::from(b);
-        symbol_counts[sym] += 1;
-        max_symbol = max_symbol.max(b);
-    }
+    let symbols: Vec<_> = stats.symbols().collect();
+    let max_symbol = stats.max_symbol();
 
-    let q_shift = 5;
-    let q_bits = if q_shift > 4 { 9 } else { 8 };
-    let p_bits = 7;
-    let p_shift = i32::from(lens[0] > 128);
+    let mut q_shift = strategy.q_shift;
+
+    let (max_sym, q_map, q_map_inverse) =
+        if symbols.len() <= MAX_QMAP_SYMBOL_COUNT && symbols.len() < usize::from(max_symbol) + 1 {
+            flags |= parameter::Flags::HAVE_QMAP;
+
+            let mut q_map_inverse = vec![0; 256];
+
+            for (sym, &q) in symbols.iter().enumerate() {
+                q_map_inverse[usize::from(q)] = sym as u8;
+            }
+
+            // The dense alphabet needs fewer bits per quality score in the context.
+            q_shift = q_shift.min(bit_width(symbols.len() - 1).max(1));
+
+            (symbols.len() as u8, symbols, q_map_inverse)
+        } else {
+            (max_symbol, Vec::new(), (0..=u8::MAX).collect())
+        };
 
     let q_tab: Vec<_> = (0..=u8::MAX).collect();
 
-    let mut p_tab = vec![0; 1024];
+    let p_tab = if strategy.p_bits > 0 {
+        flags |= parameter::Flags::HAVE_PTAB;
 
-    for (i, p) in p_tab.iter_mut().enumerate() {
-        *p = ((1 << p_bits) - 1).min(i >> p_shift) as u8;
-    }
+        let p_shift = strategy
+            .p_shift
+            .unwrap_or_else(|| auto_p_shift(stats.first_len, strategy.p_bits));
+
+        let max = (1 << strategy.p_bits) - 1;
+        (0..1024).map(|i| max.min(i >> p_shift) as u8).collect()
+    } else {
+        Vec::new()
+    };
 
-    let mut flags = parameter::Flags::HAVE_PTAB;
+    let d_tab = if strategy.d_bits > 0 {
+        flags |= parameter::Flags::HAVE_DTAB;
 
-    if lens.windows(2).all(|w| w[0] == w[1]) {
+        let max = (1 << strategy.d_bits) - 1;
+        (0..256usize)
+            .map(|i| max.min(sqrt(i >> strategy.d_shift)) as u8)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    if stats.is_fixed_len {
         flags |= parameter::Flags::DO_LEN;
     }
 
+    if stats.duplicate_count > 0 && stats.duplicate_count * DEDUP_RECORD_RATIO >= stats.record_count
+    {
+        flags |= parameter::Flags::DO_DEDUP;
+    }
+
+    let mut gflags = parameters::Flags::empty();
+    let mut max_sel = 0;
+
+    if strategy.selector != Selector::None {
+        flags |= parameter::Flags::DO_SEL;
+        gflags |= parameters::Flags::HAVE_S_TAB;
+        max_sel = 1;
+    }
+
     let params = vec![Parameter {
         context: 0,
         flags,
-        max_sym: max_symbol,
-        q_bits,
+        max_sym,
+        q_bits: strategy.q_bits.min(MAX_CONTEXT_BITS - strategy.q_loc),
         q_shift,
-        q_loc: 7,
-        s_loc: 15,
-        p_loc: 0,
-        d_loc: 15,
+        q_loc: strategy.q_loc,
+        s_loc: strategy.s_loc,
+        p_loc: strategy.p_loc,
+        d_loc: strategy.d_loc,
+        q_map,
+        q_map_inverse,
         q_tab,
         p_tab,
+        d_tab,
     }];
 
-    let last_i = (params.len() - 1) as u8;
-    let mut s_tab = vec![last_i; 256];
-
-    for (i, s) in s_tab.iter_mut().enumerate().take(params.len()) {
-        *s = i as u8;
-    }
+    // Every selector maps to the single parameter block.
+    let s_tab = vec![0; 256];
 
     Parameters {
-        gflags: parameters::Flags::empty(),
-        max_sel: 0,
+        gflags,
+        max_sel,
         s_tab,
         params,
-        max_sym: max_symbol,
+        max_sym,
+        selector: strategy.selector,
+    }
+}
+
+fn auto_p_shift(len: usize, p_bits: u8) -> u8 {
+    // Scale positions so that the first record length spans the position table.
+    let n = len >> p_bits;
+
+    if n == 0 {
+        0
+    } else {
+        // round(log2(n))
+        let shift = n.ilog2();
+        let rounded = if 2 * n >= 3 << shift {
+            shift + 1
+        } else {
+            shift
+        };
+
+        rounded as u8
     }
 }
 
+// Returns the integer square root of `n`.
+fn sqrt(n: usize) -> usize {
+    (n as f64).sqrt() as usize
+}
+
+fn bit_width(n: usize) -> u8 {
+    (usize::BITS - n.leading_zeros()) as u8
+}
+
 fn fqz_encode_params<W>(writer: &mut W, parameters: &Parameters) -> io::Result<()>
 where
     W: Write,
@@ -228,11 +546,11 @@ where
     writer.write_u8((parameter.p_loc << 4) | parameter.d_loc)?;
 
     if parameter.flags.contains(parameter::Flags::HAVE_QMAP) {
-        todo!("have_qmap");
+        writer.write_all(&parameter.q_map)?;
     }
 
     if parameter.flags.contains(parameter::Flags::HAVE_QTAB) {
-        todo!("have_qtab");
+        write_array(writer, &parameter.q_tab)?;
     }
 
     if parameter.flags.contains(parameter::Flags::HAVE_PTAB) {
@@ -240,7 +558,7 @@ where
     }
 
     if parameter.flags.contains(parameter::Flags::HAVE_DTAB) {
-        todo!("have_dtab");
+        write_array(writer, &parameter.d_tab)?;
     }
 
     Ok(())
@@ -329,7 +647,17 @@ where
 
 #[cfg(test)]
 mod tests {
+    use sam::alignment::record::Flags;
+
     use super::*;
+    use crate::codecs::fqzcomp::{
+        decode,
+        parameters::{fqz_decode_params, read_array},
+    };
+
+    fn records(lens: &[usize]) -> Vec<(usize, Flags)> {
+        lens.iter().map(|&len| (len, Flags::empty())).collect()
+    }
 
     #[test]
     fn test_encode() -> io::Result<()> {
@@ -340,42 +668,130 @@ mod tests {
         ];
 
         let lens: Vec<_> = data.iter().map(|scores| scores.len()).collect();
+        let flags = vec![Flags::empty(); lens.len()];
         let src: Vec<_> = data.into_iter().flatten().collect();
 
-        let actual = encode(&lens, &src)?;
+        let actual = encode(&lens, &flags, &src)?;
+        assert_eq!(decode(&mut &actual[..])?, src);
 
-        let expected = [
-            0x19, 0x05, 0x00, 0x00, 0x00, 0x20, 0x03, 0x95, 0x7f, 0x0f, 0x01, 0x01, 0x7d, 0xff,
-            0xff, 0x01, 0x84, 0x00, 0x09, 0xff, 0xff, 0xf6, 0x01, 0x65, 0x00, 0x86, 0x2e, 0x98,
-            0xea, 0xca, 0x71, 0x6f, 0x08, 0x51, 0x6f, 0x00,
-        ];
+        assert!(matches!(
+            encode(&lens, &flags[1..], &src),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
 
-        assert_eq!(actual, expected);
+        assert!(matches!(
+            encode(&lens, &flags, &src[1..]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
 
         Ok(())
     }
 
     #[test]
-    fn test_encode_with_do_len() -> io::Result<()> {
+    fn test_encode_with_parameters() -> io::Result<()> {
         let data = [
-            vec![0, 0, 0, 1, 1, 2, 1, 1, 0, 0],
-            vec![0, 1, 2, 3, 3, 3, 3, 3, 3, 3],
-            vec![2, 1, 1, 0, 0, 0, 0, 0, 1, 1],
+            vec![30, 30, 30, 31, 31, 32, 31, 31, 30, 30],
+            vec![30, 31, 32, 33, 33, 33, 33, 33, 33, 33],
+            vec![30, 31, 32, 33, 33, 33, 33, 33, 33, 33],
+            vec![32, 31, 31, 30, 30, 30, 30, 30, 31, 31],
         ];
 
         let lens: Vec<_> = data.iter().map(|scores| scores.len()).collect();
         let src: Vec<_> = data.into_iter().flatten().collect();
 
-        let actual = encode(&lens, &src)?;
+        let records: Vec<_> = lens
+            .iter()
+            .enumerate()
+            .map(|(i, &len)| {
+                let flags = if i % 2 == 0 {
+                    Flags::FIRST_SEGMENT
+                } else {
+                    Flags::LAST_SEGMENT
+                };
+
+                (len, flags)
+            })
+            .collect();
+
+        let stats = Stats::new(&records, &src);
+
+        for strategy in &STRATEGIES {
+            let parameters = build_parameters(strategy, &stats);
+            let dst = encode_with_parameters(&records, &src, &parameters)?;
+            assert_eq!(decode(&mut &dst[..])?, src);
+        }
 
-        let expected = [
-            0x1e, 0x05, 0x00, 0x00, 0x00, 0x24, 0x03, 0x95, 0x7f, 0x0f, 0x01, 0x01, 0x7d, 0xff,
-            0xff, 0x01, 0x84, 0x00, 0x09, 0xff, 0xff, 0xf6, 0x01, 0x65, 0x0c, 0x10, 0x86, 0x6d,
-            0x57, 0x10, 0x38, 0x60, 0xac,
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_parameters() -> io::Result<()> {
+        let data = [
+            vec![2, 12, 23, 37, 37, 37],
+            vec![2, 12, 23, 37, 37, 37],
+            vec![37, 37, 23, 12, 2, 2],
         ];
 
-        assert_eq!(actual, expected);
+        let lens: Vec<_> = data.iter().map(|scores| scores.len()).collect();
+        let src: Vec<_> = data.into_iter().flatten().collect();
+        let stats = Stats::new(&records(&lens), &src);
+
+        assert_eq!(stats.duplicate_count, 1);
+        assert!(stats.is_fixed_len);
+
+        let parameters = build_parameters(&STRATEGIES[0], &stats);
+        let param = &parameters.params[0];
+
+        assert!(param.flags.contains(parameter::Flags::HAVE_QMAP));
+        assert!(param.flags.contains(parameter::Flags::HAVE_PTAB));
+        assert!(param.flags.contains(parameter::Flags::HAVE_DTAB));
+        assert!(param.flags.contains(parameter::Flags::DO_LEN));
+        assert!(param.flags.contains(parameter::Flags::DO_DEDUP));
+        assert!(!param.flags.contains(parameter::Flags::DO_SEL));
+        assert_eq!(param.max_sym, 4);
+        assert_eq!(param.q_map, [2, 12, 23, 37]);
+        assert_eq!(param.q_shift, 2);
+
+        let parameters = build_parameters(&STRATEGIES[1], &stats);
+        let param = &parameters.params[0];
+
+        assert!(parameters.gflags.contains(parameters::Flags::HAVE_S_TAB));
+        assert_eq!(parameters.max_sel, 1);
+        assert!(param.flags.contains(parameter::Flags::DO_SEL));
+        assert!(!param.flags.contains(parameter::Flags::HAVE_DTAB));
+
+        // The written parameters can be read back.
+        let mut buf = Vec::new();
+        fqz_encode_params(&mut buf, &parameters)?;
+        let actual = fqz_decode_params(&mut &buf[..])?;
+        assert_eq!(actual.gflags, parameters.gflags);
+        assert_eq!(actual.max_sel, parameters.max_sel);
+        assert_eq!(actual.s_tab, parameters.s_tab);
+        assert_eq!(actual.params[0].q_map.as_deref(), Some(&param.q_map[..]));
+        assert_eq!(actual.params[0].p_tab.as_deref(), Some(&param.p_tab[..]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_array() -> io::Result<()> {
+        let d_tab: Vec<_> = (0..256usize)
+            .map(|i| 3.min((i >> 1).isqrt()) as u8)
+            .collect();
+
+        let mut buf = Vec::new();
+        write_array(&mut buf, &d_tab)?;
+        assert_eq!(read_array(&mut &buf[..], d_tab.len())?, d_tab);
 
         Ok(())
     }
+
+    #[test]
+    fn test_auto_p_shift() {
+        assert_eq!(auto_p_shift(0, 4), 0);
+        assert_eq!(auto_p_shift(16, 4), 0);
+        assert_eq!(auto_p_shift(100, 4), 3);
+        assert_eq!(auto_p_shift(151, 4), 3);
+        assert_eq!(auto_p_shift(250, 4), 4);
+    }
 }
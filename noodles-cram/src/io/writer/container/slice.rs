@@ -224,13 +224,14 @@ fn build_blocks(
                     Some(Encoder::Fqzcomp) => {
                         if all_quality_scores_stored_as_arrays {
                             let lens: Vec<_> = records.iter().map(|r| r.read_length).collect();
-                            let data = fqzcomp::encode(&lens, &buf)?;
+                            let flags: Vec<_> = records.iter().map(|r| r.bam_flags).collect();
+                            let data = fqzcomp::encode(&lens, &flags, &buf)?;
 
                             Ok(Block {
                                 compression_method: CompressionMethod::Fqzcomp,
                                 content_type,
                                 content_id: block_content_id,
-                                uncompressed_size: data.len(),
+                                uncompressed_size: buf.len(),
                                 src: data,
                             })
                         } else {
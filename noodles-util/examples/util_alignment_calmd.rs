@@ -0,0 +1,65 @@
+//! Calculates the MD and NM data fields of records in an alignment file.
+//!
+//! Records are written as SAM to stdout. Existing MD and NM fields that differ from the calculated
+//! values are reported to stderr.
+//!
+//! The result is similar to `samtools calmd <src> <fasta-src>`.
+
+use std::{
+    env,
+    io::{self, BufWriter},
+};
+
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    alignment::{io::Write, RecordBuf},
+};
+use noodles_util::alignment::{self, calmd};
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let fasta_src = args.next().expect("missing fasta-src");
+
+    let repository = fasta::io::indexed_reader::Builder::default()
+        .build_from_path(fasta_src)
+        .map(fasta::repository::adapters::IndexedReader::new)
+        .map(fasta::Repository::new)?;
+
+    let mut reader = alignment::io::reader::Builder::default()
+        .set_reference_sequence_repository(repository.clone())
+        .build_from_path(src)?;
+
+    let header = reader.read_header()?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = sam::io::Writer::new(BufWriter::new(stdout));
+    writer.write_header(&header)?;
+
+    for result in reader.records(&header) {
+        let record = result?;
+        let mut record = RecordBuf::try_from_alignment_record(&header, &record)?;
+
+        for mismatch in calmd::update(&header, &repository, &mut record)? {
+            let name = record
+                .name()
+                .map(|name| name.to_string())
+                .unwrap_or_default();
+
+            eprintln!(
+                "{name}: {:?}: {:?} != {:?}",
+                mismatch.tag(),
+                mismatch.actual(),
+                mismatch.expected()
+            );
+        }
+
+        writer.write_alignment_record(&header, &record)?;
+    }
+
+    writer.finish(&header)?;
+
+    Ok(())
+}
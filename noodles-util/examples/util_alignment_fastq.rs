@@ -0,0 +1,55 @@
+//! Converts name-grouped alignment records to the FASTQ format.
+//!
+//! Primary records are written to separate files for first segments, last segments, and
+//! singletons. Data fields with the given comma-separated tags, e.g., `BC,RX`, are copied to the
+//! FASTQ record descriptions.
+//!
+//! The result is similar to the output of `samtools fastq -1 <r1-dst> -2 <r2-dst> -0 <s-dst> -s
+//! <s-dst> [-T <tags>] <src>`.
+
+use std::{env, fs::File, io};
+
+use noodles_sam::alignment::record::data::field::Tag;
+use noodles_util::alignment::{self, fastq, iter::Templates};
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let r1_dst = args.next().expect("missing r1-dst");
+    let r2_dst = args.next().expect("missing r2-dst");
+    let singletons_dst = args.next().expect("missing singletons-dst");
+    let tags = args.next();
+
+    let mut builder = fastq::Builder::default().set_use_original_quality_scores(true);
+
+    if let Some(tags) = tags {
+        let tags = tags
+            .split(',')
+            .map(|s| {
+                <[u8; 2]>::try_from(s.as_bytes())
+                    .map(Tag::from)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+            })
+            .collect::<io::Result<_>>()?;
+
+        builder = builder.set_data_tags(tags);
+    }
+
+    let mut reader = alignment::io::reader::Builder::default().build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let mut writer = fastq::Writer::new(
+        builder.build(),
+        File::create(r1_dst)?,
+        File::create(r2_dst)?,
+        File::create(singletons_dst)?,
+    );
+
+    for result in Templates::new(reader.records(&header)) {
+        let template = result?;
+        writer.write_template(&template)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+//! Fixes the mate information of name-grouped alignment records and prints them in the SAM format.
+//!
+//! The result is similar to the output of `samtools fixmate -O sam <src> -`.
+
+use std::{
+    env,
+    io::{self, BufWriter},
+};
+
+use noodles_sam::{
+    self as sam,
+    alignment::{io::Write, RecordBuf},
+};
+use noodles_util::alignment::{self, fixmate, iter::Templates};
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = alignment::io::reader::Builder::default().build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = sam::io::Writer::new(BufWriter::new(stdout));
+
+    writer.write_header(&header)?;
+
+    for result in Templates::new_unchecked(reader.records(&header)) {
+        let template = result?;
+
+        let mut records = template
+            .records()
+            .iter()
+            .map(|record| RecordBuf::try_from_alignment_record(&header, record))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        fixmate::update(&mut records)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+    }
+
+    Ok(())
+}
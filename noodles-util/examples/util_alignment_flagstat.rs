@@ -0,0 +1,42 @@
+//! Counts alignment records by flags.
+//!
+//! Reference sequences in the FASTA format are only required for CRAM inputs that require them.
+//!
+//! The result matches the output of `samtools flagstat [--reference <fasta-src>] <src>`.
+
+use std::{env, io};
+
+use noodles_fasta::{self as fasta, repository::adapters::IndexedReader};
+use noodles_util::alignment::{self, flagstat::FlagStat};
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let fasta_src = args.next();
+
+    let mut builder = alignment::io::reader::Builder::default();
+
+    if let Some(fasta_src) = fasta_src {
+        let repository = fasta::io::indexed_reader::Builder::default()
+            .build_from_path(fasta_src)
+            .map(IndexedReader::new)
+            .map(fasta::Repository::new)?;
+
+        builder = builder.set_reference_sequence_repository(repository);
+    }
+
+    let mut reader = builder.build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let mut flagstat = FlagStat::default();
+
+    for result in reader.records(&header) {
+        let record = result?;
+        flagstat.add(&header, &record)?;
+    }
+
+    print!("{flagstat}");
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+//! Prints alignment index statistics.
+//!
+//! The statistics are read from the index metadata of SAM and BAM inputs. CRAM indices do not
+//! have this metadata, so the records are scanned instead.
+//!
+//! The result matches the output of `samtools idxstats <src>`.
+
+use std::{env, io};
+
+use noodles_util::alignment::{self, idxstats::IdxStats, io::IndexedReader};
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = alignment::io::indexed_reader::Builder::default().build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let idxstats = match &reader {
+        IndexedReader::Sam(r) => IdxStats::from_index(&header, r.index()),
+        IndexedReader::Bam(r) => IdxStats::from_index(&header, r.index()),
+        IndexedReader::Cram(_) => None,
+    };
+
+    let idxstats = match idxstats {
+        Some(idxstats) => idxstats,
+        None => IdxStats::scan(&header, reader.records(&header))?,
+    };
+
+    print!("{idxstats}");
+
+    Ok(())
+}
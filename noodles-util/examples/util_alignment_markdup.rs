@@ -0,0 +1,78 @@
+//! Marks duplicate records in an alignment file.
+//!
+//! Records are written as SAM to stdout, and duplication metrics are written to stderr.
+//!
+//! The result is similar to `picard MarkDuplicates --INPUT <src>`.
+
+use std::{
+    env,
+    io::{self, BufWriter},
+};
+
+use noodles_sam::{
+    self as sam,
+    alignment::{io::Write, record::Flags, RecordBuf},
+};
+use noodles_util::alignment::{self, markdup};
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = alignment::io::reader::Builder::default().build_from_path(&src)?;
+    let header = reader.read_header()?;
+
+    let mut detector = markdup::Detector::new(&header);
+
+    for result in reader.records(&header) {
+        let record = result?;
+        detector.push(&record)?;
+    }
+
+    let duplicates = detector.finish();
+
+    let mut reader = alignment::io::reader::Builder::default().build_from_path(&src)?;
+    reader.read_header()?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = sam::io::Writer::new(BufWriter::new(stdout));
+    writer.write_header(&header)?;
+
+    for (i, result) in reader.records(&header).enumerate() {
+        let record = result?;
+        let mut record = RecordBuf::try_from_alignment_record(&header, &record)?;
+
+        let flags = record.flags_mut();
+        flags.set(Flags::DUPLICATE, duplicates.is_duplicate(i));
+
+        writer.write_alignment_record(&header, &record)?;
+    }
+
+    writer.finish(&header)?;
+
+    eprintln!(
+        "LIBRARY\tUNPAIRED_READS_EXAMINED\tREAD_PAIRS_EXAMINED\tSECONDARY_OR_SUPPLEMENTARY_RDS\tUNMAPPED_READS\tUNPAIRED_READ_DUPLICATES\tREAD_PAIR_DUPLICATES\tREAD_PAIR_OPTICAL_DUPLICATES\tPERCENT_DUPLICATION\tESTIMATED_LIBRARY_SIZE"
+    );
+
+    for metrics in duplicates.metrics() {
+        let estimated_library_size = metrics
+            .estimated_library_size()
+            .map(|n| n.to_string())
+            .unwrap_or_default();
+
+        eprintln!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{:.6}\t{}",
+            metrics.library(),
+            metrics.unpaired_reads_examined(),
+            metrics.read_pairs_examined(),
+            metrics.secondary_or_supplementary_reads(),
+            metrics.unmapped_reads(),
+            metrics.unpaired_read_duplicates(),
+            metrics.read_pair_duplicates(),
+            metrics.read_pair_optical_duplicates(),
+            metrics.percent_duplication(),
+            estimated_library_size,
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//! Merges coordinate-sorted alignment files.
+//!
+//! The output format is determined from the extension of the destination.
+
+use std::{env, io};
+
+use noodles_util::alignment;
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let dst = args.next().expect("missing dst");
+    let srcs: Vec<_> = args.collect();
+
+    let mut readers = srcs
+        .iter()
+        .map(|src| alignment::io::reader::Builder::default().build_from_path(src))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let headers = readers
+        .iter_mut()
+        .map(|reader| reader.read_header())
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let records = alignment::merge::Builder::default().build(&mut readers, &headers)?;
+    let header = records.header().clone();
+
+    let mut writer = alignment::io::writer::Builder::default().build_from_path(dst)?;
+    writer.write_header(&header)?;
+
+    for result in records {
+        let record = result?;
+        writer.write_record(&header, &record)?;
+    }
+
+    writer.finish(&header)?;
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+//! Prints the per-sample depths of positions in a list of regions across alignment files.
+//!
+//! The regions file has one region per line. Each alignment file must be coordinate-sorted and
+//! indexed, and samples are taken from its read groups.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+};
+
+use noodles_core::Region;
+use noodles_util::alignment::{
+    self,
+    pileup::{self, multi_sample_pileup::Samples, MultiSamplePileup},
+};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+
+    let regions_src = args.next().expect("missing regions-src");
+    let srcs: Vec<_> = args.collect();
+
+    let mut pileup = MultiSamplePileup::new(pileup::Builder::default());
+
+    for src in srcs {
+        let mut reader = alignment::io::indexed_reader::Builder::default().build_from_path(src)?;
+        let header = reader.read_header()?;
+        pileup.add_input(reader, header, Samples::ReadGroups);
+    }
+
+    let stdout = io::stdout().lock();
+    let mut writer = BufWriter::new(stdout);
+
+    write!(writer, "#name\tposition")?;
+
+    for sample in pileup.samples() {
+        write!(writer, "\t{sample}")?;
+    }
+
+    writeln!(writer)?;
+
+    let regions_reader = File::open(regions_src).map(BufReader::new)?;
+
+    for result in regions_reader.lines() {
+        let line = result?;
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let region: Region = line.parse()?;
+
+        for result in pileup.query(&region)? {
+            let column = result?;
+
+            write!(writer, "{}\t{}", region.name(), column.position())?;
+
+            for sample_column in column.columns() {
+                let depth = sample_column
+                    .as_ref()
+                    .map(|c| c.depth())
+                    .unwrap_or_default();
+                write!(writer, "\t{depth}")?;
+            }
+
+            writeln!(writer)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+//! Prints a text pileup of an alignment file.
+//!
+//! The input must be coordinate-sorted. If a reference sequence FASTA is given, matching bases are
+//! written as `.` (forward) and `,` (reverse).
+//!
+//! The output is similar to `samtools mpileup [--fasta-ref <fasta-src>] <src>`.
+
+use std::{
+    env,
+    io::{self, BufWriter, Write},
+};
+
+use noodles_fasta as fasta;
+use noodles_util::alignment::{
+    self,
+    pileup::{
+        self,
+        column::{
+            entry::{Indel, Kind},
+            Entry,
+        },
+    },
+};
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let fasta_src = args.next();
+
+    let mut reader_builder = alignment::io::reader::Builder::default();
+    let mut pileup_builder = pileup::Builder::default();
+
+    if let Some(fasta_src) = fasta_src {
+        let repository = fasta::io::indexed_reader::Builder::default()
+            .build_from_path(fasta_src)
+            .map(fasta::repository::adapters::IndexedReader::new)
+            .map(fasta::Repository::new)?;
+
+        reader_builder = reader_builder.set_reference_sequence_repository(repository.clone());
+        pileup_builder = pileup_builder.set_reference_sequence_repository(repository);
+    }
+
+    let mut reader = reader_builder.build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = BufWriter::new(stdout);
+
+    let mut bases = Vec::new();
+    let mut quality_scores = Vec::new();
+
+    for result in pileup_builder.build(&header, reader.records(&header)) {
+        let column = result?;
+
+        let (name, _) = header
+            .reference_sequences()
+            .get_index(column.reference_sequence_id())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid reference"))?;
+
+        let reference_base = column.reference_base().map(|b| b.to_ascii_uppercase());
+
+        bases.clear();
+        quality_scores.clear();
+
+        for entry in column.entries() {
+            write_entry(&mut bases, reference_base, entry);
+            quality_scores.push(entry.quality_score().unwrap_or(0).min(93) + b'!');
+        }
+
+        write!(writer, "{name}\t{}\t", column.position())?;
+        writer.write_all(&[reference_base.unwrap_or(b'N')])?;
+        write!(writer, "\t{}\t", column.entries().len())?;
+        writer.write_all(&bases)?;
+        writer.write_all(b"\t")?;
+        writer.write_all(&quality_scores)?;
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
+fn write_entry(dst: &mut Vec<u8>, reference_base: Option<u8>, entry: &Entry) {
+    let is_reverse_complemented = entry.is_reverse_complemented();
+
+    let set_case = |b: u8| {
+        if is_reverse_complemented {
+            b.to_ascii_lowercase()
+        } else {
+            b.to_ascii_uppercase()
+        }
+    };
+
+    if entry.is_head() {
+        let mapping_quality = entry.mapping_quality().map(u8::from).unwrap_or(255);
+        dst.push(b'^');
+        dst.push(mapping_quality.min(93) + b'!');
+    }
+
+    match entry.kind() {
+        Kind::Base(base) if Some(base.to_ascii_uppercase()) == reference_base => {
+            dst.push(if is_reverse_complemented { b',' } else { b'.' });
+        }
+        Kind::Base(base) => dst.push(set_case(base)),
+        Kind::Deletion => dst.push(b'*'),
+        Kind::Skip => dst.push(if is_reverse_complemented { b'<' } else { b'>' }),
+    }
+
+    match entry.indel() {
+        Some(Indel::Insertion(bases)) => {
+            dst.push(b'+');
+            dst.extend(bases.len().to_string().bytes());
+            dst.extend(bases.iter().copied().map(set_case));
+        }
+        Some(Indel::Deletion(len)) => {
+            dst.push(b'-');
+            dst.extend(len.to_string().bytes());
+            dst.extend((0..*len).map(|_| set_case(b'N')));
+        }
+        None => {}
+    }
+
+    if entry.is_tail() {
+        dst.push(b'$');
+    }
+}
@@ -0,0 +1,52 @@
+//! Sorts an alignment file.
+//!
+//! The output format is determined from the extension of the destination. The sort order is one
+//! of `coordinate` (default), `queryname`, or `template-coordinate`.
+
+use std::{env, io};
+
+use noodles_util::alignment::{
+    self,
+    sort::{self, SortOrder},
+};
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let dst = args.next().expect("missing dst");
+
+    let sort_order = match args.next().as_deref() {
+        None | Some("coordinate") => SortOrder::Coordinate,
+        Some("queryname") => SortOrder::QueryName,
+        Some("template-coordinate") => SortOrder::TemplateCoordinate,
+        Some(s) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid sort order: {s}"),
+            ))
+        }
+    };
+
+    let mut reader = alignment::io::reader::Builder::default().build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let sorter = sort::Builder::default()
+        .set_sort_order(sort_order)
+        .build(header.clone());
+
+    let records = sorter.sort(reader.records(&header))?;
+    let header = records.header().clone();
+
+    let mut writer = alignment::io::writer::Builder::default().build_from_path(dst)?;
+    writer.write_header(&header)?;
+
+    for result in records {
+        let record = result?;
+        writer.write_record(&header, &record)?;
+    }
+
+    writer.finish(&header)?;
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+//! Prints alignment statistics.
+//!
+//! Statistics are collected for each reference sequence of an indexed alignment file in parallel
+//! and merged. Unplaced unmapped records are not counted.
+//!
+//! The output is a subset of the sections of `samtools stats <src>`: summary numbers (`SN`), read
+//! lengths (`RL`), insert sizes (`IS`), and coverage (`COV`).
+
+use std::{env, io, thread};
+
+use noodles_core::Region;
+use noodles_util::alignment::{
+    self,
+    stats::{Collector, Stats},
+};
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = alignment::io::indexed_reader::Builder::default().build_from_path(&src)?;
+    let header = reader.read_header()?;
+
+    let names: Vec<_> = header
+        .reference_sequences()
+        .keys()
+        .map(|name| name.to_string())
+        .collect();
+
+    let stats = thread::scope(|scope| {
+        let handles: Vec<_> = names
+            .iter()
+            .map(|name| scope.spawn(|| collect(&src, name)))
+            .collect();
+
+        let mut stats = Stats::default();
+
+        for handle in handles {
+            let partial_stats = handle.join().unwrap()?;
+            stats.merge(&partial_stats);
+        }
+
+        Ok::<_, io::Error>(stats)
+    })?;
+
+    print_stats(&stats);
+
+    Ok(())
+}
+
+fn collect(src: &str, name: &str) -> io::Result<Stats> {
+    let mut reader = alignment::io::indexed_reader::Builder::default().build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let region = Region::new(name, ..);
+    let mut collector = Collector::new(&header);
+
+    for result in reader.query(&header, &region)? {
+        let record = result?;
+        collector.add(&record)?;
+    }
+
+    Ok(collector.finish())
+}
+
+fn print_stats(stats: &Stats) {
+    println!("SN\traw total sequences:\t{}", stats.record_count());
+    println!(
+        "SN\tnon-primary alignments:\t{}",
+        stats.non_primary_record_count()
+    );
+    println!("SN\treads mapped:\t{}", stats.mapped_record_count());
+    println!("SN\treads unmapped:\t{}", stats.unmapped_record_count());
+    println!(
+        "SN\treads mapped and paired:\t{}",
+        stats.mapped_and_paired_record_count()
+    );
+    println!(
+        "SN\treads properly paired:\t{}",
+        stats.properly_paired_record_count()
+    );
+    println!("SN\treads duplicated:\t{}", stats.duplicate_record_count());
+    println!("SN\treads MQ0:\t{}", stats.mapping_quality_0_record_count());
+    println!("SN\treads QC failed:\t{}", stats.qc_fail_record_count());
+    println!("SN\ttotal length:\t{}", stats.total_length());
+    println!("SN\tbases mapped:\t{}", stats.mapped_length());
+    println!("SN\tbases mapped (cigar):\t{}", stats.mapped_cigar_length());
+
+    if let Some(average_length) = stats.average_length() {
+        println!("SN\taverage length:\t{average_length:.0}");
+    }
+
+    if let Some(average_quality) = stats.average_quality() {
+        println!("SN\taverage quality:\t{average_quality:.1}");
+    }
+
+    if let Some((mean, standard_deviation)) = stats.insert_size_mean_and_standard_deviation() {
+        println!("SN\tinsert size average:\t{mean:.1}");
+        println!("SN\tinsert size standard deviation:\t{standard_deviation:.1}");
+    }
+
+    println!("SN\tinward oriented pairs:\t{}", stats.inward_pair_count());
+    println!(
+        "SN\toutward oriented pairs:\t{}",
+        stats.outward_pair_count()
+    );
+    println!(
+        "SN\tpairs with other orientation:\t{}",
+        stats.other_pair_count()
+    );
+
+    let first_read_lengths = stats.first_fragments().read_lengths();
+    let last_read_lengths = stats.last_fragments().read_lengths();
+
+    for read_length in 0..first_read_lengths.len().max(last_read_lengths.len()) {
+        let count = first_read_lengths.get(read_length).unwrap_or(&0)
+            + last_read_lengths.get(read_length).unwrap_or(&0);
+
+        if count > 0 {
+            println!("RL\t{read_length}\t{count}");
+        }
+    }
+
+    for (insert_size, count) in stats.insert_sizes().iter().enumerate() {
+        if *count > 0 {
+            println!("IS\t{insert_size}\t{count}");
+        }
+    }
+
+    for (depth, count) in stats.coverage().iter().enumerate() {
+        if *count > 0 {
+            println!("COV\t[{depth}-{depth}]\t{depth}\t{count}");
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Concatenates sorted variant files and indexes the output.
+//!
+//! The inputs must have compatible headers. The output is written to `<dst>`, and its CSI index,
+//! to `<dst>.csi`. With `--naive`, BGZF blocks are copied without re-encoding records.
+//!
+//! The result is similar to `bcftools concat [--naive] --output <dst> --write-index <src>...`.
+
+use std::{env, io};
+
+use noodles_csi as csi;
+use noodles_util::variant::concat;
+
+fn main() -> io::Result<()> {
+    let mut args: Vec<_> = env::args().skip(1).collect();
+
+    let is_naive = args.first().map(|arg| arg == "--naive").unwrap_or_default();
+
+    if is_naive {
+        args.remove(0);
+    }
+
+    let (dst, srcs) = args.split_first().expect("missing dst");
+
+    if srcs.is_empty() {
+        panic!("missing srcs");
+    }
+
+    let index = if is_naive {
+        concat::concat_naive(srcs, dst)?
+    } else {
+        concat::concat(srcs, dst)?
+    };
+
+    csi::fs::write(format!("{dst}.csi"), &index)
+}
@@ -0,0 +1,48 @@
+//! Sets the allele count (`AC`), total allele count (`AN`), and allele frequency (`AF`) info
+//! fields of the records of a VCF file from their genotypes.
+//!
+//! Records are written as VCF to stdout.
+//!
+//! The result is similar to `bcftools +fill-tags <src> -- -t AC,AN,AF`.
+
+use std::{
+    env,
+    io::{self, BufWriter},
+};
+
+use noodles_util::variant::genotypes;
+use noodles_vcf::{
+    self as vcf,
+    header::record::value::{map::Info, Map},
+    variant::{io::Write, record::info::field::key},
+};
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = vcf::io::reader::Builder::default().build_from_path(src)?;
+    let mut header = reader.read_header()?;
+
+    for id in [
+        key::ALLELE_COUNT,
+        key::TOTAL_ALLELE_COUNT,
+        key::ALLELE_FREQUENCIES,
+    ] {
+        header
+            .infos_mut()
+            .entry(String::from(id))
+            .or_insert_with(|| Map::<Info>::from(id));
+    }
+
+    let stdout = io::stdout().lock();
+    let mut writer = vcf::io::Writer::new(BufWriter::new(stdout));
+    writer.write_header(&header)?;
+
+    for result in reader.record_bufs(&header) {
+        let mut record = result?;
+        genotypes::fill_allele_counts(&header, &mut record)?;
+        writer.write_variant_record(&header, &record)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//! Merges indexed variant files into a single multi-sample VCF.
+//!
+//! Each input must be sorted and indexed. Records are written as VCF to stdout.
+//!
+//! The result is similar to `bcftools merge --merge all <src>...` for records with identical
+//! reference bases.
+
+use std::{
+    env,
+    io::{self, BufWriter},
+};
+
+use noodles_util::variant;
+use noodles_vcf::{self as vcf, variant::io::Write};
+
+fn main() -> io::Result<()> {
+    let srcs: Vec<_> = env::args().skip(1).collect();
+
+    if srcs.is_empty() {
+        panic!("missing srcs");
+    }
+
+    let mut readers = srcs
+        .iter()
+        .map(|src| variant::io::indexed_reader::Builder::default().build_from_path(src))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let headers: Vec<_> = readers
+        .iter_mut()
+        .map(|reader| reader.read_header())
+        .collect::<io::Result<_>>()?;
+
+    let merge = variant::merge::Builder::default().build(&mut readers, &headers)?;
+    let header = merge.header().clone();
+
+    let stdout = io::stdout().lock();
+    let mut writer = vcf::io::Writer::new(BufWriter::new(stdout));
+    writer.write_header(&header)?;
+
+    for result in merge {
+        let record = result?;
+        writer.write_variant_record(&header, &record)?;
+    }
+
+    Ok(())
+}
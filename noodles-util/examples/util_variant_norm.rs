@@ -0,0 +1,67 @@
+//! Normalizes the records of a VCF file.
+//!
+//! Records are left-aligned and trimmed and written as VCF to stdout. Records with reference bases
+//! that differ from the reference sequence are written unchanged, and the numbers of normalized,
+//! skipped, and mismatched records are reported to stderr.
+//!
+//! The result is similar to `bcftools norm --check-ref w --fasta-ref <fasta-src> <src>`.
+
+use std::{
+    env,
+    io::{self, BufWriter},
+};
+
+use noodles_fasta as fasta;
+use noodles_util::variant::norm::{self, Status};
+use noodles_vcf::{self as vcf, variant::io::Write};
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let fasta_src = args.next().expect("missing fasta-src");
+
+    let repository = fasta::io::indexed_reader::Builder::default()
+        .build_from_path(fasta_src)
+        .map(fasta::repository::adapters::IndexedReader::new)
+        .map(fasta::Repository::new)?;
+
+    let mut reader = vcf::io::reader::Builder::default().build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = vcf::io::Writer::new(BufWriter::new(stdout));
+    writer.write_header(&header)?;
+
+    let mut normalized_record_count = 0;
+    let mut skipped_record_count = 0;
+    let mut mismatched_record_count = 0;
+
+    for result in reader.record_bufs(&header) {
+        let mut record = result?;
+
+        match norm::normalize(&repository, &mut record)? {
+            Status::Unchanged => {}
+            Status::Normalized => normalized_record_count += 1,
+            Status::Skipped => skipped_record_count += 1,
+            Status::ReferenceMismatch(expected) => {
+                eprintln!(
+                    "REF mismatch at {}:{}: expected {expected}, got {}",
+                    record.reference_sequence_name(),
+                    record.variant_start().map(usize::from).unwrap_or_default(),
+                    record.reference_bases(),
+                );
+
+                mismatched_record_count += 1;
+            }
+        }
+
+        writer.write_variant_record(&header, &record)?;
+    }
+
+    eprintln!(
+        "normalized: {normalized_record_count}, skipped: {skipped_record_count}, REF mismatches: {mismatched_record_count}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! Splits multiallelic records of a VCF file into biallelic records.
+//!
+//! Records are written as VCF to stdout.
+//!
+//! The result is similar to `bcftools norm --multiallelics -any <src>`.
+
+use std::{
+    env,
+    io::{self, BufWriter},
+};
+
+use noodles_util::variant::multiallelic;
+use noodles_vcf::{self as vcf, variant::io::Write};
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = vcf::io::reader::Builder::default().build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = vcf::io::Writer::new(BufWriter::new(stdout));
+    writer.write_header(&header)?;
+
+    for result in reader.record_bufs(&header) {
+        let record = result?;
+
+        for biallelic_record in multiallelic::split(&header, &record)? {
+            writer.write_variant_record(&header, &biallelic_record)?;
+        }
+    }
+
+    Ok(())
+}
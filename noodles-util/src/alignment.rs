@@ -3,5 +3,15 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 
+pub mod calmd;
+pub mod fastq;
+pub mod fixmate;
+pub mod flagstat;
+pub mod idxstats;
 pub mod io;
 pub mod iter;
+pub mod markdup;
+pub mod merge;
+pub mod pileup;
+pub mod sort;
+pub mod stats;
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{num::NonZeroUsize, path::Path};
 
 use noodles_bam as bam;
 use noodles_bgzf as bgzf;
@@ -18,6 +18,7 @@ use crate::alignment::io::{CompressionMethod, Format};
 pub struct Builder {
     compression_method: Option<Option<CompressionMethod>>,
     format: Option<Format>,
+    worker_count: Option<NonZeroUsize>,
     reference_sequence_repository: fasta::Repository,
 }
 
@@ -53,6 +54,23 @@ impl Builder {
         self
     }
 
+    /// Sets the number of workers used to decompress BGZF blocks.
+    ///
+    /// This is only used when the input is BGZF-compressed. By default, the worker count is set to
+    /// the number of available logical CPUs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_util::alignment::r#async::io::reader::Builder;
+    /// let _builder = Builder::default().set_worker_count(NonZeroUsize::MIN);
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
     /// Sets the reference sequence repository.
     ///
     /// # Examples
@@ -143,11 +161,11 @@ impl Builder {
         let reader: Box<dyn AsyncBufRead + Unpin> = match (format, compression_method) {
             (Format::Sam, None) => Box::new(reader),
             (Format::Sam, Some(CompressionMethod::Bgzf)) => {
-                Box::new(bgzf::AsyncReader::new(reader))
+                Box::new(self.build_bgzf_reader(reader))
             }
             (Format::Bam, None) => Box::new(reader),
             (Format::Bam, Some(CompressionMethod::Bgzf)) => {
-                Box::new(bgzf::AsyncReader::new(reader))
+                Box::new(self.build_bgzf_reader(reader))
             }
             (Format::Cram, None) => {
                 let inner: Box<dyn AsyncBufRead + Unpin> = Box::new(reader);
@@ -172,4 +190,17 @@ impl Builder {
 
         Ok(reader)
     }
+
+    fn build_bgzf_reader<R>(&self, reader: R) -> bgzf::AsyncReader<R>
+    where
+        R: AsyncRead,
+    {
+        let mut builder = bgzf::r#async::reader::Builder::default();
+
+        if let Some(worker_count) = self.worker_count {
+            builder = builder.set_worker_count(worker_count);
+        }
+
+        builder.build_from_reader(reader)
+    }
 }
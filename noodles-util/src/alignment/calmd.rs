@@ -0,0 +1,416 @@
+//! Alignment mismatched positions (`MD`) and edit distance (`NM`) calculation.
+//!
+//! This is similar to `samtools calmd`.
+
+use std::io;
+
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        record::{
+            cigar::{op::Kind, Op},
+            data::field::Tag,
+        },
+        record_buf::data::field::Value,
+        Record, RecordBuf,
+    },
+};
+
+/// Calculated mismatched positions (`MD`) and edit distance (`NM`) values.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tags {
+    md: String,
+    nm: u32,
+}
+
+impl Tags {
+    /// Returns the mismatched positions (`MD`).
+    pub fn md(&self) -> &str {
+        &self.md
+    }
+
+    /// Returns the edit distance (`NM`).
+    pub fn nm(&self) -> u32 {
+        self.nm
+    }
+}
+
+/// A disagreement between an existing data field and its calculated value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch {
+    tag: Tag,
+    actual: Value,
+    expected: Value,
+}
+
+impl Mismatch {
+    /// Returns the data field tag.
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Returns the existing value.
+    pub fn actual(&self) -> &Value {
+        &self.actual
+    }
+
+    /// Returns the calculated value.
+    pub fn expected(&self) -> &Value {
+        &self.expected
+    }
+}
+
+/// Calculates the mismatched positions (`MD`) and edit distance (`NM`) of a record.
+///
+/// This returns `None` if the record is unmapped or has no sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{
+///             cigar::{op::Kind, Op},
+///             Flags,
+///         },
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment::calmd;
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"ACGTACGT".to_vec()),
+/// )]);
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(8.try_into()?))
+///     .build();
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::MIN)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(b"ACCT".to_vec().into())
+///     .build();
+///
+/// let tags = calmd::calculate(&header, &repository, &record)?.unwrap();
+/// assert_eq!(tags.md(), "2G1");
+/// assert_eq!(tags.nm(), 1);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn calculate(
+    header: &sam::Header,
+    repository: &fasta::Repository,
+    record: &dyn Record,
+) -> io::Result<Option<Tags>> {
+    let flags = record.flags()?;
+
+    if flags.is_unmapped() {
+        return Ok(None);
+    }
+
+    let sequence = record.sequence();
+
+    if sequence.is_empty() {
+        return Ok(None);
+    }
+
+    let (Some(reference_sequence), Some(alignment_start)) = (
+        record.reference_sequence(header).transpose()?,
+        record.alignment_start().transpose()?,
+    ) else {
+        return Ok(None);
+    };
+
+    let (name, _) = reference_sequence;
+
+    let reference_sequence = repository.get(name).transpose()?.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("missing reference sequence: {name}"),
+        )
+    })?;
+
+    let ops: Vec<_> = record.cigar().iter().collect::<io::Result<_>>()?;
+    let bases: Vec<_> = sequence.iter().collect();
+
+    calculate_tags(
+        reference_sequence.as_ref(),
+        usize::from(alignment_start) - 1,
+        &ops,
+        &bases,
+    )
+    .map(Some)
+}
+
+/// Updates the mismatched positions (`MD`) and edit distance (`NM`) of a record.
+///
+/// Existing `MD` and `NM` data fields are overwritten. Any that disagree with the calculated values
+/// are returned as mismatches. Records that are unmapped or have no sequence are left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{
+///             cigar::{op::Kind, Op},
+///             data::field::Tag,
+///             Flags,
+///         },
+///         record_buf::data::field::Value,
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment::calmd;
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"ACGTACGT".to_vec()),
+/// )]);
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(8.try_into()?))
+///     .build();
+///
+/// let mut record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::MIN)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(b"ACCT".to_vec().into())
+///     .set_data([(Tag::EDIT_DISTANCE, Value::from(0u8))].into_iter().collect())
+///     .build();
+///
+/// let mismatches = calmd::update(&header, &repository, &mut record)?;
+/// assert_eq!(mismatches.len(), 1);
+/// assert_eq!(mismatches[0].tag(), Tag::EDIT_DISTANCE);
+///
+/// assert_eq!(record.data().get(&Tag::EDIT_DISTANCE), Some(&Value::from(1u8)));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn update(
+    header: &sam::Header,
+    repository: &fasta::Repository,
+    record: &mut RecordBuf,
+) -> io::Result<Vec<Mismatch>> {
+    let Some(tags) = calculate(header, repository, record)? else {
+        return Ok(Vec::new());
+    };
+
+    let mut mismatches = Vec::new();
+
+    let md = Value::from(tags.md);
+
+    if let Some(actual) = record
+        .data_mut()
+        .insert(Tag::MISMATCHED_POSITIONS, md.clone())
+        .map(|(_, value)| value)
+    {
+        if actual != md {
+            mismatches.push(Mismatch {
+                tag: Tag::MISMATCHED_POSITIONS,
+                actual,
+                expected: md,
+            });
+        }
+    }
+
+    let nm = edit_distance_value(tags.nm);
+
+    if let Some(actual) = record
+        .data_mut()
+        .insert(Tag::EDIT_DISTANCE, nm.clone())
+        .map(|(_, value)| value)
+    {
+        if actual.as_int() != Some(i64::from(tags.nm)) {
+            mismatches.push(Mismatch {
+                tag: Tag::EDIT_DISTANCE,
+                actual,
+                expected: nm,
+            });
+        }
+    }
+
+    Ok(mismatches)
+}
+
+// Uses the smallest unsigned integer type that fits, like `samtools calmd`.
+fn edit_distance_value(n: u32) -> Value {
+    if let Ok(m) = u8::try_from(n) {
+        Value::from(m)
+    } else if let Ok(m) = u16::try_from(n) {
+        Value::from(m)
+    } else {
+        Value::from(n)
+    }
+}
+
+fn calculate_tags(
+    reference_sequence: &[u8],
+    alignment_start: usize,
+    ops: &[Op],
+    bases: &[u8],
+) -> io::Result<Tags> {
+    use std::fmt::Write;
+
+    fn reference_bases(src: &[u8], start: usize, len: usize) -> io::Result<&[u8]> {
+        src.get(start..start + len).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "alignment extends past the reference sequence end",
+            )
+        })
+    }
+
+    fn read_bases(src: &[u8], start: usize, len: usize) -> io::Result<&[u8]> {
+        src.get(start..start + len).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "CIGAR read length exceeds the sequence length",
+            )
+        })
+    }
+
+    let mut md = String::new();
+    let mut nm = 0;
+    let mut match_count = 0;
+
+    let mut reference_position = alignment_start;
+    let mut read_position = 0;
+
+    for op in ops {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                let expected = reference_bases(reference_sequence, reference_position, len)?;
+                let actual = read_bases(bases, read_position, len)?;
+
+                for (&a, &b) in actual.iter().zip(expected) {
+                    if is_match(a, b) {
+                        match_count += 1;
+                    } else {
+                        write!(md, "{match_count}").unwrap();
+                        md.push(char::from(b.to_ascii_uppercase()));
+                        match_count = 0;
+                        nm += 1;
+                    }
+                }
+
+                reference_position += len;
+                read_position += len;
+            }
+            Kind::Insertion => {
+                read_bases(bases, read_position, len)?;
+                read_position += len;
+                nm += len;
+            }
+            Kind::Deletion => {
+                let expected = reference_bases(reference_sequence, reference_position, len)?;
+
+                write!(md, "{match_count}^").unwrap();
+                md.extend(expected.iter().map(|b| char::from(b.to_ascii_uppercase())));
+                match_count = 0;
+
+                reference_position += len;
+                nm += len;
+            }
+            Kind::Skip => reference_position += len,
+            Kind::SoftClip => read_position += len,
+            Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    write!(md, "{match_count}").unwrap();
+
+    let nm = u32::try_from(nm).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(Tags { md, nm })
+}
+
+// `=` in the read sequence is a match, and `N` never matches, including itself.
+fn is_match(read_base: u8, reference_base: u8) -> bool {
+    read_base == b'='
+        || (read_base.eq_ignore_ascii_case(&reference_base)
+            && !read_base.eq_ignore_ascii_case(&b'N'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_tags() -> io::Result<()> {
+        fn t(ops: &[Op], bases: &[u8], expected_md: &str, expected_nm: u32) -> io::Result<()> {
+            let tags = calculate_tags(b"ACGTACGTacgt", 1, ops, bases)?;
+            assert_eq!(tags.md(), expected_md);
+            assert_eq!(tags.nm(), expected_nm);
+            Ok(())
+        }
+
+        t(&[Op::new(Kind::Match, 4)], b"CGTA", "4", 0)?;
+        t(&[Op::new(Kind::Match, 4)], b"AGTT", "0C2A0", 2)?;
+        t(&[Op::new(Kind::Match, 4)], b"C=NA", "2T1", 1)?;
+        t(
+            &[
+                Op::new(Kind::SoftClip, 1),
+                Op::new(Kind::Match, 2),
+                Op::new(Kind::Insertion, 1),
+                Op::new(Kind::Match, 1),
+                Op::new(Kind::Deletion, 2),
+                Op::new(Kind::Match, 2),
+                Op::new(Kind::HardClip, 3),
+            ],
+            b"NCGATAT",
+            "3^AC0G1",
+            4,
+        )?;
+        t(
+            &[
+                Op::new(Kind::Match, 1),
+                Op::new(Kind::Skip, 6),
+                Op::new(Kind::Match, 2),
+            ],
+            b"CAC",
+            "3",
+            0,
+        )?;
+        t(
+            &[Op::new(Kind::Deletion, 2), Op::new(Kind::Match, 1)],
+            b"T",
+            "0^CG1",
+            2,
+        )?;
+
+        assert!(matches!(
+            calculate_tags(b"ACGT", 1, &[Op::new(Kind::Match, 4)], b"CGTA"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(matches!(
+            calculate_tags(b"ACGT", 0, &[Op::new(Kind::Match, 4)], b"ACG"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_edit_distance_value() {
+        assert_eq!(edit_distance_value(0), Value::UInt8(0));
+        assert_eq!(edit_distance_value(256), Value::UInt16(256));
+        assert_eq!(edit_distance_value(65536), Value::UInt32(65536));
+    }
+}
@@ -0,0 +1,320 @@
+//! Alignment to FASTQ conversion.
+//!
+//! This is similar to `samtools fastq`.
+
+mod builder;
+mod writer;
+
+pub use self::{builder::Builder, writer::Writer};
+
+use std::io::{self, Write};
+
+use noodles_fastq::{self as fastq, record::Definition};
+use noodles_sam::alignment::{
+    record::data::field::{value::Array, Tag, Value},
+    Record,
+};
+
+const MISSING_NAME: &[u8] = b"*";
+const MISSING_QUALITY_SCORE: u8 = 0xff;
+const DEFAULT_QUALITY_SCORE: u8 = 1;
+const QUALITY_SCORE_OFFSET: u8 = b'!';
+
+/// An alignment to FASTQ converter.
+///
+/// This is built using a [`Builder`].
+#[derive(Clone, Debug, Default)]
+pub struct Converter {
+    data_tags: Vec<Tag>,
+    use_original_quality_scores: bool,
+}
+
+impl Converter {
+    /// Converts an alignment record to a FASTQ record.
+    ///
+    /// The sequence and quality scores of reverse complemented records are restored to their
+    /// original orientation. Missing quality scores are set to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::{data::field::Tag, Flags},
+    ///     record_buf::data::field::Value,
+    ///     RecordBuf,
+    /// };
+    /// use noodles_util::alignment::fastq;
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_name("r0")
+    ///     .set_flags(Flags::REVERSE_COMPLEMENTED)
+    ///     .set_sequence(b"AACG".to_vec().into())
+    ///     .set_quality_scores(vec![10, 20, 30, 40].into())
+    ///     .set_data(
+    ///         [(Tag::SAMPLE_BARCODE_SEQUENCE, Value::from("ACGT"))]
+    ///             .into_iter()
+    ///             .collect(),
+    ///     )
+    ///     .build();
+    ///
+    /// let converter = fastq::Builder::default()
+    ///     .set_data_tags(vec![Tag::SAMPLE_BARCODE_SEQUENCE])
+    ///     .build();
+    ///
+    /// let fastq_record = converter.convert(&record)?;
+    ///
+    /// assert_eq!(fastq_record.name(), b"r0");
+    /// assert_eq!(fastq_record.description(), b"BC:Z:ACGT");
+    /// assert_eq!(fastq_record.sequence(), b"CGTT");
+    /// assert_eq!(fastq_record.quality_scores(), b"I?5+");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn convert(&self, record: &dyn Record) -> io::Result<fastq::Record> {
+        let is_reverse_complemented = record.flags()?.is_reverse_complemented();
+
+        let name = record.name().map(|name| &**name).unwrap_or(MISSING_NAME);
+
+        let mut sequence: Vec<_> = record.sequence().iter().collect();
+
+        let mut quality_scores = match self.original_quality_scores(record)? {
+            Some(quality_scores) => quality_scores,
+            None => encode_quality_scores(record, sequence.len())?,
+        };
+
+        if quality_scores.len() != sequence.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "sequence and quality scores length mismatch",
+            ));
+        }
+
+        if is_reverse_complemented {
+            sequence.reverse();
+
+            for base in &mut sequence {
+                *base = complement_base(*base);
+            }
+
+            quality_scores.reverse();
+        }
+
+        let description = self.build_description(record)?;
+
+        Ok(fastq::Record::new(
+            Definition::new(name, description),
+            sequence,
+            quality_scores,
+        ))
+    }
+
+    fn original_quality_scores(&self, record: &dyn Record) -> io::Result<Option<Vec<u8>>> {
+        if !self.use_original_quality_scores {
+            return Ok(None);
+        }
+
+        match record
+            .data()
+            .get(&Tag::ORIGINAL_QUALITY_SCORES)
+            .transpose()?
+        {
+            Some(Value::String(s)) => Ok(Some(s.to_vec())),
+            Some(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid original quality scores (OQ) value type",
+            )),
+            None => Ok(None),
+        }
+    }
+
+    fn build_description(&self, record: &dyn Record) -> io::Result<Vec<u8>> {
+        let data = record.data();
+        let mut description = Vec::new();
+
+        for tag in &self.data_tags {
+            let Some(value) = data.get(tag).transpose()? else {
+                continue;
+            };
+
+            if !description.is_empty() {
+                description.push(b'\t');
+            }
+
+            write_field(&mut description, *tag, value)?;
+        }
+
+        Ok(description)
+    }
+}
+
+fn encode_quality_scores(record: &dyn Record, read_length: usize) -> io::Result<Vec<u8>> {
+    let quality_scores = record.quality_scores();
+
+    let scores: Vec<_> = quality_scores.iter().collect::<io::Result<_>>()?;
+
+    if scores.is_empty() || scores.contains(&MISSING_QUALITY_SCORE) {
+        return Ok(vec![
+            DEFAULT_QUALITY_SCORE + QUALITY_SCORE_OFFSET;
+            read_length
+        ]);
+    }
+
+    scores
+        .into_iter()
+        .map(|score| {
+            score
+                .checked_add(QUALITY_SCORE_OFFSET)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid quality score"))
+        })
+        .collect()
+}
+
+fn complement_base(base: u8) -> u8 {
+    match base {
+        b'A' => b'T',
+        b'C' => b'G',
+        b'G' => b'C',
+        b'T' => b'A',
+        b'U' => b'A',
+        b'M' => b'K',
+        b'K' => b'M',
+        b'R' => b'Y',
+        b'Y' => b'R',
+        b'B' => b'V',
+        b'D' => b'H',
+        b'H' => b'D',
+        b'V' => b'B',
+        b'a' => b't',
+        b'c' => b'g',
+        b'g' => b'c',
+        b't' => b'a',
+        b => b,
+    }
+}
+
+fn write_field(dst: &mut Vec<u8>, tag: Tag, value: Value<'_>) -> io::Result<()> {
+    dst.extend_from_slice(tag.as_ref());
+
+    match value {
+        Value::Character(c) => {
+            dst.extend_from_slice(b":A:");
+            dst.push(c);
+        }
+        Value::Int8(n) => write!(dst, ":i:{n}")?,
+        Value::UInt8(n) => write!(dst, ":i:{n}")?,
+        Value::Int16(n) => write!(dst, ":i:{n}")?,
+        Value::UInt16(n) => write!(dst, ":i:{n}")?,
+        Value::Int32(n) => write!(dst, ":i:{n}")?,
+        Value::UInt32(n) => write!(dst, ":i:{n}")?,
+        Value::Float(n) => write!(dst, ":f:{n}")?,
+        Value::String(s) => {
+            dst.extend_from_slice(b":Z:");
+            dst.extend_from_slice(s);
+        }
+        Value::Hex(s) => {
+            dst.extend_from_slice(b":H:");
+            dst.extend_from_slice(s);
+        }
+        Value::Array(array) => {
+            dst.extend_from_slice(b":B:");
+
+            match array {
+                Array::Int8(values) => write_array_values(dst, 'c', values.iter())?,
+                Array::UInt8(values) => write_array_values(dst, 'C', values.iter())?,
+                Array::Int16(values) => write_array_values(dst, 's', values.iter())?,
+                Array::UInt16(values) => write_array_values(dst, 'S', values.iter())?,
+                Array::Int32(values) => write_array_values(dst, 'i', values.iter())?,
+                Array::UInt32(values) => write_array_values(dst, 'I', values.iter())?,
+                Array::Float(values) => write_array_values(dst, 'f', values.iter())?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn write_array_values<I, N>(dst: &mut Vec<u8>, subtype: char, values: I) -> io::Result<()>
+where
+    I: Iterator<Item = io::Result<N>>,
+    N: std::fmt::Display,
+{
+    write!(dst, "{subtype}")?;
+
+    for result in values {
+        let n = result?;
+        write!(dst, ",{n}")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{
+        record::Flags,
+        record_buf::{self, data::field::value::Array as ArrayBuf},
+        RecordBuf,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_convert() -> io::Result<()> {
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::REVERSE_COMPLEMENTED)
+            .set_sequence(b"ACGN".to_vec().into())
+            .set_quality_scores(vec![10, 20, 30, 40].into())
+            .set_data(
+                [
+                    (
+                        Tag::ORIGINAL_QUALITY_SCORES,
+                        record_buf::data::field::Value::from("ABCD"),
+                    ),
+                    (
+                        Tag::UMI_SEQUENCE,
+                        record_buf::data::field::Value::from("TTT"),
+                    ),
+                    (
+                        Tag::new(b'x', b'a'),
+                        record_buf::data::field::Value::Array(ArrayBuf::UInt8(vec![1, 2])),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let converter = Builder::default()
+            .set_data_tags(vec![
+                Tag::SAMPLE_BARCODE_SEQUENCE,
+                Tag::UMI_SEQUENCE,
+                Tag::new(b'x', b'a'),
+            ])
+            .set_use_original_quality_scores(true)
+            .build();
+
+        let actual = converter.convert(&record)?;
+        let expected = fastq::Record::new(
+            Definition::new("r0", "RX:Z:TTT\txa:B:C,1,2"),
+            "NCGT",
+            "DCBA",
+        );
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_with_missing_quality_scores() -> io::Result<()> {
+        let record = RecordBuf::builder()
+            .set_flags(Flags::UNMAPPED)
+            .set_sequence(b"ACG".to_vec().into())
+            .build();
+
+        let actual = Converter::default().convert(&record)?;
+        let expected = fastq::Record::new(Definition::new("*", ""), "ACG", "\"\"\"");
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+//! Alignment to FASTQ converter builder.
+
+use noodles_sam::alignment::record::data::field::Tag;
+
+use super::Converter;
+
+/// An alignment to FASTQ converter builder.
+#[derive(Clone, Debug, Default)]
+pub struct Builder {
+    data_tags: Vec<Tag>,
+    use_original_quality_scores: bool,
+}
+
+impl Builder {
+    /// Sets the tags of data fields to copy to the FASTQ record description.
+    ///
+    /// Fields are written in the given order, in the SAM format (`TAG:TYPE:VALUE`) and separated
+    /// by tabs. Missing fields are skipped. By default, no data fields are copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::data::field::Tag;
+    /// use noodles_util::alignment::fastq;
+    ///
+    /// let builder = fastq::Builder::default()
+    ///     .set_data_tags(vec![Tag::SAMPLE_BARCODE_SEQUENCE, Tag::UMI_SEQUENCE]);
+    /// ```
+    pub fn set_data_tags(mut self, data_tags: Vec<Tag>) -> Self {
+        self.data_tags = data_tags;
+        self
+    }
+
+    /// Sets whether to use the original quality scores (`OQ`).
+    ///
+    /// When enabled, the quality scores of records with original quality scores are replaced by
+    /// them. This is disabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::fastq;
+    /// let builder = fastq::Builder::default().set_use_original_quality_scores(true);
+    /// ```
+    pub fn set_use_original_quality_scores(mut self, use_original_quality_scores: bool) -> Self {
+        self.use_original_quality_scores = use_original_quality_scores;
+        self
+    }
+
+    /// Builds an alignment to FASTQ converter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::fastq;
+    /// let converter = fastq::Builder::default().build();
+    /// ```
+    pub fn build(self) -> Converter {
+        Converter {
+            data_tags: self.data_tags,
+            use_original_quality_scores: self.use_original_quality_scores,
+        }
+    }
+}
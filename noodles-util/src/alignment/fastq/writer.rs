@@ -0,0 +1,115 @@
+//! Alignment to FASTQ writer.
+
+use std::io::{self, Write};
+
+use noodles_fastq as fastq;
+
+use super::Converter;
+use crate::alignment::iter::Template;
+
+/// An alignment to FASTQ writer.
+///
+/// This writes the primary records of templates to separate FASTQ writers: first segments of
+/// complete pairs, last segments of complete pairs, and singletons, i.e., unsegmented records and
+/// segments without a mate in the template.
+pub struct Writer<W> {
+    converter: Converter,
+    first_segments: fastq::io::Writer<W>,
+    last_segments: fastq::io::Writer<W>,
+    singletons: fastq::io::Writer<W>,
+}
+
+impl<W> Writer<W>
+where
+    W: Write,
+{
+    /// Creates an alignment to FASTQ writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_util::alignment::fastq;
+    ///
+    /// let writer = fastq::Writer::new(
+    ///     fastq::Builder::default().build(),
+    ///     io::sink(),
+    ///     io::sink(),
+    ///     io::sink(),
+    /// );
+    /// ```
+    pub fn new(converter: Converter, first_segments: W, last_segments: W, singletons: W) -> Self {
+        Self {
+            converter,
+            first_segments: fastq::io::Writer::new(first_segments),
+            last_segments: fastq::io::Writer::new(last_segments),
+            singletons: fastq::io::Writer::new(singletons),
+        }
+    }
+
+    /// Returns the underlying writers of first segments, last segments, and singletons.
+    pub fn into_inner(self) -> (W, W, W) {
+        (
+            self.first_segments.into_inner(),
+            self.last_segments.into_inner(),
+            self.singletons.into_inner(),
+        )
+    }
+
+    /// Writes the primary records of a template.
+    ///
+    /// Secondary and supplementary records are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_sam::alignment::{record::Flags, Record, RecordBuf};
+    /// use noodles_util::alignment::{fastq, iter::Templates};
+    ///
+    /// let build_record = |flags| -> io::Result<Box<dyn Record>> {
+    ///     Ok(Box::new(RecordBuf::builder().set_name("r0").set_flags(flags).build()))
+    /// };
+    ///
+    /// let records = vec![
+    ///     build_record(Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+    ///     build_record(Flags::SEGMENTED | Flags::LAST_SEGMENT),
+    /// ];
+    ///
+    /// let mut writer = fastq::Writer::new(
+    ///     fastq::Builder::default().build(),
+    ///     Vec::new(),
+    ///     Vec::new(),
+    ///     Vec::new(),
+    /// );
+    ///
+    /// for result in Templates::new(records.into_iter()) {
+    ///     let template = result?;
+    ///     writer.write_template(&template)?;
+    /// }
+    ///
+    /// let (first_segments, last_segments, singletons) = writer.into_inner();
+    /// assert_eq!(first_segments, b"@r0\n\n+\n\n");
+    /// assert_eq!(last_segments, b"@r0\n\n+\n\n");
+    /// assert!(singletons.is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_template(&mut self, template: &Template) -> io::Result<()> {
+        match (template.first_segment(), template.last_segment()) {
+            (Some(first_segment), Some(last_segment)) => {
+                let record = self.converter.convert(first_segment)?;
+                self.first_segments.write_record(&record)?;
+
+                let record = self.converter.convert(last_segment)?;
+                self.last_segments.write_record(&record)?;
+            }
+            (Some(segment), None) | (None, Some(segment)) => {
+                let record = self.converter.convert(segment)?;
+                self.singletons.write_record(&record)?;
+            }
+            (None, None) => {}
+        }
+
+        Ok(())
+    }
+}
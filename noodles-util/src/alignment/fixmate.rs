@@ -0,0 +1,403 @@
+//! Alignment mate information fixing.
+//!
+//! This is similar to `samtools fixmate`.
+
+use std::{fmt::Write, io};
+
+use noodles_core::Position;
+use noodles_sam::alignment::{
+    record::{cigar::op::Kind, data::field::Tag, Flags, MappingQuality},
+    record_buf::{data::field::Value, Cigar},
+    RecordBuf,
+};
+
+const MISSING_MAPPING_QUALITY: u8 = 255;
+
+/// Fixes the mate information of the records of a template.
+///
+/// The given records must all have the same name, e.g., a group from
+/// [`super::iter::Templates::new_unchecked`].
+///
+/// For the primary records of the first and last segments, this recomputes:
+///
+///   * the mate reference sequence ID, mate alignment start, and mate reverse complemented and
+///     mate unmapped flags from the mate;
+///   * the template length, which is nonzero only if both segments are mapped to the same
+///     reference sequence, and positive for the leftmost segment; and
+///   * the mate CIGAR (`MC`) and mate mapping quality (`MQ`) data fields, which are removed if
+///     the mate is unmapped.
+///
+/// An unmapped segment with a mapped mate is placed at the position of its mate, and the
+/// properly segmented flag is cleared unless both segments are mapped to the same reference
+/// sequence. Secondary and supplementary records are updated with the mate information of the
+/// primary record of the other segment, except for the template length.
+///
+/// Templates without both primary segments are left unchanged. An error is returned if there is
+/// more than one primary record of a segment.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::{
+///         cigar::{op::Kind, Op},
+///         data::field::Tag,
+///         Flags,
+///     },
+///     record_buf::data::field::Value,
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::fixmate;
+///
+/// let build_record = |flags, alignment_start| {
+///     RecordBuf::builder()
+///         .set_flags(flags)
+///         .set_reference_sequence_id(0)
+///         .set_alignment_start(alignment_start)
+///         .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///         .build()
+/// };
+///
+/// let mut records = [
+///     build_record(Flags::SEGMENTED | Flags::FIRST_SEGMENT, Position::try_from(8)?),
+///     build_record(
+///         Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::REVERSE_COMPLEMENTED,
+///         Position::try_from(13)?,
+///     ),
+/// ];
+///
+/// fixmate::update(&mut records)?;
+///
+/// let [r1, r2] = &records;
+///
+/// assert!(r1.flags().is_mate_reverse_complemented());
+/// assert_eq!(r1.mate_reference_sequence_id(), Some(0));
+/// assert_eq!(r1.mate_alignment_start(), Some(Position::try_from(13)?));
+/// assert_eq!(r1.template_length(), 9);
+/// assert_eq!(r1.data().get(&Tag::MATE_CIGAR), Some(&Value::from("4M")));
+///
+/// assert_eq!(r2.mate_alignment_start(), Some(Position::try_from(8)?));
+/// assert_eq!(r2.template_length(), -9);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn update(records: &mut [RecordBuf]) -> io::Result<()> {
+    let Some((i, j)) = find_primary_segments(records)? else {
+        return Ok(());
+    };
+
+    place_unmapped_segment(records, i, j);
+    place_unmapped_segment(records, j, i);
+
+    let first_mate = Mate::from(&records[j]);
+    let last_mate = Mate::from(&records[i]);
+    let (first_template_length, last_template_length) =
+        calculate_template_lengths(&records[i], &records[j])?;
+
+    for (k, record) in records.iter_mut().enumerate() {
+        let flags = record.flags();
+
+        if !flags.is_segmented() {
+            continue;
+        }
+
+        if k == i {
+            first_mate.apply(record);
+            *record.template_length_mut() = first_template_length;
+        } else if k == j {
+            last_mate.apply(record);
+            *record.template_length_mut() = last_template_length;
+        } else if flags.is_first_segment() && !flags.is_last_segment() {
+            first_mate.apply(record);
+        } else if flags.is_last_segment() && !flags.is_first_segment() {
+            last_mate.apply(record);
+        }
+    }
+
+    Ok(())
+}
+
+fn find_primary_segments(records: &[RecordBuf]) -> io::Result<Option<(usize, usize)>> {
+    let mut first_segment = None;
+    let mut last_segment = None;
+
+    for (i, record) in records.iter().enumerate() {
+        let flags = record.flags();
+
+        if !flags.is_segmented() || flags.is_secondary() || flags.is_supplementary() {
+            continue;
+        }
+
+        let segment = match (flags.is_first_segment(), flags.is_last_segment()) {
+            (true, false) => &mut first_segment,
+            (false, true) => &mut last_segment,
+            _ => continue,
+        };
+
+        if segment.replace(i).is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "template has duplicate primary records",
+            ));
+        }
+    }
+
+    Ok(first_segment.zip(last_segment))
+}
+
+fn place_unmapped_segment(records: &mut [RecordBuf], i: usize, j: usize) {
+    let mate_flags = records[j].flags();
+    let mate_reference_sequence_id = records[j].reference_sequence_id();
+    let mate_alignment_start = records[j].alignment_start();
+
+    let record = &mut records[i];
+
+    if !record.flags().is_unmapped() {
+        return;
+    }
+
+    if mate_flags.is_unmapped() {
+        *record.reference_sequence_id_mut() = None;
+        *record.alignment_start_mut() = None;
+    } else {
+        *record.reference_sequence_id_mut() = mate_reference_sequence_id;
+        *record.alignment_start_mut() = mate_alignment_start;
+    }
+}
+
+fn calculate_template_lengths(a: &RecordBuf, b: &RecordBuf) -> io::Result<(i32, i32)> {
+    if a.flags().is_unmapped()
+        || b.flags().is_unmapped()
+        || a.reference_sequence_id() != b.reference_sequence_id()
+    {
+        return Ok((0, 0));
+    }
+
+    let (Some(a_start), Some(a_end), Some(b_start), Some(b_end)) = (
+        a.alignment_start(),
+        a.alignment_end(),
+        b.alignment_start(),
+        b.alignment_end(),
+    ) else {
+        return Ok((0, 0));
+    };
+
+    let start = a_start.min(b_start);
+    let end = a_end.max(b_end);
+
+    let len = usize::from(end) - usize::from(start) + 1;
+    let len = i32::try_from(len).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    if a_start <= b_start {
+        Ok((len, -len))
+    } else {
+        Ok((-len, len))
+    }
+}
+
+struct Mate {
+    reference_sequence_id: Option<usize>,
+    alignment_start: Option<Position>,
+    is_unmapped: bool,
+    is_reverse_complemented: bool,
+    mapping_quality: Option<MappingQuality>,
+    cigar: Option<String>,
+}
+
+impl From<&RecordBuf> for Mate {
+    fn from(record: &RecordBuf) -> Self {
+        let flags = record.flags();
+
+        let cigar = if flags.is_unmapped() || record.cigar().as_ref().is_empty() {
+            None
+        } else {
+            Some(cigar_string(record.cigar()))
+        };
+
+        Self {
+            reference_sequence_id: record.reference_sequence_id(),
+            alignment_start: record.alignment_start(),
+            is_unmapped: flags.is_unmapped(),
+            is_reverse_complemented: flags.is_reverse_complemented(),
+            mapping_quality: record.mapping_quality(),
+            cigar,
+        }
+    }
+}
+
+impl Mate {
+    fn apply(&self, record: &mut RecordBuf) {
+        *record.mate_reference_sequence_id_mut() = self.reference_sequence_id;
+        *record.mate_alignment_start_mut() = self.alignment_start;
+
+        let is_properly_placed = !record.flags().is_unmapped()
+            && !self.is_unmapped
+            && self.reference_sequence_id == record.reference_sequence_id();
+
+        let flags = record.flags_mut();
+        flags.set(Flags::MATE_UNMAPPED, self.is_unmapped);
+        flags.set(
+            Flags::MATE_REVERSE_COMPLEMENTED,
+            self.is_reverse_complemented,
+        );
+
+        if !is_properly_placed {
+            flags.remove(Flags::PROPERLY_SEGMENTED);
+        }
+
+        let data = record.data_mut();
+
+        if self.is_unmapped {
+            data.remove(&Tag::MATE_CIGAR);
+            data.remove(&Tag::MATE_MAPPING_QUALITY);
+            return;
+        }
+
+        match &self.cigar {
+            Some(cigar) => {
+                data.insert(Tag::MATE_CIGAR, Value::from(cigar.as_str()));
+            }
+            None => {
+                data.remove(&Tag::MATE_CIGAR);
+            }
+        }
+
+        let mapping_quality = self
+            .mapping_quality
+            .map(u8::from)
+            .unwrap_or(MISSING_MAPPING_QUALITY);
+
+        data.insert(Tag::MATE_MAPPING_QUALITY, Value::from(mapping_quality));
+    }
+}
+
+fn cigar_string(cigar: &Cigar) -> String {
+    let mut s = String::new();
+
+    for op in cigar.as_ref() {
+        let c = match op.kind() {
+            Kind::Match => 'M',
+            Kind::Insertion => 'I',
+            Kind::Deletion => 'D',
+            Kind::Skip => 'N',
+            Kind::SoftClip => 'S',
+            Kind::HardClip => 'H',
+            Kind::Pad => 'P',
+            Kind::SequenceMatch => '=',
+            Kind::SequenceMismatch => 'X',
+        };
+
+        // SAFETY: Writing to a `String` is infallible.
+        write!(s, "{}{c}", op.len()).unwrap();
+    }
+
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::record::cigar::Op;
+
+    use super::*;
+
+    fn build_record(flags: Flags, alignment_start: usize) -> RecordBuf {
+        RecordBuf::builder()
+            .set_flags(flags)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::new(alignment_start).unwrap())
+            .set_mapping_quality(MappingQuality::new(30).unwrap())
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .build()
+    }
+
+    #[test]
+    fn test_update() -> io::Result<()> {
+        let mut records = [
+            build_record(Flags::SEGMENTED | Flags::FIRST_SEGMENT, 21),
+            build_record(
+                Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::REVERSE_COMPLEMENTED,
+                5,
+            ),
+            build_record(
+                Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::SUPPLEMENTARY,
+                34,
+            ),
+        ];
+
+        *records[0].template_length_mut() = 100;
+
+        update(&mut records)?;
+
+        let [r1, r2, r1_supplementary] = &records;
+
+        assert!(r1.flags().is_mate_reverse_complemented());
+        assert!(!r1.flags().is_mate_unmapped());
+        assert_eq!(r1.mate_alignment_start(), Position::new(5));
+        assert_eq!(r1.template_length(), -20);
+        assert_eq!(
+            r1.data().get(&Tag::MATE_MAPPING_QUALITY),
+            Some(&Value::from(30u8))
+        );
+
+        assert!(!r2.flags().is_mate_reverse_complemented());
+        assert_eq!(r2.mate_alignment_start(), Position::new(21));
+        assert_eq!(r2.template_length(), 20);
+        assert_eq!(r2.data().get(&Tag::MATE_CIGAR), Some(&Value::from("4M")));
+
+        assert_eq!(r1_supplementary.mate_alignment_start(), Position::new(5));
+        assert_eq!(r1_supplementary.template_length(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_with_unmapped_mate() -> io::Result<()> {
+        let mut records = [
+            build_record(
+                Flags::SEGMENTED | Flags::PROPERLY_SEGMENTED | Flags::FIRST_SEGMENT,
+                8,
+            ),
+            RecordBuf::builder()
+                .set_flags(Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::UNMAPPED)
+                .build(),
+        ];
+
+        records[0]
+            .data_mut()
+            .insert(Tag::MATE_CIGAR, Value::from("4M"));
+
+        update(&mut records)?;
+
+        let [r1, r2] = &records;
+
+        assert!(r1.flags().is_mate_unmapped());
+        assert!(!r1.flags().is_properly_segmented());
+        assert_eq!(r1.mate_reference_sequence_id(), Some(0));
+        assert_eq!(r1.mate_alignment_start(), Position::new(8));
+        assert_eq!(r1.template_length(), 0);
+        assert!(r1.data().get(&Tag::MATE_CIGAR).is_none());
+
+        assert_eq!(r2.reference_sequence_id(), Some(0));
+        assert_eq!(r2.alignment_start(), Position::new(8));
+        assert_eq!(r2.mate_alignment_start(), Position::new(8));
+        assert_eq!(
+            r2.data().get(&Tag::MATE_MAPPING_QUALITY),
+            Some(&Value::from(30u8))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_with_duplicate_primary_records() {
+        let mut records = [
+            build_record(Flags::SEGMENTED | Flags::FIRST_SEGMENT, 8),
+            build_record(Flags::SEGMENTED | Flags::FIRST_SEGMENT, 13),
+        ];
+
+        assert!(matches!(
+            update(&mut records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,417 @@
+//! Alignment flag statistics.
+//!
+//! This is similar to `samtools flagstat`.
+
+use std::{fmt, io};
+
+use noodles_sam::{self as sam, alignment::Record};
+
+const MIN_HQ_MAPPING_QUALITY: u8 = 5;
+
+/// Alignment flag counts.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Counts {
+    read: u64,
+    primary: u64,
+    secondary: u64,
+    supplementary: u64,
+    duplicate: u64,
+    primary_duplicate: u64,
+    mapped: u64,
+    primary_mapped: u64,
+    paired: u64,
+    read_1: u64,
+    read_2: u64,
+    proper_pair: u64,
+    mate_mapped: u64,
+    singleton: u64,
+    mate_reference_sequence_id_mismatch: u64,
+    mate_reference_sequence_id_mismatch_hq: u64,
+}
+
+impl Counts {
+    /// Returns the number of records.
+    pub fn read(&self) -> u64 {
+        self.read
+    }
+
+    /// Returns the number of primary records.
+    pub fn primary(&self) -> u64 {
+        self.primary
+    }
+
+    /// Returns the number of secondary records.
+    pub fn secondary(&self) -> u64 {
+        self.secondary
+    }
+
+    /// Returns the number of supplementary records.
+    pub fn supplementary(&self) -> u64 {
+        self.supplementary
+    }
+
+    /// Returns the number of duplicate records.
+    pub fn duplicate(&self) -> u64 {
+        self.duplicate
+    }
+
+    /// Returns the number of primary duplicate records.
+    pub fn primary_duplicate(&self) -> u64 {
+        self.primary_duplicate
+    }
+
+    /// Returns the number of mapped records.
+    pub fn mapped(&self) -> u64 {
+        self.mapped
+    }
+
+    /// Returns the number of primary mapped records.
+    pub fn primary_mapped(&self) -> u64 {
+        self.primary_mapped
+    }
+
+    /// Returns the number of primary segmented records.
+    pub fn paired(&self) -> u64 {
+        self.paired
+    }
+
+    /// Returns the number of primary first segments.
+    pub fn read_1(&self) -> u64 {
+        self.read_1
+    }
+
+    /// Returns the number of primary last segments.
+    pub fn read_2(&self) -> u64 {
+        self.read_2
+    }
+
+    /// Returns the number of primary mapped, properly segmented records.
+    pub fn proper_pair(&self) -> u64 {
+        self.proper_pair
+    }
+
+    /// Returns the number of primary segmented records where both it and its mate are mapped.
+    pub fn mate_mapped(&self) -> u64 {
+        self.mate_mapped
+    }
+
+    /// Returns the number of primary mapped segmented records with an unmapped mate.
+    pub fn singleton(&self) -> u64 {
+        self.singleton
+    }
+
+    /// Returns the number of primary segmented records with a mate mapped to a different
+    /// reference sequence.
+    pub fn mate_reference_sequence_id_mismatch(&self) -> u64 {
+        self.mate_reference_sequence_id_mismatch
+    }
+
+    /// Returns the number of primary segmented records with a mapping quality of at least 5 and a
+    /// mate mapped to a different reference sequence.
+    pub fn mate_reference_sequence_id_mismatch_hq(&self) -> u64 {
+        self.mate_reference_sequence_id_mismatch_hq
+    }
+
+    fn add(&mut self, header: &sam::Header, record: &dyn Record) -> io::Result<()> {
+        let flags = record.flags()?;
+
+        self.read += 1;
+
+        if !flags.is_unmapped() {
+            self.mapped += 1;
+        }
+
+        if flags.is_duplicate() {
+            self.duplicate += 1;
+        }
+
+        if flags.is_secondary() {
+            self.secondary += 1;
+            return Ok(());
+        } else if flags.is_supplementary() {
+            self.supplementary += 1;
+            return Ok(());
+        }
+
+        self.primary += 1;
+
+        if !flags.is_unmapped() {
+            self.primary_mapped += 1;
+        }
+
+        if flags.is_duplicate() {
+            self.primary_duplicate += 1;
+        }
+
+        if !flags.is_segmented() {
+            return Ok(());
+        }
+
+        self.paired += 1;
+
+        if flags.is_first_segment() {
+            self.read_1 += 1;
+        }
+
+        if flags.is_last_segment() {
+            self.read_2 += 1;
+        }
+
+        if flags.is_unmapped() {
+            return Ok(());
+        }
+
+        if flags.is_properly_segmented() {
+            self.proper_pair += 1;
+        }
+
+        if flags.is_mate_unmapped() {
+            self.singleton += 1;
+            return Ok(());
+        }
+
+        self.mate_mapped += 1;
+
+        let reference_sequence_id = record.reference_sequence_id(header).transpose()?;
+        let mate_reference_sequence_id = record.mate_reference_sequence_id(header).transpose()?;
+
+        if mate_reference_sequence_id != reference_sequence_id {
+            self.mate_reference_sequence_id_mismatch += 1;
+
+            let mapping_quality = record.mapping_quality().transpose()?;
+
+            // A missing mapping quality (255) is counted as high quality.
+            if mapping_quality.map_or(true, |mapq| u8::from(mapq) >= MIN_HQ_MAPPING_QUALITY) {
+                self.mate_reference_sequence_id_mismatch_hq += 1;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Alignment flag statistics.
+///
+/// Records are counted separately by whether they pass quality controls.
+///
+/// The [`fmt::Display`] implementation formats the statistics the same as `samtools flagstat`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{record::Flags, RecordBuf},
+/// };
+/// use noodles_util::alignment::flagstat::FlagStat;
+///
+/// let header = sam::Header::default();
+/// let mut flagstat = FlagStat::default();
+///
+/// flagstat.add(&header, &RecordBuf::builder().set_flags(Flags::UNMAPPED).build())?;
+/// flagstat.add(&header, &RecordBuf::builder().set_flags(Flags::QC_FAIL).build())?;
+///
+/// assert_eq!(flagstat.qc_pass().read(), 1);
+/// assert_eq!(flagstat.qc_pass().mapped(), 0);
+/// assert_eq!(flagstat.qc_fail().read(), 1);
+/// assert_eq!(flagstat.qc_fail().mapped(), 1);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FlagStat {
+    qc_pass: Counts,
+    qc_fail: Counts,
+}
+
+impl FlagStat {
+    /// Adds a record.
+    pub fn add(&mut self, header: &sam::Header, record: &dyn Record) -> io::Result<()> {
+        if record.flags()?.is_qc_fail() {
+            self.qc_fail.add(header, record)
+        } else {
+            self.qc_pass.add(header, record)
+        }
+    }
+
+    /// Returns the counts of records that pass quality controls.
+    pub fn qc_pass(&self) -> &Counts {
+        &self.qc_pass
+    }
+
+    /// Returns the counts of records that fail quality controls.
+    pub fn qc_fail(&self) -> &Counts {
+        &self.qc_fail
+    }
+}
+
+impl fmt::Display for FlagStat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (a, b) = (&self.qc_pass, &self.qc_fail);
+
+        writeln!(
+            f,
+            "{} + {} in total (QC-passed reads + QC-failed reads)",
+            a.read, b.read
+        )?;
+        writeln!(f, "{} + {} primary", a.primary, b.primary)?;
+        writeln!(f, "{} + {} secondary", a.secondary, b.secondary)?;
+        writeln!(f, "{} + {} supplementary", a.supplementary, b.supplementary)?;
+        writeln!(f, "{} + {} duplicates", a.duplicate, b.duplicate)?;
+        writeln!(
+            f,
+            "{} + {} primary duplicates",
+            a.primary_duplicate, b.primary_duplicate
+        )?;
+        writeln!(
+            f,
+            "{} + {} mapped ({} : {})",
+            a.mapped,
+            b.mapped,
+            Percentage(a.mapped, a.read),
+            Percentage(b.mapped, b.read)
+        )?;
+        writeln!(
+            f,
+            "{} + {} primary mapped ({} : {})",
+            a.primary_mapped,
+            b.primary_mapped,
+            Percentage(a.primary_mapped, a.primary),
+            Percentage(b.primary_mapped, b.primary)
+        )?;
+        writeln!(f, "{} + {} paired in sequencing", a.paired, b.paired)?;
+        writeln!(f, "{} + {} read1", a.read_1, b.read_1)?;
+        writeln!(f, "{} + {} read2", a.read_2, b.read_2)?;
+        writeln!(
+            f,
+            "{} + {} properly paired ({} : {})",
+            a.proper_pair,
+            b.proper_pair,
+            Percentage(a.proper_pair, a.paired),
+            Percentage(b.proper_pair, b.paired)
+        )?;
+        writeln!(
+            f,
+            "{} + {} with itself and mate mapped",
+            a.mate_mapped, b.mate_mapped
+        )?;
+        writeln!(
+            f,
+            "{} + {} singletons ({} : {})",
+            a.singleton,
+            b.singleton,
+            Percentage(a.singleton, a.paired),
+            Percentage(b.singleton, b.paired)
+        )?;
+        writeln!(
+            f,
+            "{} + {} with mate mapped to a different chr",
+            a.mate_reference_sequence_id_mismatch, b.mate_reference_sequence_id_mismatch
+        )?;
+        writeln!(
+            f,
+            "{} + {} with mate mapped to a different chr (mapQ>={MIN_HQ_MAPPING_QUALITY})",
+            a.mate_reference_sequence_id_mismatch_hq, b.mate_reference_sequence_id_mismatch_hq,
+        )
+    }
+}
+
+struct Percentage(u64, u64);
+
+impl fmt::Display for Percentage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.1 == 0 {
+            f.write_str("N/A")
+        } else {
+            let (a, b) = (self.0 as f64, self.1 as f64);
+            write!(f, "{:.2}%", a / b * 100.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use sam::{
+        alignment::{
+            record::{Flags, MappingQuality},
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_add() -> io::Result<()> {
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .add_reference_sequence("sq1", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .build();
+
+        let build_record = |flags, mate_reference_sequence_id, mapping_quality| {
+            RecordBuf::builder()
+                .set_flags(flags)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_mapping_quality(MappingQuality::new(mapping_quality).unwrap())
+                .set_mate_reference_sequence_id(mate_reference_sequence_id)
+                .build()
+        };
+
+        let records = [
+            build_record(
+                Flags::SEGMENTED | Flags::PROPERLY_SEGMENTED | Flags::FIRST_SEGMENT,
+                0,
+                60,
+            ),
+            build_record(Flags::SEGMENTED | Flags::LAST_SEGMENT, 1, 3),
+            build_record(
+                Flags::SEGMENTED | Flags::MATE_UNMAPPED | Flags::DUPLICATE,
+                0,
+                60,
+            ),
+            build_record(Flags::SUPPLEMENTARY | Flags::DUPLICATE, 0, 60),
+            build_record(Flags::UNMAPPED | Flags::QC_FAIL, 0, 0),
+        ];
+
+        let mut flagstat = FlagStat::default();
+
+        for record in &records {
+            flagstat.add(&header, record)?;
+        }
+
+        let counts = flagstat.qc_pass();
+        assert_eq!(counts.read(), 4);
+        assert_eq!(counts.primary(), 3);
+        assert_eq!(counts.supplementary(), 1);
+        assert_eq!(counts.duplicate(), 2);
+        assert_eq!(counts.primary_duplicate(), 1);
+        assert_eq!(counts.mapped(), 4);
+        assert_eq!(counts.paired(), 3);
+        assert_eq!(counts.read_1(), 1);
+        assert_eq!(counts.read_2(), 1);
+        assert_eq!(counts.proper_pair(), 1);
+        assert_eq!(counts.mate_mapped(), 2);
+        assert_eq!(counts.singleton(), 1);
+        assert_eq!(counts.mate_reference_sequence_id_mismatch(), 1);
+        assert_eq!(counts.mate_reference_sequence_id_mismatch_hq(), 0);
+
+        let counts = flagstat.qc_fail();
+        assert_eq!(counts.read(), 1);
+        assert_eq!(counts.mapped(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt() {
+        let flagstat = FlagStat::default();
+        let s = flagstat.to_string();
+
+        assert!(s.starts_with("0 + 0 in total (QC-passed reads + QC-failed reads)\n"));
+        assert!(s.contains("0 + 0 mapped (N/A : N/A)\n"));
+        assert!(s.ends_with("0 + 0 with mate mapped to a different chr (mapQ>=5)\n"));
+    }
+}
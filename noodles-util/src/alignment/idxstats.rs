@@ -0,0 +1,278 @@
+//! Alignment index statistics.
+//!
+//! This is similar to `samtools idxstats`.
+
+use std::{fmt, io};
+
+use noodles_csi::BinningIndex;
+use noodles_sam::{self as sam, alignment::Record};
+
+/// Index statistics of a reference sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferenceSequence {
+    name: String,
+    length: usize,
+    mapped_record_count: u64,
+    unmapped_record_count: u64,
+}
+
+impl ReferenceSequence {
+    /// Returns the reference sequence name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the reference sequence length.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Returns the number of mapped records.
+    pub fn mapped_record_count(&self) -> u64 {
+        self.mapped_record_count
+    }
+
+    /// Returns the number of unmapped records placed on the reference sequence.
+    pub fn unmapped_record_count(&self) -> u64 {
+        self.unmapped_record_count
+    }
+}
+
+/// Alignment index statistics.
+///
+/// The [`fmt::Display`] implementation formats the statistics the same as `samtools idxstats`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IdxStats {
+    reference_sequences: Vec<ReferenceSequence>,
+    unplaced_unmapped_record_count: u64,
+}
+
+impl IdxStats {
+    /// Reads index statistics from the metadata of a binning index.
+    ///
+    /// This returns `None` if the index does not have any metadata. In that case, use
+    /// [`Self::scan`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::idxstats::IdxStats;
+    ///
+    /// let header = sam::Header::default();
+    /// let index = csi::Index::default();
+    /// assert!(IdxStats::from_index(&header, &index).is_none());
+    /// ```
+    pub fn from_index(header: &sam::Header, index: &dyn BinningIndex) -> Option<Self> {
+        let mut has_metadata = false;
+
+        let mut index_reference_sequences = index.reference_sequences();
+
+        let reference_sequences = header
+            .reference_sequences()
+            .iter()
+            .map(|(name, reference_sequence)| {
+                let (mapped_record_count, unmapped_record_count) = index_reference_sequences
+                    .next()
+                    .and_then(|rs| rs.metadata())
+                    .map(|metadata| {
+                        has_metadata = true;
+                        (
+                            metadata.mapped_record_count(),
+                            metadata.unmapped_record_count(),
+                        )
+                    })
+                    .unwrap_or_default();
+
+                ReferenceSequence {
+                    name: name.to_string(),
+                    length: reference_sequence.length().get(),
+                    mapped_record_count,
+                    unmapped_record_count,
+                }
+            })
+            .collect();
+
+        let unplaced_unmapped_record_count = index.unplaced_unmapped_record_count();
+
+        if !has_metadata && unplaced_unmapped_record_count.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            reference_sequences,
+            unplaced_unmapped_record_count: unplaced_unmapped_record_count.unwrap_or_default(),
+        })
+    }
+
+    /// Computes index statistics by scanning records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::idxstats::IdxStats;
+    ///
+    /// let header = sam::Header::default();
+    /// let records = [Ok(RecordBuf::default())];
+    ///
+    /// let idxstats = IdxStats::scan(&header, records)?;
+    /// assert_eq!(idxstats.unplaced_unmapped_record_count(), 1);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn scan<I, R>(header: &sam::Header, records: I) -> io::Result<Self>
+    where
+        I: IntoIterator<Item = io::Result<R>>,
+        R: Record,
+    {
+        let mut reference_sequences: Vec<_> = header
+            .reference_sequences()
+            .iter()
+            .map(|(name, reference_sequence)| ReferenceSequence {
+                name: name.to_string(),
+                length: reference_sequence.length().get(),
+                mapped_record_count: 0,
+                unmapped_record_count: 0,
+            })
+            .collect();
+
+        let mut unplaced_unmapped_record_count = 0;
+
+        for result in records {
+            let record = result?;
+
+            let Some(reference_sequence_id) = record.reference_sequence_id(header).transpose()?
+            else {
+                unplaced_unmapped_record_count += 1;
+                continue;
+            };
+
+            let reference_sequence = reference_sequences
+                .get_mut(reference_sequence_id)
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence ID")
+                })?;
+
+            if record.flags()?.is_unmapped() {
+                reference_sequence.unmapped_record_count += 1;
+            } else {
+                reference_sequence.mapped_record_count += 1;
+            }
+        }
+
+        Ok(Self {
+            reference_sequences,
+            unplaced_unmapped_record_count,
+        })
+    }
+
+    /// Returns the statistics of each reference sequence.
+    ///
+    /// These are in the same order as the reference sequences in the header.
+    pub fn reference_sequences(&self) -> &[ReferenceSequence] {
+        &self.reference_sequences
+    }
+
+    /// Returns the number of unmapped records not placed on a reference sequence.
+    pub fn unplaced_unmapped_record_count(&self) -> u64 {
+        self.unplaced_unmapped_record_count
+    }
+}
+
+impl fmt::Display for IdxStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for reference_sequence in &self.reference_sequences {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}",
+                reference_sequence.name,
+                reference_sequence.length,
+                reference_sequence.mapped_record_count,
+                reference_sequence.unmapped_record_count
+            )?;
+        }
+
+        writeln!(f, "*\t0\t0\t{}", self.unplaced_unmapped_record_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_bgzf as bgzf;
+    use noodles_core::Position;
+    use noodles_csi::{self as csi, binning_index::index::reference_sequence::Metadata};
+    use sam::{
+        alignment::{record::Flags, RecordBuf},
+        header::record::value::{map, Map},
+    };
+
+    use super::*;
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<map::ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<map::ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build())
+    }
+
+    #[test]
+    fn test_from_index() -> Result<(), Box<dyn std::error::Error>> {
+        use csi::binning_index::index::ReferenceSequence as IndexReferenceSequence;
+
+        let header = build_header()?;
+
+        let metadata = Metadata::new(
+            bgzf::VirtualPosition::default(),
+            bgzf::VirtualPosition::default(),
+            3,
+            1,
+        );
+
+        let index = csi::Index::builder()
+            .set_reference_sequences(vec![
+                IndexReferenceSequence::new(Default::default(), Default::default(), Some(metadata)),
+                IndexReferenceSequence::new(Default::default(), Default::default(), None),
+            ])
+            .set_unplaced_unmapped_record_count(2)
+            .build();
+
+        let actual = IdxStats::from_index(&header, &index).map(|idxstats| idxstats.to_string());
+        let expected = "sq0\t8\t3\t1\nsq1\t13\t0\t0\n*\t0\t0\t2\n";
+        assert_eq!(actual.as_deref(), Some(expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let records = [
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::MIN)
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::MIN)
+                .build(),
+            RecordBuf::default(),
+        ];
+
+        let idxstats = IdxStats::scan(&header, records.into_iter().map(Ok))?;
+        let expected = "sq0\t8\t0\t0\nsq1\t13\t1\t1\n*\t0\t0\t1\n";
+        assert_eq!(idxstats.to_string(), expected);
+
+        Ok(())
+    }
+}
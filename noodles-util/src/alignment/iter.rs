@@ -1,5 +1,13 @@
 //! Composable iterators for alignment records.
 
+mod aligned_pairs;
+mod mate_pairs;
 mod pileup;
+mod templates;
 
-pub use self::pileup::Pileup as Depth;
+pub use self::{
+    aligned_pairs::{AlignedPair, AlignedPairs},
+    mate_pairs::MatePairs,
+    pileup::Pileup as Depth,
+    templates::{Template, Templates},
+};
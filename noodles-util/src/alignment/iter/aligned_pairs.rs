@@ -0,0 +1,458 @@
+use std::io;
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        record::cigar::{op::Kind, Op},
+        Record,
+    },
+};
+
+/// An aligned pair of a read position and a reference sequence position.
+///
+/// Depending on the CIGAR operation kind, either position may be missing: insertions and soft
+/// clips have no reference position, and deletions and skips have no read position.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AlignedPair {
+    kind: Kind,
+    read_position: Option<Position>,
+    reference_position: Option<Position>,
+    read_base: Option<u8>,
+    quality_score: Option<u8>,
+    reference_base: Option<u8>,
+}
+
+impl AlignedPair {
+    /// Returns the CIGAR operation kind that produced this pair.
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Returns the 1-based position in the read sequence.
+    pub fn read_position(&self) -> Option<Position> {
+        self.read_position
+    }
+
+    /// Returns the 1-based position in the reference sequence.
+    pub fn reference_position(&self) -> Option<Position> {
+        self.reference_position
+    }
+
+    /// Returns the read base.
+    ///
+    /// This is `None` if there is no read position or the record has no sequence.
+    pub fn read_base(&self) -> Option<u8> {
+        self.read_base
+    }
+
+    /// Returns the read base quality score.
+    ///
+    /// This is `None` if there is no read position or the record has no quality scores.
+    pub fn quality_score(&self) -> Option<u8> {
+        self.quality_score
+    }
+
+    /// Returns the reference base.
+    ///
+    /// This is `None` if there is no reference position or no reference sequence was given.
+    pub fn reference_base(&self) -> Option<u8> {
+        self.reference_base
+    }
+}
+
+/// An iterator over the aligned pairs of an alignment record.
+///
+/// This walks the CIGAR operations of a record alongside its sequence and quality scores. One pair
+/// is emitted per read base (including soft clipped bases) and per reference base consumed by
+/// deletions and skips. Hard clips and pads consume neither and are not emitted.
+///
+/// Unmapped records have no aligned pairs.
+pub struct AlignedPairs {
+    ops: std::vec::IntoIter<Op>,
+    op: Option<(Kind, usize)>,
+    read_position: usize,
+    reference_position: usize,
+    bases: Vec<u8>,
+    quality_scores: Vec<u8>,
+    reference_sequence: Option<fasta::record::Sequence>,
+}
+
+impl AlignedPairs {
+    /// Creates an aligned pairs iterator without reference bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::alignment::{
+    ///     record::{
+    ///         cigar::{op::Kind, Op},
+    ///         Flags,
+    ///     },
+    ///     RecordBuf,
+    /// };
+    /// use noodles_util::alignment::iter::AlignedPairs;
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_flags(Flags::empty())
+    ///     .set_alignment_start(Position::try_from(8)?)
+    ///     .set_cigar([Op::new(Kind::Match, 1), Op::new(Kind::Deletion, 1)].into_iter().collect())
+    ///     .set_sequence(b"A".to_vec().into())
+    ///     .build();
+    ///
+    /// let mut pairs = AlignedPairs::new(&record)?;
+    ///
+    /// let pair = pairs.next().unwrap();
+    /// assert_eq!(pair.read_position(), Some(Position::MIN));
+    /// assert_eq!(pair.reference_position(), Position::new(8));
+    /// assert_eq!(pair.read_base(), Some(b'A'));
+    ///
+    /// let pair = pairs.next().unwrap();
+    /// assert!(pair.read_position().is_none());
+    /// assert_eq!(pair.reference_position(), Position::new(9));
+    ///
+    /// assert!(pairs.next().is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(record: &dyn Record) -> io::Result<Self> {
+        build(record, None)
+    }
+
+    /// Creates an aligned pairs iterator with reference bases from a reference sequence
+    /// repository.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::{
+    ///         record::{
+    ///             cigar::{op::Kind, Op},
+    ///             Flags,
+    ///         },
+    ///         RecordBuf,
+    ///     },
+    ///     header::record::value::{map::ReferenceSequence, Map},
+    /// };
+    /// use noodles_util::alignment::iter::AlignedPairs;
+    ///
+    /// let repository = fasta::Repository::new(vec![fasta::Record::new(
+    ///     Definition::new("sq0", None),
+    ///     Sequence::from(b"ACGT".to_vec()),
+    /// )]);
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(4.try_into()?))
+    ///     .build();
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_flags(Flags::empty())
+    ///     .set_reference_sequence_id(0)
+    ///     .set_alignment_start(Position::try_from(2)?)
+    ///     .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+    ///     .set_sequence(b"CT".to_vec().into())
+    ///     .build();
+    ///
+    /// let pairs = AlignedPairs::with_reference_sequence_repository(&header, &repository, &record)?;
+    ///
+    /// let actual: Vec<_> = pairs
+    ///     .map(|pair| (pair.read_base(), pair.reference_base()))
+    ///     .collect();
+    ///
+    /// assert_eq!(actual, [(Some(b'C'), Some(b'C')), (Some(b'T'), Some(b'G'))]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_reference_sequence_repository(
+        header: &sam::Header,
+        repository: &fasta::Repository,
+        record: &dyn Record,
+    ) -> io::Result<Self> {
+        let reference_sequence = match record.reference_sequence(header).transpose()? {
+            Some((name, _)) => {
+                let sequence = repository.get(name).transpose()?.ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("missing reference sequence: {name}"),
+                    )
+                })?;
+
+                Some(sequence)
+            }
+            None => None,
+        };
+
+        build(record, reference_sequence)
+    }
+}
+
+fn build(
+    record: &dyn Record,
+    reference_sequence: Option<fasta::record::Sequence>,
+) -> io::Result<AlignedPairs> {
+    let flags = record.flags()?;
+
+    if flags.is_unmapped() {
+        return Ok(AlignedPairs::empty());
+    }
+
+    // Hard clips and pads consume neither the read nor the reference and are dropped.
+    let ops: Vec<_> = record
+        .cigar()
+        .iter()
+        .filter(|result| {
+            result.as_ref().map_or(true, |op| {
+                op.kind().consumes_read() || op.kind().consumes_reference()
+            })
+        })
+        .collect::<io::Result<_>>()?;
+
+    if ops.is_empty() {
+        return Ok(AlignedPairs::empty());
+    }
+
+    let alignment_start = record
+        .alignment_start()
+        .transpose()?
+        .map(|position| usize::from(position) - 1)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing alignment start"))?;
+
+    let bases: Vec<_> = record.sequence().iter().collect();
+    let quality_scores: Vec<_> = record.quality_scores().iter().collect::<io::Result<_>>()?;
+
+    let read_length: usize = ops
+        .iter()
+        .filter(|op| op.kind().consumes_read())
+        .map(|op| op.len())
+        .sum();
+
+    if !bases.is_empty() && bases.len() != read_length {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "CIGAR read length does not match the sequence length",
+        ));
+    }
+
+    if !quality_scores.is_empty() && quality_scores.len() != read_length {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "CIGAR read length does not match the quality scores length",
+        ));
+    }
+
+    if let Some(sequence) = &reference_sequence {
+        let span: usize = ops
+            .iter()
+            .filter(|op| op.kind().consumes_reference())
+            .map(|op| op.len())
+            .sum();
+
+        if alignment_start + span > sequence.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "alignment extends past the reference sequence end",
+            ));
+        }
+    }
+
+    Ok(AlignedPairs {
+        ops: ops.into_iter(),
+        op: None,
+        read_position: 0,
+        reference_position: alignment_start,
+        bases,
+        quality_scores,
+        reference_sequence,
+    })
+}
+
+impl AlignedPairs {
+    fn empty() -> Self {
+        Self {
+            ops: Vec::new().into_iter(),
+            op: None,
+            read_position: 0,
+            reference_position: 0,
+            bases: Vec::new(),
+            quality_scores: Vec::new(),
+            reference_sequence: None,
+        }
+    }
+
+    fn read(&mut self) -> (Option<Position>, Option<u8>, Option<u8>) {
+        let i = self.read_position;
+        self.read_position += 1;
+
+        (
+            Position::new(i + 1),
+            self.bases.get(i).copied(),
+            self.quality_scores.get(i).copied(),
+        )
+    }
+
+    fn reference(&mut self) -> (Option<Position>, Option<u8>) {
+        let i = self.reference_position;
+        self.reference_position += 1;
+
+        let base = self
+            .reference_sequence
+            .as_ref()
+            .and_then(|sequence| sequence.as_ref().get(i).copied());
+
+        (Position::new(i + 1), base)
+    }
+}
+
+impl Iterator for AlignedPairs {
+    type Item = AlignedPair;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let kind = loop {
+            match self.op {
+                Some((kind, len)) if len > 0 => {
+                    self.op = Some((kind, len - 1));
+                    break kind;
+                }
+                _ => {
+                    let op = self.ops.next()?;
+                    self.op = Some((op.kind(), op.len()));
+                }
+            }
+        };
+
+        let (read_position, read_base, quality_score) = if kind.consumes_read() {
+            self.read()
+        } else {
+            (None, None, None)
+        };
+
+        let (reference_position, reference_base) = if kind.consumes_reference() {
+            self.reference()
+        } else {
+            (None, None)
+        };
+
+        Some(AlignedPair {
+            kind,
+            read_position,
+            reference_position,
+            read_base,
+            quality_score,
+            reference_base,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sam::alignment::{record::Flags, RecordBuf};
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = fasta::record::Sequence::from(b"ACGTACGTAC".to_vec());
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::try_from(2)?)
+            .set_cigar(
+                [
+                    Op::new(Kind::HardClip, 2),
+                    Op::new(Kind::SoftClip, 1),
+                    Op::new(Kind::Match, 1),
+                    Op::new(Kind::Insertion, 1),
+                    Op::new(Kind::Pad, 1),
+                    Op::new(Kind::Deletion, 1),
+                    Op::new(Kind::Skip, 2),
+                    Op::new(Kind::SequenceMismatch, 1),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_sequence(b"NCAG".to_vec().into())
+            .set_quality_scores(vec![10, 20, 30, 40].into())
+            .build();
+
+        let pairs = build(&record, Some(reference_sequence))?;
+        let actual: Vec<_> = pairs
+            .map(|pair| {
+                (
+                    pair.kind(),
+                    pair.read_position().map(usize::from),
+                    pair.reference_position().map(usize::from),
+                    pair.read_base(),
+                    pair.quality_score(),
+                    pair.reference_base(),
+                )
+            })
+            .collect();
+
+        let expected = [
+            (Kind::SoftClip, Some(1), None, Some(b'N'), Some(10), None),
+            (
+                Kind::Match,
+                Some(2),
+                Some(2),
+                Some(b'C'),
+                Some(20),
+                Some(b'C'),
+            ),
+            (Kind::Insertion, Some(3), None, Some(b'A'), Some(30), None),
+            (Kind::Deletion, None, Some(3), None, None, Some(b'G')),
+            (Kind::Skip, None, Some(4), None, None, Some(b'T')),
+            (Kind::Skip, None, Some(5), None, None, Some(b'A')),
+            (
+                Kind::SequenceMismatch,
+                Some(4),
+                Some(6),
+                Some(b'G'),
+                Some(40),
+                Some(b'C'),
+            ),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        let record = RecordBuf::builder().build();
+        assert_eq!(build(&record, None)?.count(), 0);
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+            .set_sequence(b"ACG".to_vec().into())
+            .build();
+
+        assert!(matches!(
+            build(&record, None),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::try_from(3)?)
+            .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+            .build();
+
+        assert_eq!(build(&record, None)?.count(), 2);
+
+        let reference_sequence = fasta::record::Sequence::from(b"ACG".to_vec());
+
+        assert!(matches!(
+            build(&record, Some(reference_sequence)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
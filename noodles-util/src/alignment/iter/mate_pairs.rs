@@ -0,0 +1,194 @@
+use std::{collections::HashMap, io};
+
+use noodles_sam::alignment::Record;
+
+use super::Template;
+
+/// An iterator over templates of primary records paired with their mates.
+///
+/// This takes records in any order, e.g., coordinate-sorted, and buffers segments until their
+/// mates are read. A template is emitted as soon as it is complete, so templates are in the order
+/// of their last segment. Secondary and supplementary records are skipped, and unsegmented and
+/// unnamed records are emitted immediately.
+///
+/// The number of buffered segments is bounded. An error is returned if the buffer is full when
+/// another segment needs to be buffered. At the end of the input, segments whose mates were never
+/// read are emitted as incomplete templates in input order.
+pub struct MatePairs<I> {
+    records: I,
+    capacity: usize,
+    buffer: HashMap<Vec<u8>, (usize, Box<dyn Record>)>,
+    buffered_record_count: usize,
+    unpaired_records: Option<std::vec::IntoIter<Box<dyn Record>>>,
+}
+
+impl<I> MatePairs<I>
+where
+    I: Iterator<Item = io::Result<Box<dyn Record>>>,
+{
+    /// Creates an iterator over templates of primary records paired with their mates.
+    ///
+    /// The capacity is the maximum number of segments waiting for their mates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_sam::alignment::{record::Flags, Record, RecordBuf};
+    /// use noodles_util::alignment::iter::MatePairs;
+    ///
+    /// let build_record = |name, flags| -> io::Result<Box<dyn Record>> {
+    ///     Ok(Box::new(RecordBuf::builder().set_name(name).set_flags(flags).build()))
+    /// };
+    ///
+    /// let records = vec![
+    ///     build_record("r0", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+    ///     build_record("r1", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+    ///     build_record("r0", Flags::SEGMENTED | Flags::LAST_SEGMENT),
+    /// ];
+    ///
+    /// let mut pairs = MatePairs::new(records.into_iter(), 1024);
+    ///
+    /// let template = pairs.next().transpose()?.expect("missing template");
+    /// assert_eq!(template.name(), Some(&b"r0"[..]));
+    /// assert!(template.last_segment().is_some());
+    ///
+    /// let template = pairs.next().transpose()?.expect("missing template");
+    /// assert_eq!(template.name(), Some(&b"r1"[..]));
+    /// assert!(template.last_segment().is_none());
+    ///
+    /// assert!(pairs.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new(records: I, capacity: usize) -> Self {
+        Self {
+            records,
+            capacity,
+            buffer: HashMap::new(),
+            buffered_record_count: 0,
+            unpaired_records: None,
+        }
+    }
+
+    fn read_template(&mut self) -> io::Result<Option<Template>> {
+        if let Some(unpaired_records) = &mut self.unpaired_records {
+            return unpaired_records
+                .next()
+                .map(|record| Template::new(vec![record], true))
+                .transpose();
+        }
+
+        loop {
+            let Some(record) = self.records.next().transpose()? else {
+                let mut unpaired_records: Vec<_> = self.buffer.drain().map(|(_, r)| r).collect();
+                unpaired_records.sort_unstable_by_key(|(i, _)| *i);
+
+                let unpaired_records: Vec<_> =
+                    unpaired_records.into_iter().map(|(_, r)| r).collect();
+                self.unpaired_records = Some(unpaired_records.into_iter());
+
+                return self.read_template();
+            };
+
+            let flags = record.flags()?;
+
+            if flags.is_secondary() || flags.is_supplementary() {
+                continue;
+            }
+
+            let name = match record.name() {
+                Some(name) if flags.is_segmented() => name.to_vec(),
+                _ => return Template::new(vec![record], true).map(Some),
+            };
+
+            if let Some((_, mate)) = self.buffer.remove(&name) {
+                return Template::new(vec![mate, record], true).map(Some);
+            }
+
+            if self.buffer.len() >= self.capacity {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "mate buffer capacity exceeded",
+                ));
+            }
+
+            self.buffer
+                .insert(name, (self.buffered_record_count, record));
+
+            self.buffered_record_count += 1;
+        }
+    }
+}
+
+impl<I> Iterator for MatePairs<I>
+where
+    I: Iterator<Item = io::Result<Box<dyn Record>>>,
+{
+    type Item = io::Result<Template>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_template().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{record::Flags, RecordBuf};
+
+    use super::*;
+
+    fn build_record(name: &str, flags: Flags) -> io::Result<Box<dyn Record>> {
+        Ok(Box::new(
+            RecordBuf::builder().set_name(name).set_flags(flags).build(),
+        ))
+    }
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let records = [
+            build_record("r0", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+            build_record("r1", Flags::SEGMENTED | Flags::LAST_SEGMENT),
+            build_record("r2", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+            build_record("r0", Flags::SEGMENTED | Flags::SUPPLEMENTARY),
+            build_record("r3", Flags::empty()),
+            build_record("r0", Flags::SEGMENTED | Flags::LAST_SEGMENT),
+        ];
+
+        let actual: Vec<_> = MatePairs::new(records.into_iter(), 8)
+            .map(|result| {
+                result.map(|template| {
+                    (
+                        template.name().map(|name| name.to_vec()),
+                        template.records().len(),
+                    )
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (Some(b"r3".to_vec()), 1),
+            (Some(b"r0".to_vec()), 2),
+            (Some(b"r1".to_vec()), 1),
+            (Some(b"r2".to_vec()), 1),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_full_buffer() {
+        let records = [
+            build_record("r0", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+            build_record("r1", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+        ];
+
+        let mut pairs = MatePairs::new(records.into_iter(), 1);
+
+        assert!(matches!(
+            pairs.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,353 @@
+use std::io;
+
+use noodles_sam::alignment::{record::Flags, Record};
+
+/// The records of a template.
+///
+/// A template is the set of records with the same name, e.g., both segments of a read pair and
+/// any of their secondary and supplementary alignments.
+pub struct Template {
+    records: Vec<Box<dyn Record>>,
+    first_segment: Option<usize>,
+    last_segment: Option<usize>,
+}
+
+impl Template {
+    pub(super) fn new(
+        records: Vec<Box<dyn Record>>,
+        validate_mate_flags: bool,
+    ) -> io::Result<Self> {
+        let mut first_segment = None;
+        let mut last_segment = None;
+        let mut is_segmented = None;
+
+        for (i, record) in records.iter().enumerate() {
+            let flags = record.flags()?;
+
+            if *is_segmented.get_or_insert(flags.is_segmented()) != flags.is_segmented() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "template has segmented and unsegmented records",
+                ));
+            }
+
+            if flags.is_secondary() || flags.is_supplementary() {
+                continue;
+            }
+
+            let segment = if !flags.is_segmented() {
+                &mut first_segment
+            } else {
+                match (flags.is_first_segment(), flags.is_last_segment()) {
+                    (true, false) => &mut first_segment,
+                    (false, true) => &mut last_segment,
+                    _ => continue,
+                }
+            };
+
+            if segment.replace(i).is_some() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "template has duplicate primary records",
+                ));
+            }
+        }
+
+        if let (true, Some(i), Some(j)) = (validate_mate_flags, first_segment, last_segment) {
+            let a = records[i].flags()?;
+            let b = records[j].flags()?;
+
+            if !mate_flags_match(a, b) || !mate_flags_match(b, a) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "template segments have inconsistent mate flags",
+                ));
+            }
+        }
+
+        Ok(Self {
+            records,
+            first_segment,
+            last_segment,
+        })
+    }
+
+    /// Returns the template name.
+    pub fn name(&self) -> Option<&[u8]> {
+        self.records
+            .first()
+            .and_then(|record| record.name())
+            .map(|name| &**name)
+    }
+
+    /// Returns the records in input order.
+    pub fn records(&self) -> &[Box<dyn Record>] {
+        &self.records
+    }
+
+    /// Returns the primary record of the first segment.
+    ///
+    /// For an unsegmented template, this is its primary record.
+    pub fn first_segment(&self) -> Option<&dyn Record> {
+        self.first_segment.map(|i| &*self.records[i])
+    }
+
+    /// Returns the primary record of the last segment.
+    pub fn last_segment(&self) -> Option<&dyn Record> {
+        self.last_segment.map(|i| &*self.records[i])
+    }
+
+    /// Converts the template into its records.
+    pub fn into_records(self) -> Vec<Box<dyn Record>> {
+        self.records
+    }
+}
+
+fn mate_flags_match(flags: Flags, mate_flags: Flags) -> bool {
+    flags.is_mate_unmapped() == mate_flags.is_unmapped()
+        && flags.is_mate_reverse_complemented() == mate_flags.is_reverse_complemented()
+}
+
+/// An iterator over templates of name-grouped records.
+///
+/// The input must have all records with the same name adjacent, e.g., as declared by a header
+/// with a query group order (`GO:query`) or queryname sort order (`SO:queryname`). Records
+/// without a name are each their own template.
+///
+/// An error is returned if the flags of a template are inconsistent, i.e., if it mixes segmented
+/// and unsegmented records, has more than one primary record of the same segment, or its primary
+/// segments disagree with the mate flags of the other. Use [`Self::new_unchecked`] to skip the
+/// mate flag check, e.g., for input to [`crate::alignment::fixmate`].
+pub struct Templates<I> {
+    records: I,
+    next_record: Option<Box<dyn Record>>,
+    validate_mate_flags: bool,
+}
+
+impl<I> Templates<I>
+where
+    I: Iterator<Item = io::Result<Box<dyn Record>>>,
+{
+    /// Creates an iterator over templates of name-grouped records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_sam::alignment::{record::Flags, Record, RecordBuf};
+    /// use noodles_util::alignment::iter::Templates;
+    ///
+    /// let build_record = |name, flags| -> io::Result<Box<dyn Record>> {
+    ///     Ok(Box::new(RecordBuf::builder().set_name(name).set_flags(flags).build()))
+    /// };
+    ///
+    /// let records = vec![
+    ///     build_record("r0", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+    ///     build_record("r0", Flags::SEGMENTED | Flags::LAST_SEGMENT),
+    ///     build_record("r1", Flags::empty()),
+    /// ];
+    ///
+    /// let mut templates = Templates::new(records.into_iter());
+    ///
+    /// let template = templates.next().transpose()?.expect("missing template");
+    /// assert_eq!(template.name(), Some(&b"r0"[..]));
+    /// assert_eq!(template.records().len(), 2);
+    ///
+    /// let template = templates.next().transpose()?.expect("missing template");
+    /// assert_eq!(template.name(), Some(&b"r1"[..]));
+    ///
+    /// assert!(templates.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new(records: I) -> Self {
+        Self {
+            records,
+            next_record: None,
+            validate_mate_flags: true,
+        }
+    }
+
+    /// Creates an iterator over templates of name-grouped records without validating mate flags.
+    ///
+    /// This is useful when the mate information is known to be stale and is to be fixed, e.g.,
+    /// using [`crate::alignment::fixmate`]. Segment flags are still validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_sam::alignment::{record::Flags, Record, RecordBuf};
+    /// use noodles_util::alignment::iter::Templates;
+    ///
+    /// let build_record = |flags| -> io::Result<Box<dyn Record>> {
+    ///     Ok(Box::new(RecordBuf::builder().set_name("r0").set_flags(flags).build()))
+    /// };
+    ///
+    /// let records = vec![
+    ///     build_record(Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::MATE_UNMAPPED),
+    ///     build_record(Flags::SEGMENTED | Flags::LAST_SEGMENT),
+    /// ];
+    ///
+    /// let mut templates = Templates::new_unchecked(records.into_iter());
+    /// assert!(templates.next().transpose()?.is_some());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new_unchecked(records: I) -> Self {
+        Self {
+            records,
+            next_record: None,
+            validate_mate_flags: false,
+        }
+    }
+
+    fn read_template(&mut self) -> io::Result<Option<Template>> {
+        let first_record = match self.next_record.take() {
+            Some(record) => record,
+            None => match self.records.next().transpose()? {
+                Some(record) => record,
+                None => return Ok(None),
+            },
+        };
+
+        let mut records = vec![first_record];
+
+        if records[0].name().is_some() {
+            for result in &mut self.records {
+                let record = result?;
+
+                if record.name() == records[0].name() {
+                    records.push(record);
+                } else {
+                    self.next_record = Some(record);
+                    break;
+                }
+            }
+        }
+
+        Template::new(records, self.validate_mate_flags).map(Some)
+    }
+}
+
+impl<I> Iterator for Templates<I>
+where
+    I: Iterator<Item = io::Result<Box<dyn Record>>>,
+{
+    type Item = io::Result<Template>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_template().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::RecordBuf;
+
+    use super::*;
+
+    fn build_record(name: &str, flags: Flags) -> io::Result<Box<dyn Record>> {
+        Ok(Box::new(
+            RecordBuf::builder().set_name(name).set_flags(flags).build(),
+        ))
+    }
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let records = [
+            build_record("r0", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+            build_record(
+                "r0",
+                Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::SECONDARY,
+            ),
+            build_record("r0", Flags::SEGMENTED | Flags::LAST_SEGMENT),
+            build_record("r1", Flags::empty()),
+        ];
+
+        let mut templates = Templates::new(records.into_iter());
+
+        let template = templates.next().transpose()?.expect("missing template");
+        assert_eq!(template.name(), Some(&b"r0"[..]));
+        assert_eq!(template.records().len(), 3);
+        assert!(template
+            .first_segment()
+            .map(|record| record.flags())
+            .transpose()?
+            .is_some_and(|flags| !flags.is_secondary()));
+        assert!(template.last_segment().is_some());
+
+        let template = templates.next().transpose()?.expect("missing template");
+        assert_eq!(template.name(), Some(&b"r1"[..]));
+        assert!(template.first_segment().is_some());
+        assert!(template.last_segment().is_none());
+
+        assert!(templates.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_inconsistent_flags() {
+        fn next_template(records: Vec<io::Result<Box<dyn Record>>>) -> io::Result<Template> {
+            Templates::new(records.into_iter()).next().unwrap()
+        }
+
+        let records = vec![
+            build_record("r0", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+            build_record("r0", Flags::empty()),
+        ];
+        assert!(matches!(
+            next_template(records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let records = vec![
+            build_record("r0", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+            build_record("r0", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+        ];
+        assert!(matches!(
+            next_template(records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let records = vec![
+            build_record(
+                "r0",
+                Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::MATE_UNMAPPED,
+            ),
+            build_record("r0", Flags::SEGMENTED | Flags::LAST_SEGMENT),
+        ];
+        assert!(matches!(
+            next_template(records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_next_unchecked() -> io::Result<()> {
+        let records = [
+            build_record(
+                "r0",
+                Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::MATE_UNMAPPED,
+            ),
+            build_record("r0", Flags::SEGMENTED | Flags::LAST_SEGMENT),
+        ];
+
+        let mut templates = Templates::new_unchecked(records.into_iter());
+        let template = templates.next().transpose()?.expect("missing template");
+        assert!(template.first_segment().is_some());
+        assert!(template.last_segment().is_some());
+
+        let records = [
+            build_record("r0", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+            build_record("r0", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+        ];
+
+        let mut templates = Templates::new_unchecked(records.into_iter());
+        assert!(matches!(
+            templates.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,805 @@
+//! Alignment duplicate marking.
+//!
+//! This is similar to Picard's `MarkDuplicates`. Duplicates are found in two passes. First, each
+//! record is pushed to a [`Detector`], which returns the [`Duplicates`] when finished. Second, the
+//! records are read again, and those at duplicate indices are marked, e.g., by setting
+//! [`Flags::DUPLICATE`].
+//!
+//! Records are duplicates if they share a library, 5' unclipped positions, and strands. Read pairs
+//! are compared by both ends. The record (or pair) with the highest sum of base quality scores of
+//! at least 15 is kept. Unpaired reads that share an end with a paired read are always duplicates.
+//! Unmapped, secondary, and supplementary records are never marked.
+//!
+//! Like `samtools markdup`, a read pair is keyed as soon as its first segment is pushed, using the
+//! 5' unclipped position of the segment and that of its mate, which is computed from the mate
+//! reference sequence ID, mate alignment start, mate reverse complemented flag, and mate CIGAR
+//! (`MC`). Pairs are therefore found even if a mate record is missing from the input. Segments
+//! without a mate CIGAR are instead matched to their mates by name, like Picard does, and unnamed
+//! segments without a mate CIGAR are treated as unpaired reads.
+
+mod builder;
+pub mod metrics;
+
+pub use self::{builder::Builder, metrics::Metrics};
+
+use std::{collections::HashMap, io};
+
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        record::{data::field::Tag, Flags},
+        Record,
+    },
+    header::record::value::map::read_group::tag::LIBRARY,
+};
+
+use super::sort::key::{mate_cigar_ops, string_field, unclipped_5_prime_position};
+
+const UNKNOWN_LIBRARY: &str = "Unknown Library";
+const MIN_BASE_QUALITY_SCORE: u8 = 15;
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct End {
+    reference_sequence_id: usize,
+    position: i64,
+    is_reverse_complemented: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Location {
+    read_group: usize,
+    tile: i64,
+    x: i64,
+    y: i64,
+}
+
+struct Fragment {
+    library: usize,
+    end: End,
+    score: u32,
+    index: usize,
+    is_paired: bool,
+}
+
+struct Pair {
+    library: usize,
+    ends: (End, End),
+    score: u32,
+    indices: Vec<usize>,
+    location: Option<Location>,
+}
+
+// A segment keyed by both ends of its pair, using the mate CIGAR.
+struct Segment {
+    library: usize,
+    read_group: usize,
+    ends: (End, End),
+    score: u32,
+    index: usize,
+    name: Option<Vec<u8>>,
+}
+
+// Read group IDs to read group and library indices.
+type ReadGroups = HashMap<Vec<u8>, (usize, usize)>;
+
+struct PendingMate {
+    end: End,
+    score: u32,
+    index: usize,
+}
+
+/// An alignment duplicate detector.
+///
+/// Records must be pushed in the same order they are read in the second pass.
+pub struct Detector<'h> {
+    header: &'h sam::Header,
+    optical_duplicate_pixel_distance: Option<i64>,
+    read_groups: ReadGroups,
+    metrics: Vec<Metrics>,
+    unknown_library: Option<usize>,
+    record_count: usize,
+    fragments: Vec<Fragment>,
+    pairs: Vec<Pair>,
+    segments: Vec<Segment>,
+    pending_mates: HashMap<Vec<u8>, PendingMate>,
+}
+
+impl<'h> Detector<'h> {
+    /// Creates an alignment duplicate detector with the default options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::markdup::Detector;
+    ///
+    /// let header = sam::Header::default();
+    /// let detector = Detector::new(&header);
+    /// ```
+    pub fn new(header: &'h sam::Header) -> Self {
+        Builder::default().build(header)
+    }
+
+    /// Adds a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::markdup::Detector;
+    ///
+    /// let header = sam::Header::default();
+    /// let mut detector = Detector::new(&header);
+    /// detector.push(&RecordBuf::default())?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn push(&mut self, record: &dyn Record) -> io::Result<()> {
+        let index = self.record_count;
+        self.record_count += 1;
+
+        let flags = record.flags()?;
+        let (read_group, library) = self.read_group(record)?;
+        let metrics = &mut self.metrics[library];
+
+        if flags.is_unmapped() {
+            metrics.unmapped_reads += 1;
+            return Ok(());
+        } else if flags.is_secondary() || flags.is_supplementary() {
+            metrics.secondary_or_supplementary_reads += 1;
+            return Ok(());
+        }
+
+        let has_mapped_mate = flags.is_segmented() && !flags.is_mate_unmapped();
+
+        let mate_end = if has_mapped_mate {
+            mate_end(self.header, record, flags)?
+        } else {
+            None
+        };
+
+        // Without a mate CIGAR, mates are matched by name, so unnamed segments are treated as
+        // fragments.
+        let name = record.name().filter(|_| has_mapped_mate);
+        let is_paired = mate_end.is_some() || name.is_some();
+
+        if is_paired {
+            metrics.read_pairs_examined += 1;
+        } else {
+            metrics.unpaired_reads_examined += 1;
+        }
+
+        let end = record_end(self.header, record, flags)?;
+        let score = score(record)?;
+
+        self.fragments.push(Fragment {
+            library,
+            end,
+            score,
+            index,
+            is_paired,
+        });
+
+        let name = name.map(|name| name.to_vec());
+
+        if let Some(mate_end) = mate_end {
+            self.segments.push(Segment {
+                library,
+                read_group,
+                ends: sort_ends(end, mate_end),
+                score,
+                index,
+                name,
+            });
+
+            return Ok(());
+        }
+
+        let Some(name) = name else {
+            return Ok(());
+        };
+
+        if let Some(mate) = self.pending_mates.remove(&name) {
+            let location = self
+                .optical_duplicate_pixel_distance
+                .and_then(|_| parse_location(&name, read_group));
+
+            self.pairs.push(Pair {
+                library,
+                ends: sort_ends(mate.end, end),
+                score: mate.score + score,
+                indices: vec![mate.index, index],
+                location,
+            });
+        } else {
+            self.pending_mates
+                .insert(name, PendingMate { end, score, index });
+        }
+
+        Ok(())
+    }
+
+    /// Finds the duplicates of all pushed records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::markdup::Detector;
+    ///
+    /// let header = sam::Header::default();
+    /// let mut detector = Detector::new(&header);
+    /// detector.push(&RecordBuf::default())?;
+    ///
+    /// let duplicates = detector.finish();
+    /// assert!(!duplicates.is_duplicate(0));
+    /// assert_eq!(duplicates.metrics()[0].unmapped_reads(), 1);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn finish(mut self) -> Duplicates {
+        let mut is_duplicate = vec![false; self.record_count];
+
+        self.mark_fragments(&mut is_duplicate);
+        self.pair_segments();
+        self.mark_pairs(&mut is_duplicate);
+
+        for metrics in &mut self.metrics {
+            metrics.read_pairs_examined /= 2;
+        }
+
+        Duplicates {
+            is_duplicate,
+            metrics: self.metrics,
+        }
+    }
+
+    fn read_group(&mut self, record: &dyn Record) -> io::Result<(usize, usize)> {
+        if let Some(id) = string_field(record, Tag::READ_GROUP)? {
+            if let Some(ids) = self.read_groups.get(&id) {
+                return Ok(*ids);
+            }
+        }
+
+        // Records without a read group and read groups without a library share a library.
+        let library = match self.unknown_library.or_else(|| {
+            self.metrics
+                .iter()
+                .position(|metrics| metrics.library == UNKNOWN_LIBRARY)
+        }) {
+            Some(library) => library,
+            None => {
+                self.metrics
+                    .push(Metrics::new(String::from(UNKNOWN_LIBRARY)));
+                self.metrics.len() - 1
+            }
+        };
+
+        self.unknown_library = Some(library);
+
+        Ok((usize::MAX, library))
+    }
+
+    fn mark_fragments(&mut self, is_duplicate: &mut [bool]) {
+        self.fragments.sort_unstable_by(|a, b| {
+            (a.library, a.end)
+                .cmp(&(b.library, b.end))
+                .then_with(|| b.score.cmp(&a.score))
+                .then_with(|| a.index.cmp(&b.index))
+        });
+
+        for group in self
+            .fragments
+            .chunk_by(|a, b| (a.library, a.end) == (b.library, b.end))
+        {
+            let has_paired = group.iter().any(|fragment| fragment.is_paired);
+
+            // The first fragment has the highest score.
+            let skip = if has_paired { 0 } else { 1 };
+
+            for fragment in group.iter().skip(skip).filter(|f| !f.is_paired) {
+                is_duplicate[fragment.index] = true;
+                self.metrics[fragment.library].unpaired_read_duplicates += 1;
+            }
+        }
+    }
+
+    // Groups the segments keyed by the mate CIGAR into pairs, matching mates by name. A segment
+    // whose mate was not pushed or that is unnamed forms a pair by itself.
+    fn pair_segments(&mut self) {
+        let mut segments = std::mem::take(&mut self.segments);
+
+        segments.sort_unstable_by(|a, b| {
+            (a.library, a.ends, &a.name, a.index).cmp(&(b.library, b.ends, &b.name, b.index))
+        });
+
+        for group in segments.chunk_by(|a, b| {
+            (a.library, a.ends) == (b.library, b.ends) && a.name.is_some() && a.name == b.name
+        }) {
+            let segment = &group[0];
+
+            let location = self
+                .optical_duplicate_pixel_distance
+                .zip(segment.name.as_deref())
+                .and_then(|(_, name)| parse_location(name, segment.read_group));
+
+            self.pairs.push(Pair {
+                library: segment.library,
+                ends: segment.ends,
+                score: group.iter().map(|segment| segment.score).sum(),
+                indices: group.iter().map(|segment| segment.index).collect(),
+                location,
+            });
+        }
+    }
+
+    fn mark_pairs(&mut self, is_duplicate: &mut [bool]) {
+        self.pairs.sort_unstable_by(|a, b| {
+            (a.library, a.ends)
+                .cmp(&(b.library, b.ends))
+                .then_with(|| b.score.cmp(&a.score))
+                .then_with(|| a.indices.cmp(&b.indices))
+        });
+
+        for group in self
+            .pairs
+            .chunk_by(|a, b| (a.library, a.ends) == (b.library, b.ends))
+        {
+            let Some((_, duplicates)) = group.split_first() else {
+                continue;
+            };
+
+            let metrics = &mut self.metrics[group[0].library];
+
+            for pair in duplicates {
+                for &i in &pair.indices {
+                    is_duplicate[i] = true;
+                }
+
+                metrics.read_pair_duplicates += 1;
+            }
+
+            if let Some(distance) = self.optical_duplicate_pixel_distance {
+                metrics.read_pair_optical_duplicates += count_optical_duplicates(group, distance);
+            }
+        }
+    }
+}
+
+/// Duplicate records and metrics.
+#[derive(Debug)]
+pub struct Duplicates {
+    is_duplicate: Vec<bool>,
+    metrics: Vec<Metrics>,
+}
+
+impl Duplicates {
+    /// Returns whether the record at the given index is a duplicate.
+    ///
+    /// The index is the order in which the record was pushed to the detector.
+    pub fn is_duplicate(&self, i: usize) -> bool {
+        self.is_duplicate.get(i).copied().unwrap_or_default()
+    }
+
+    /// Returns the number of duplicate records.
+    pub fn len(&self) -> usize {
+        self.is_duplicate.iter().filter(|&&b| b).count()
+    }
+
+    /// Returns whether there are no duplicate records.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the metrics of each library.
+    pub fn metrics(&self) -> &[Metrics] {
+        &self.metrics
+    }
+}
+
+pub(super) fn build_read_groups(header: &sam::Header) -> (ReadGroups, Vec<Metrics>) {
+    let mut read_groups = HashMap::new();
+    let mut metrics: Vec<Metrics> = Vec::new();
+
+    for (i, (id, read_group)) in header.read_groups().iter().enumerate() {
+        let library = read_group
+            .other_fields()
+            .get(&LIBRARY)
+            .map(|lb| lb.to_string())
+            .unwrap_or_else(|| String::from(UNKNOWN_LIBRARY));
+
+        let j = match metrics.iter().position(|m| m.library == library) {
+            Some(j) => j,
+            None => {
+                metrics.push(Metrics::new(library));
+                metrics.len() - 1
+            }
+        };
+
+        read_groups.insert(id.to_vec(), (i, j));
+    }
+
+    (read_groups, metrics)
+}
+
+fn record_end(header: &sam::Header, record: &dyn Record, flags: Flags) -> io::Result<End> {
+    let (Some(reference_sequence_id), Some(alignment_start)) = (
+        record.reference_sequence_id(header).transpose()?,
+        record.alignment_start().transpose()?,
+    ) else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing reference sequence ID or alignment start",
+        ));
+    };
+
+    let ops: Vec<_> = record.cigar().iter().collect::<io::Result<_>>()?;
+    let is_reverse_complemented = flags.is_reverse_complemented();
+    let position = unclipped_5_prime_position(alignment_start, &ops, is_reverse_complemented);
+
+    Ok(End {
+        reference_sequence_id,
+        position,
+        is_reverse_complemented,
+    })
+}
+
+fn mate_end(header: &sam::Header, record: &dyn Record, flags: Flags) -> io::Result<Option<End>> {
+    let (Some(reference_sequence_id), Some(alignment_start), Some(ops)) = (
+        record.mate_reference_sequence_id(header).transpose()?,
+        record.mate_alignment_start().transpose()?,
+        mate_cigar_ops(record)?,
+    ) else {
+        return Ok(None);
+    };
+
+    let is_reverse_complemented = flags.is_mate_reverse_complemented();
+    let position = unclipped_5_prime_position(alignment_start, &ops, is_reverse_complemented);
+
+    Ok(Some(End {
+        reference_sequence_id,
+        position,
+        is_reverse_complemented,
+    }))
+}
+
+fn sort_ends(a: End, b: End) -> (End, End) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+// The sum of base quality scores of at least 15.
+fn score(record: &dyn Record) -> io::Result<u32> {
+    let mut sum = 0;
+
+    for result in record.quality_scores().iter() {
+        let score = result?;
+
+        if score >= MIN_BASE_QUALITY_SCORE {
+            sum += u32::from(score);
+        }
+    }
+
+    Ok(sum)
+}
+
+// Parses the tile and x and y coordinates from the last three colon-separated fields of an
+// Illumina read name, e.g., `<instrument>:<run>:<flowcell>:<lane>:<tile>:<x>:<y>`.
+fn parse_location(name: &[u8], read_group: usize) -> Option<Location> {
+    fn parse_i64(buf: &[u8]) -> Option<i64> {
+        std::str::from_utf8(buf).ok()?.parse().ok()
+    }
+
+    let mut fields = name.rsplit(|&b| b == b':');
+
+    let y = fields.next().and_then(parse_i64)?;
+    let x = fields.next().and_then(parse_i64)?;
+    let tile = fields.next().and_then(parse_i64)?;
+
+    Some(Location {
+        read_group,
+        tile,
+        x,
+        y,
+    })
+}
+
+// Counts the pairs that are within the pixel distance of a preceding pair on the same tile.
+fn count_optical_duplicates(pairs: &[Pair], distance: i64) -> u64 {
+    let mut n = 0;
+
+    for (i, pair) in pairs.iter().enumerate().skip(1) {
+        let Some(a) = pair.location else {
+            continue;
+        };
+
+        let is_optical_duplicate = pairs[..i].iter().filter_map(|p| p.location).any(|b| {
+            a.read_group == b.read_group
+                && a.tile == b.tile
+                && (a.x - b.x).abs() <= distance
+                && (a.y - b.y).abs() <= distance
+        });
+
+        if is_optical_duplicate {
+            n += 1;
+        }
+    }
+
+    n
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use sam::{
+        alignment::{
+            record::cigar::{op::Kind, Op},
+            record_buf::data::field::Value,
+            RecordBuf,
+        },
+        header::record::value::{
+            map::{ReadGroup, ReferenceSequence},
+            Map,
+        },
+    };
+
+    use super::*;
+
+    fn build_record(
+        name: &str,
+        flags: Flags,
+        alignment_start: usize,
+        ops: &[Op],
+        quality_score: u8,
+    ) -> RecordBuf {
+        let read_length = ops
+            .iter()
+            .filter(|op| op.kind().consumes_read())
+            .map(|op| op.len())
+            .sum();
+
+        RecordBuf::builder()
+            .set_name(name)
+            .set_flags(flags)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::new(alignment_start).unwrap())
+            .set_cigar(ops.iter().copied().collect())
+            .set_quality_scores(vec![quality_score; read_length].into())
+            .build()
+    }
+
+    #[test]
+    fn test_finish() -> io::Result<()> {
+        const READ_1: Flags = Flags::SEGMENTED.union(Flags::FIRST_SEGMENT);
+        const READ_2: Flags = Flags::SEGMENTED
+            .union(Flags::LAST_SEGMENT)
+            .union(Flags::REVERSE_COMPLEMENTED);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .build();
+
+        let m4 = [Op::new(Kind::Match, 4)];
+        let s2m2 = [Op::new(Kind::SoftClip, 2), Op::new(Kind::Match, 2)];
+
+        let records = [
+            build_record("a:1:100:100", READ_1, 1, &m4, 20),
+            build_record("b:1:150:150", READ_1, 1, &m4, 30),
+            build_record("c:2:100:100", READ_1, 3, &s2m2, 40),
+            build_record("f0", Flags::empty(), 1, &m4, 40),
+            build_record("a:1:100:100", READ_2, 9, &m4, 20),
+            build_record("b:1:150:150", READ_2, 9, &m4, 30),
+            build_record("c:2:100:100", READ_2, 9, &m4, 20),
+            build_record("f1", Flags::empty(), 20, &m4, 10),
+            build_record("f2", Flags::empty(), 20, &m4, 20),
+            build_record("u0", Flags::UNMAPPED, 20, &[], 0),
+        ];
+
+        let mut detector = Detector::new(&header);
+
+        for record in &records {
+            detector.push(record)?;
+        }
+
+        let duplicates = detector.finish();
+
+        let actual: Vec<_> = (0..records.len())
+            .map(|i| duplicates.is_duplicate(i))
+            .collect();
+
+        let expected = [
+            true, false, true, true, true, false, true, true, false, false,
+        ];
+
+        assert_eq!(actual, expected);
+        assert_eq!(duplicates.len(), 6);
+
+        let metrics = &duplicates.metrics()[0];
+        assert_eq!(metrics.library(), UNKNOWN_LIBRARY);
+        assert_eq!(metrics.unpaired_reads_examined(), 3);
+        assert_eq!(metrics.read_pairs_examined(), 3);
+        assert_eq!(metrics.unmapped_reads(), 1);
+        assert_eq!(metrics.unpaired_read_duplicates(), 2);
+        assert_eq!(metrics.read_pair_duplicates(), 2);
+        assert_eq!(metrics.read_pair_optical_duplicates(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_mate_cigar() -> io::Result<()> {
+        const READ_1: Flags = Flags::SEGMENTED
+            .union(Flags::FIRST_SEGMENT)
+            .union(Flags::MATE_REVERSE_COMPLEMENTED);
+        const READ_2: Flags = Flags::SEGMENTED
+            .union(Flags::LAST_SEGMENT)
+            .union(Flags::REVERSE_COMPLEMENTED);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .build();
+
+        let m4 = [Op::new(Kind::Match, 4)];
+
+        let build_segment = |name, flags, alignment_start, quality_score, mate: (usize, &str)| {
+            let mut record = build_record(name, flags, alignment_start, &m4, quality_score);
+            *record.mate_reference_sequence_id_mut() = Some(0);
+            *record.mate_alignment_start_mut() = Position::new(mate.0);
+            record
+                .data_mut()
+                .insert(Tag::MATE_CIGAR, Value::from(mate.1));
+            record
+        };
+
+        let records = [
+            build_segment("p0", READ_1, 1, 20, (9, "4M")),
+            build_segment("p1", READ_1, 1, 30, (9, "4M")),
+            // The mate of p2 is missing.
+            build_segment("p2", READ_1, 1, 40, (9, "4M")),
+            // The 5' end of the mate of p3 includes a soft clip.
+            build_segment("p3", READ_1, 1, 10, (9, "2M2S")),
+            build_segment("p0", READ_2, 9, 20, (1, "4M")),
+            build_segment("p1", READ_2, 9, 30, (1, "4M")),
+        ];
+
+        let mut detector = Detector::new(&header);
+
+        for record in &records {
+            detector.push(record)?;
+        }
+
+        let duplicates = detector.finish();
+
+        let actual: Vec<_> = (0..records.len())
+            .map(|i| duplicates.is_duplicate(i))
+            .collect();
+        assert_eq!(actual, [true, false, true, true, true, false]);
+
+        let metrics = &duplicates.metrics()[0];
+        assert_eq!(metrics.unpaired_reads_examined(), 0);
+        assert_eq!(metrics.read_pairs_examined(), 3);
+        assert_eq!(metrics.read_pair_duplicates(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_unknown_library() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .add_read_group("rg0", Map::<ReadGroup>::default())
+            .add_read_group(
+                "rg1",
+                Map::<ReadGroup>::builder().insert(LIBRARY, "lb0").build()?,
+            )
+            .build();
+
+        let m4 = [Op::new(Kind::Match, 4)];
+
+        let build_record_with_read_group = |name, quality_score, read_group: Option<&str>| {
+            let mut record = build_record(name, Flags::empty(), 1, &m4, quality_score);
+
+            if let Some(id) = read_group {
+                record.data_mut().insert(Tag::READ_GROUP, Value::from(id));
+            }
+
+            record
+        };
+
+        let records = [
+            build_record_with_read_group("f0", 20, Some("rg0")),
+            build_record_with_read_group("f1", 30, None),
+            build_record_with_read_group("f2", 10, Some("rg2")),
+            build_record_with_read_group("f3", 10, Some("rg1")),
+        ];
+
+        let mut detector = Detector::new(&header);
+
+        for record in &records {
+            detector.push(record)?;
+        }
+
+        let duplicates = detector.finish();
+
+        let actual: Vec<_> = (0..records.len())
+            .map(|i| duplicates.is_duplicate(i))
+            .collect();
+        assert_eq!(actual, [true, false, true, false]);
+
+        let metrics = duplicates.metrics();
+        assert_eq!(metrics.len(), 2);
+
+        assert_eq!(metrics[0].library(), UNKNOWN_LIBRARY);
+        assert_eq!(metrics[0].unpaired_reads_examined(), 3);
+        assert_eq!(metrics[0].unpaired_read_duplicates(), 2);
+
+        assert_eq!(metrics[1].library(), "lb0");
+        assert_eq!(metrics[1].unpaired_reads_examined(), 1);
+        assert_eq!(metrics[1].unpaired_read_duplicates(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_unnamed_segments() -> io::Result<()> {
+        const READ_1: Flags = Flags::SEGMENTED.union(Flags::FIRST_SEGMENT);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .build();
+
+        let m4 = [Op::new(Kind::Match, 4)];
+
+        let build_unnamed_record = |alignment_start, quality_score| {
+            let mut record = build_record("", READ_1, alignment_start, &m4, quality_score);
+            *record.name_mut() = None;
+            record
+        };
+
+        let records = [
+            build_unnamed_record(1, 20),
+            build_unnamed_record(9, 20),
+            build_unnamed_record(1, 30),
+        ];
+
+        let mut detector = Detector::new(&header);
+
+        for record in &records {
+            detector.push(record)?;
+        }
+
+        let duplicates = detector.finish();
+
+        let actual: Vec<_> = (0..records.len())
+            .map(|i| duplicates.is_duplicate(i))
+            .collect();
+        assert_eq!(actual, [true, false, false]);
+
+        let metrics = &duplicates.metrics()[0];
+        assert_eq!(metrics.unpaired_reads_examined(), 3);
+        assert_eq!(metrics.read_pairs_examined(), 0);
+        assert_eq!(metrics.unpaired_read_duplicates(), 1);
+        assert_eq!(metrics.read_pair_duplicates(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_location() {
+        assert_eq!(
+            parse_location(b"m:1:fc:2:1101:1234:5678", 0),
+            Some(Location {
+                read_group: 0,
+                tile: 1101,
+                x: 1234,
+                y: 5678
+            })
+        );
+
+        assert!(parse_location(b"r0", 0).is_none());
+        assert!(parse_location(b"a:b:c", 0).is_none());
+    }
+}
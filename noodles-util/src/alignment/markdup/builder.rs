@@ -0,0 +1,75 @@
+//! Alignment duplicate detector builder.
+
+use std::collections::HashMap;
+
+use noodles_sam as sam;
+
+use super::{build_read_groups, Detector};
+
+const DEFAULT_OPTICAL_DUPLICATE_PIXEL_DISTANCE: u32 = 100;
+
+/// An alignment duplicate detector builder.
+#[derive(Debug)]
+pub struct Builder {
+    optical_duplicate_pixel_distance: Option<u32>,
+}
+
+impl Builder {
+    /// Sets the maximum pixel distance between optical duplicates.
+    ///
+    /// Optical duplicates are duplicate read pairs on the same tile whose x and y coordinates,
+    /// parsed from their read names, are within this distance. Set this to `None` to disable
+    /// optical duplicate detection. By default, this is 100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::markdup;
+    /// let builder = markdup::Builder::default().set_optical_duplicate_pixel_distance(Some(2500));
+    /// ```
+    pub fn set_optical_duplicate_pixel_distance(
+        mut self,
+        optical_duplicate_pixel_distance: Option<u32>,
+    ) -> Self {
+        self.optical_duplicate_pixel_distance = optical_duplicate_pixel_distance;
+        self
+    }
+
+    /// Builds an alignment duplicate detector.
+    ///
+    /// The header is used to resolve reference sequences and the libraries (`LB`) of read groups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::markdup;
+    ///
+    /// let header = sam::Header::default();
+    /// let detector = markdup::Builder::default().build(&header);
+    /// ```
+    pub fn build(self, header: &sam::Header) -> Detector<'_> {
+        let (read_groups, metrics) = build_read_groups(header);
+
+        Detector {
+            header,
+            optical_duplicate_pixel_distance: self.optical_duplicate_pixel_distance.map(i64::from),
+            read_groups,
+            metrics,
+            unknown_library: None,
+            record_count: 0,
+            fragments: Vec::new(),
+            pairs: Vec::new(),
+            segments: Vec::new(),
+            pending_mates: HashMap::new(),
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            optical_duplicate_pixel_distance: Some(DEFAULT_OPTICAL_DUPLICATE_PIXEL_DISTANCE),
+        }
+    }
+}
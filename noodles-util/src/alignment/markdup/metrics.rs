@@ -0,0 +1,161 @@
+//! Duplicate marking metrics.
+
+/// Duplicate marking metrics of a library.
+///
+/// The fields are the same as Picard's `DuplicationMetrics`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Metrics {
+    pub(super) library: String,
+    pub(super) unpaired_reads_examined: u64,
+    pub(super) read_pairs_examined: u64,
+    pub(super) secondary_or_supplementary_reads: u64,
+    pub(super) unmapped_reads: u64,
+    pub(super) unpaired_read_duplicates: u64,
+    pub(super) read_pair_duplicates: u64,
+    pub(super) read_pair_optical_duplicates: u64,
+}
+
+impl Metrics {
+    pub(super) fn new(library: String) -> Self {
+        Self {
+            library,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the library name (`LIBRARY`).
+    pub fn library(&self) -> &str {
+        &self.library
+    }
+
+    /// Returns the number of mapped primary reads without a mapped mate
+    /// (`UNPAIRED_READS_EXAMINED`).
+    pub fn unpaired_reads_examined(&self) -> u64 {
+        self.unpaired_reads_examined
+    }
+
+    /// Returns the number of mapped primary read pairs (`READ_PAIRS_EXAMINED`).
+    pub fn read_pairs_examined(&self) -> u64 {
+        self.read_pairs_examined
+    }
+
+    /// Returns the number of secondary and supplementary reads (`SECONDARY_OR_SUPPLEMENTARY_RDS`).
+    pub fn secondary_or_supplementary_reads(&self) -> u64 {
+        self.secondary_or_supplementary_reads
+    }
+
+    /// Returns the number of unmapped reads (`UNMAPPED_READS`).
+    pub fn unmapped_reads(&self) -> u64 {
+        self.unmapped_reads
+    }
+
+    /// Returns the number of unpaired reads marked as duplicates (`UNPAIRED_READ_DUPLICATES`).
+    pub fn unpaired_read_duplicates(&self) -> u64 {
+        self.unpaired_read_duplicates
+    }
+
+    /// Returns the number of read pairs marked as duplicates (`READ_PAIR_DUPLICATES`).
+    pub fn read_pair_duplicates(&self) -> u64 {
+        self.read_pair_duplicates
+    }
+
+    /// Returns the number of read pairs that are optical duplicates
+    /// (`READ_PAIR_OPTICAL_DUPLICATES`).
+    pub fn read_pair_optical_duplicates(&self) -> u64 {
+        self.read_pair_optical_duplicates
+    }
+
+    /// Returns the fraction of mapped reads that are duplicates (`PERCENT_DUPLICATION`).
+    ///
+    /// Despite its name, this is a fraction between 0 and 1.
+    pub fn percent_duplication(&self) -> f64 {
+        let reads = self.unpaired_reads_examined + self.read_pairs_examined * 2;
+
+        if reads == 0 {
+            0.0
+        } else {
+            let duplicates = self.unpaired_read_duplicates + self.read_pair_duplicates * 2;
+            duplicates as f64 / reads as f64
+        }
+    }
+
+    /// Returns the estimated number of unique molecules in the library (`ESTIMATED_LIBRARY_SIZE`).
+    ///
+    /// This is estimated from the read pairs using the Lander-Waterman equation. It is `None` if
+    /// there are no non-optical duplicate pairs.
+    pub fn estimated_library_size(&self) -> Option<u64> {
+        estimate_library_size(
+            self.read_pairs_examined
+                .saturating_sub(self.read_pair_optical_duplicates),
+            self.read_pairs_examined
+                .saturating_sub(self.read_pair_duplicates),
+        )
+    }
+}
+
+// Solves `c / x = 1 - exp(-n / x)` for `x`, where `n` is the number of read pairs and `c`, the
+// number of unique read pairs.
+fn estimate_library_size(read_pairs: u64, unique_read_pairs: u64) -> Option<u64> {
+    fn f(x: f64, c: f64, n: f64) -> f64 {
+        c / x - 1.0 + (-n / x).exp()
+    }
+
+    if read_pairs == 0 || unique_read_pairs >= read_pairs {
+        return None;
+    }
+
+    let (n, c) = (read_pairs as f64, unique_read_pairs as f64);
+
+    if f(c, c, n) < 0.0 {
+        return None;
+    }
+
+    let mut m = 1.0;
+    let mut upper = 100.0;
+
+    while f(upper * c, c, n) > 0.0 {
+        upper *= 10.0;
+    }
+
+    for _ in 0..40 {
+        let r = (m + upper) / 2.0;
+        let u = f(r * c, c, n);
+
+        if u == 0.0 {
+            break;
+        } else if u > 0.0 {
+            m = r;
+        } else {
+            upper = r;
+        }
+    }
+
+    Some((c * (m + upper) / 2.0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_duplication() {
+        let metrics = Metrics {
+            unpaired_reads_examined: 2,
+            read_pairs_examined: 4,
+            unpaired_read_duplicates: 1,
+            read_pair_duplicates: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(metrics.percent_duplication(), 0.3);
+        assert_eq!(Metrics::default().percent_duplication(), 0.0);
+    }
+
+    #[test]
+    fn test_estimate_library_size() {
+        assert_eq!(estimate_library_size(0, 0), None);
+        assert_eq!(estimate_library_size(8, 8), None);
+
+        assert_eq!(estimate_library_size(10000, 8000), Some(21541));
+    }
+}
@@ -0,0 +1,183 @@
+//! Alignment record merging.
+//!
+//! [`Merge`] is a k-way merge of sorted alignment inputs. The input headers are merged into a
+//! single header, and records are rewritten to use the reference sequence IDs, read group IDs, and
+//! program IDs of the merged header.
+
+pub mod builder;
+mod header;
+
+pub use self::builder::Builder;
+
+use std::{cmp::Reverse, collections::BinaryHeap, io};
+
+use noodles_sam::{self as sam, alignment::RecordBuf};
+
+use self::header::Mapping;
+use super::sort::{key::Key, SortOrder};
+
+type Records<'a> = Box<dyn Iterator<Item = io::Result<Box<dyn sam::alignment::Record>>> + 'a>;
+
+/// An iterator over merged alignment records.
+///
+/// This is created by calling [`Builder::build`].
+pub struct Merge<'a> {
+    header: sam::Header,
+    sort_order: SortOrder,
+    sources: Vec<Source<'a>>,
+    records: Vec<Option<RecordBuf>>,
+    heap: BinaryHeap<Reverse<(Key, usize)>>,
+}
+
+struct Source<'a> {
+    header: &'a sam::Header,
+    records: Records<'a>,
+    mapping: Mapping,
+}
+
+impl<'a> Merge<'a> {
+    fn new(
+        header: sam::Header,
+        sort_order: SortOrder,
+        sources: Vec<Source<'a>>,
+    ) -> io::Result<Self> {
+        let mut merge = Self {
+            header,
+            sort_order,
+            records: vec![None; sources.len()],
+            sources,
+            heap: BinaryHeap::new(),
+        };
+
+        for i in 0..merge.sources.len() {
+            merge.fill(i)?;
+        }
+
+        Ok(merge)
+    }
+
+    /// Returns the merged header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_util::alignment;
+    ///
+    /// let mut readers = vec![
+    ///     alignment::io::reader::Builder::default()
+    ///         .build_from_reader(Cursor::new(b"@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:sq0\tLN:8\n"))?,
+    ///     alignment::io::reader::Builder::default()
+    ///         .build_from_reader(Cursor::new(b"@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:sq1\tLN:13\n"))?,
+    /// ];
+    ///
+    /// let headers: Vec<_> = readers
+    ///     .iter_mut()
+    ///     .map(|reader| reader.read_header())
+    ///     .collect::<io::Result<_>>()?;
+    ///
+    /// let merge = alignment::merge::Builder::default().build(&mut readers, &headers)?;
+    ///
+    /// let names: Vec<_> = merge.header().reference_sequences().keys().collect();
+    /// assert_eq!(names, ["sq0", "sq1"]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn header(&self) -> &sam::Header {
+        &self.header
+    }
+
+    fn fill(&mut self, i: usize) -> io::Result<()> {
+        let source = &mut self.sources[i];
+
+        let Some(record) = source.records.next().transpose()? else {
+            return Ok(());
+        };
+
+        let mut record = RecordBuf::try_from_alignment_record(source.header, &record)?;
+        source.mapping.apply(&mut record)?;
+
+        let key = Key::new(&self.header, self.sort_order, &record)?;
+
+        self.records[i] = Some(record);
+        self.heap.push(Reverse((key, i)));
+
+        Ok(())
+    }
+}
+
+impl Iterator for Merge<'_> {
+    type Item = io::Result<RecordBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((_, i)) = self.heap.pop()?;
+        let record = self.records[i].take()?;
+
+        match self.fill(i) {
+            Ok(()) => Some(Ok(record)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use sam::alignment::record::data::field::Tag;
+
+    use super::*;
+    use crate::alignment::io::reader;
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        const SRC_A: &[u8] = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+@SQ\tSN:sq1\tLN:13
+@RG\tID:rg0\tLB:lb0
+r0\t0\tsq0\t1\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0
+r2\t0\tsq1\t5\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0
+";
+
+        const SRC_B: &[u8] = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq1\tLN:13
+@RG\tID:rg0\tLB:lb1
+r1\t0\tsq1\t2\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0
+r3\t0\tsq1\t5\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0
+r4\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0
+";
+
+        let mut readers = vec![
+            reader::Builder::default().build_from_reader(Cursor::new(SRC_A))?,
+            reader::Builder::default().build_from_reader(Cursor::new(SRC_B))?,
+        ];
+
+        let headers: Vec<_> = readers
+            .iter_mut()
+            .map(|reader| reader.read_header())
+            .collect::<io::Result<_>>()?;
+
+        let merge = Builder::default().build(&mut readers, &headers)?;
+        let records: Vec<_> = merge.collect::<io::Result<_>>()?;
+
+        let actual: Vec<_> = records
+            .iter()
+            .map(|record| {
+                let name = record.name().map(|name| name.to_vec()).unwrap_or_default();
+                let read_group_id = record.data().get(&Tag::READ_GROUP).cloned();
+                (name, record.reference_sequence_id(), read_group_id)
+            })
+            .collect();
+
+        let expected = [
+            (b"r0".to_vec(), Some(0), Some("rg0".into())),
+            (b"r1".to_vec(), Some(1), Some("rg0-1".into())),
+            (b"r2".to_vec(), Some(1), Some("rg0".into())),
+            (b"r3".to_vec(), Some(1), Some("rg0-1".into())),
+            (b"r4".to_vec(), None, Some("rg0-1".into())),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+//! Alignment record merge builder.
+
+use std::io::{self, Read};
+
+use noodles_sam as sam;
+
+use super::{header::merge_headers, Merge, Source};
+use crate::alignment::{io::Reader, sort::SortOrder};
+
+/// An alignment record merge builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    sort_order: SortOrder,
+}
+
+impl Builder {
+    /// Sets the sort order of the inputs.
+    ///
+    /// All inputs must be sorted by this order. By default, inputs are expected to be sorted by
+    /// coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::{self, sort::SortOrder};
+    /// let builder = alignment::merge::Builder::default().set_sort_order(SortOrder::QueryName);
+    /// ```
+    pub fn set_sort_order(mut self, sort_order: SortOrder) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+
+    /// Builds a merge iterator from alignment readers and their headers.
+    ///
+    /// Each reader is expected to be positioned at the start of its records, i.e., its header has
+    /// already been read into `headers`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_util::alignment;
+    ///
+    /// let mut readers = vec![
+    ///     alignment::io::reader::Builder::default()
+    ///         .build_from_reader(Cursor::new(b"@HD\tVN:1.6\n"))?,
+    /// ];
+    ///
+    /// let headers: Vec<_> = readers
+    ///     .iter_mut()
+    ///     .map(|reader| reader.read_header())
+    ///     .collect::<io::Result<_>>()?;
+    ///
+    /// let mut merge = alignment::merge::Builder::default().build(&mut readers, &headers)?;
+    /// assert!(merge.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build<'a, R>(
+        self,
+        readers: &'a mut [Reader<R>],
+        headers: &'a [sam::Header],
+    ) -> io::Result<Merge<'a>>
+    where
+        R: Read,
+    {
+        if readers.len() != headers.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "reader and header count mismatch",
+            ));
+        }
+
+        let (header, mappings) = merge_headers(headers, self.sort_order)?;
+
+        let sources = readers
+            .iter_mut()
+            .zip(headers)
+            .zip(mappings)
+            .map(|((reader, header), mapping)| Source {
+                header,
+                records: Box::new(reader.records(header)),
+                mapping,
+            })
+            .collect();
+
+        Merge::new(header, self.sort_order, sources)
+    }
+}
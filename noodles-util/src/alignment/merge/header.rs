@@ -0,0 +1,377 @@
+use std::{collections::HashMap, io};
+
+use noodles_sam::{
+    self as sam,
+    alignment::{record::data::field::Tag, record_buf::data::field::Value, RecordBuf},
+    header::record::value::map::program::tag as program_tag,
+};
+
+use crate::alignment::sort::{set_sort_order, SortOrder};
+
+const SEPARATOR: u8 = b'-';
+
+/// Maps the reference sequences, read groups, and programs of an input header to the merged
+/// header.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub(super) struct Mapping {
+    reference_sequence_ids: Vec<usize>,
+    read_group_ids: HashMap<Vec<u8>, Vec<u8>>,
+    program_ids: HashMap<Vec<u8>, Vec<u8>>,
+}
+
+impl Mapping {
+    /// Rewrites the reference sequence IDs, read group (`RG`), and program (`PG`) of a record.
+    pub(super) fn apply(&self, record: &mut RecordBuf) -> io::Result<()> {
+        if let Some(id) = record.reference_sequence_id_mut() {
+            *id = self.map_reference_sequence_id(*id)?;
+        }
+
+        if let Some(id) = record.mate_reference_sequence_id_mut() {
+            *id = self.map_reference_sequence_id(*id)?;
+        }
+
+        rename(record, Tag::READ_GROUP, &self.read_group_ids)?;
+        rename(record, Tag::PROGRAM, &self.program_ids)?;
+
+        Ok(())
+    }
+
+    fn map_reference_sequence_id(&self, id: usize) -> io::Result<usize> {
+        self.reference_sequence_ids.get(id).copied().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence ID")
+        })
+    }
+}
+
+fn rename(record: &mut RecordBuf, tag: Tag, ids: &HashMap<Vec<u8>, Vec<u8>>) -> io::Result<()> {
+    if ids.is_empty() {
+        return Ok(());
+    }
+
+    match record.data_mut().get_mut(&tag) {
+        Some(Value::String(id)) => {
+            if let Some(new_id) = ids.get(id.as_slice()) {
+                *id = new_id.clone().into();
+            }
+
+            Ok(())
+        }
+        Some(_) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid {tag:?} field type"),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Merges the headers of sorted inputs.
+///
+/// Reference sequences are combined in order of first appearance. Reference sequences with the
+/// same name must have the same length, and for coordinate-based sort orders, every input must
+/// list its reference sequences in the same relative order.
+///
+/// Read groups and programs with the same ID but different fields are renamed by appending a
+/// suffix, e.g., `rg0` becomes `rg0-1`.
+pub(super) fn merge_headers(
+    headers: &[sam::Header],
+    sort_order: SortOrder,
+) -> io::Result<(sam::Header, Vec<Mapping>)> {
+    let mut merged_header = sam::Header::default();
+    let mut mappings = Vec::with_capacity(headers.len());
+
+    if let Some(hdr) = headers.iter().find_map(|header| header.header()) {
+        *merged_header.header_mut() = Some(hdr.clone());
+    }
+
+    for header in headers {
+        let mut mapping = Mapping::default();
+
+        merge_reference_sequences(&mut merged_header, header, sort_order, &mut mapping)?;
+        merge_read_groups(&mut merged_header, header, &mut mapping);
+        merge_programs(&mut merged_header, header, &mut mapping);
+
+        for comment in header.comments() {
+            if !merged_header.comments().contains(comment) {
+                merged_header.add_comment(comment.clone());
+            }
+        }
+
+        mappings.push(mapping);
+    }
+
+    set_sort_order(&mut merged_header, sort_order);
+
+    Ok((merged_header, mappings))
+}
+
+fn merge_reference_sequences(
+    merged_header: &mut sam::Header,
+    header: &sam::Header,
+    sort_order: SortOrder,
+    mapping: &mut Mapping,
+) -> io::Result<()> {
+    let reference_sequences = merged_header.reference_sequences_mut();
+
+    for (name, reference_sequence) in header.reference_sequences() {
+        let id = match reference_sequences.get_full(name) {
+            Some((id, _, merged_reference_sequence)) => {
+                if merged_reference_sequence.length() != reference_sequence.length() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("reference sequence length mismatch: {name}"),
+                    ));
+                }
+
+                id
+            }
+            None => {
+                let (id, _) =
+                    reference_sequences.insert_full(name.clone(), reference_sequence.clone());
+                id
+            }
+        };
+
+        if sort_order != SortOrder::QueryName
+            && mapping
+                .reference_sequence_ids
+                .last()
+                .map(|&prev_id| id < prev_id)
+                .unwrap_or(false)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("reference sequence order mismatch: {name}"),
+            ));
+        }
+
+        mapping.reference_sequence_ids.push(id);
+    }
+
+    Ok(())
+}
+
+fn merge_read_groups(merged_header: &mut sam::Header, header: &sam::Header, mapping: &mut Mapping) {
+    let read_groups = merged_header.read_groups_mut();
+
+    for (id, read_group) in header.read_groups() {
+        match read_groups.get(id) {
+            Some(merged_read_group) if merged_read_group == read_group => {}
+            Some(_) => {
+                let new_id = unique_id(id, |new_id| read_groups.contains_key(new_id));
+                read_groups.insert(new_id.clone().into(), read_group.clone());
+                mapping.read_group_ids.insert(id.to_vec(), new_id);
+            }
+            None => {
+                read_groups.insert(id.clone(), read_group.clone());
+            }
+        }
+    }
+}
+
+fn merge_programs(merged_header: &mut sam::Header, header: &sam::Header, mapping: &mut Mapping) {
+    let programs = merged_header.programs_mut().as_mut();
+
+    // IDs are resolved before inserting so that previous program IDs (`PP`) can be rewritten.
+    let mut new_programs = Vec::new();
+
+    for (id, program) in header.programs().as_ref() {
+        let mut program = program.clone();
+
+        if let Some(previous_program_id) = program
+            .other_fields_mut()
+            .get_mut(&program_tag::PREVIOUS_PROGRAM_ID)
+        {
+            if let Some(new_id) = mapping.program_ids.get(previous_program_id.as_slice()) {
+                *previous_program_id = new_id.clone().into();
+            }
+        }
+
+        match programs.get(id) {
+            Some(merged_program) if *merged_program == program => {}
+            Some(_) => {
+                let new_id = unique_id(id, |new_id| {
+                    programs.contains_key(new_id)
+                        || new_programs.iter().any(|(other_id, _)| other_id == new_id)
+                });
+
+                mapping.program_ids.insert(id.to_vec(), new_id.clone());
+                new_programs.push((new_id, program));
+            }
+            None => new_programs.push((id.to_vec(), program)),
+        }
+    }
+
+    for (id, program) in new_programs {
+        programs.insert(id.into(), program);
+    }
+}
+
+fn unique_id<F>(id: &[u8], contains: F) -> Vec<u8>
+where
+    F: Fn(&[u8]) -> bool,
+{
+    (1..)
+        .map(|i| {
+            let mut new_id = id.to_vec();
+            new_id.push(SEPARATOR);
+            new_id.extend(i.to_string().as_bytes());
+            new_id
+        })
+        .find(|new_id| !contains(new_id))
+        // SAFETY: The number of IDs is finite.
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use sam::header::record::value::{
+        map::{program, read_group, Program, ReadGroup, ReferenceSequence},
+        Map,
+    };
+
+    use super::*;
+
+    fn reference_sequence(length: usize) -> Map<ReferenceSequence> {
+        Map::<ReferenceSequence>::new(NonZeroUsize::try_from(length).unwrap())
+    }
+
+    #[test]
+    fn test_merge_headers() -> Result<(), Box<dyn std::error::Error>> {
+        let header_a = sam::Header::builder()
+            .add_reference_sequence("sq0", reference_sequence(8))
+            .add_reference_sequence("sq2", reference_sequence(21))
+            .add_read_group(
+                "rg0",
+                Map::<ReadGroup>::builder()
+                    .insert(read_group::tag::LIBRARY, "lb0")
+                    .build()?,
+            )
+            .add_program("pg0", Map::<Program>::default())
+            .add_comment("noodles")
+            .build();
+
+        let header_b = sam::Header::builder()
+            .add_reference_sequence("sq0", reference_sequence(8))
+            .add_reference_sequence("sq1", reference_sequence(13))
+            .add_reference_sequence("sq2", reference_sequence(21))
+            .add_read_group(
+                "rg0",
+                Map::<ReadGroup>::builder()
+                    .insert(read_group::tag::LIBRARY, "lb1")
+                    .build()?,
+            )
+            .add_program(
+                "pg0",
+                Map::<Program>::builder()
+                    .insert(program::tag::NAME, "noodles")
+                    .build()?,
+            )
+            .add_program(
+                "pg1",
+                Map::<Program>::builder()
+                    .insert(program::tag::PREVIOUS_PROGRAM_ID, "pg0")
+                    .build()?,
+            )
+            .add_comment("noodles")
+            .build();
+
+        let (header, mappings) = merge_headers(&[header_a, header_b], SortOrder::QueryName)?;
+
+        let names: Vec<_> = header.reference_sequences().keys().collect();
+        assert_eq!(names, ["sq0", "sq2", "sq1"]);
+
+        let read_group_ids: Vec<_> = header.read_groups().keys().collect();
+        assert_eq!(read_group_ids, ["rg0", "rg0-1"]);
+
+        let program_ids: Vec<_> = header.programs().as_ref().keys().collect();
+        assert_eq!(program_ids, ["pg0", "pg0-1", "pg1"]);
+
+        let pg1 = &header.programs().as_ref()[&b"pg1"[..]];
+        assert_eq!(
+            pg1.other_fields()
+                .get(&program::tag::PREVIOUS_PROGRAM_ID)
+                .map(|id| id.as_slice()),
+            Some(&b"pg0-1"[..])
+        );
+
+        assert_eq!(header.comments().len(), 1);
+
+        assert_eq!(mappings[0].reference_sequence_ids, [0, 1]);
+        assert!(mappings[0].read_group_ids.is_empty());
+        assert_eq!(mappings[1].reference_sequence_ids, [0, 2, 1]);
+        assert_eq!(
+            mappings[1].read_group_ids.get(&b"rg0"[..]),
+            Some(&b"rg0-1".to_vec())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_headers_with_invalid_reference_sequences() {
+        let header_a = sam::Header::builder()
+            .add_reference_sequence("sq0", reference_sequence(8))
+            .add_reference_sequence("sq1", reference_sequence(13))
+            .build();
+
+        let header_b = sam::Header::builder()
+            .add_reference_sequence("sq1", reference_sequence(13))
+            .add_reference_sequence("sq0", reference_sequence(8))
+            .build();
+
+        assert!(matches!(
+            merge_headers(&[header_a.clone(), header_b.clone()], SortOrder::Coordinate),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(merge_headers(&[header_a.clone(), header_b], SortOrder::QueryName).is_ok());
+
+        let header_c = sam::Header::builder()
+            .add_reference_sequence("sq0", reference_sequence(5))
+            .build();
+
+        assert!(matches!(
+            merge_headers(&[header_a, header_c], SortOrder::QueryName),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_mapping_apply() -> io::Result<()> {
+        let mapping = Mapping {
+            reference_sequence_ids: vec![2, 0],
+            read_group_ids: [(b"rg0".to_vec(), b"rg0-1".to_vec())].into_iter().collect(),
+            program_ids: HashMap::new(),
+        };
+
+        let mut record = RecordBuf::builder()
+            .set_reference_sequence_id(1)
+            .set_mate_reference_sequence_id(0)
+            .set_data(
+                [(Tag::READ_GROUP, Value::from("rg0"))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        mapping.apply(&mut record)?;
+
+        assert_eq!(record.reference_sequence_id(), Some(0));
+        assert_eq!(record.mate_reference_sequence_id(), Some(2));
+        assert_eq!(
+            record.data().get(&Tag::READ_GROUP),
+            Some(&Value::from("rg0-1"))
+        );
+
+        let mut record = RecordBuf::builder().set_reference_sequence_id(2).build();
+
+        assert!(matches!(
+            mapping.apply(&mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,724 @@
+//! Alignment pileup.
+//!
+//! [`Pileup`] takes coordinate-sorted alignment records and emits a [`Column`] for each covered
+//! reference sequence position. Each column entry describes the base, deletion, or reference skip
+//! of a single record at that position, along with its qualities, strand, read position, and any
+//! indel that follows it. This is similar to `samtools mpileup`.
+
+mod builder;
+pub mod column;
+pub mod multi_sample_pileup;
+
+use std::{
+    collections::{HashMap, VecDeque},
+    io,
+};
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        record::{
+            cigar::op::Kind as CigarKind,
+            data::field::{Tag, Value},
+            Flags,
+        },
+        Record,
+    },
+};
+
+use self::column::{
+    entry::{Indel, Kind},
+    Entry,
+};
+pub use self::{builder::Builder, column::Column, multi_sample_pileup::MultiSamplePileup};
+use super::iter::AlignedPairs;
+
+/// An alignment pileup.
+///
+/// This is an iterator over columns of coordinate-sorted records. Records may span multiple
+/// reference sequences. Columns without any entries are skipped.
+pub struct Pileup<'h, I> {
+    header: &'h sam::Header,
+    records: I,
+    excluded_flags: Flags,
+    min_mapping_quality: u8,
+    min_base_quality: u8,
+    deduplicate_overlapping_mates: bool,
+    reference_sequence_repository: Option<fasta::Repository>,
+    sample_ids: SampleIds,
+    reference_sequence_id: Option<usize>,
+    reference_sequence: Option<fasta::record::Sequence>,
+    window_start: Position,
+    window: VecDeque<Vec<Entry>>,
+    // Columns before this position are complete. If unset, all columns in the window are complete.
+    limit: Option<Position>,
+    next_record: Option<(Box<dyn Record>, usize)>,
+    // Record IDs of segments whose mates are expected to overlap them, keyed by name.
+    mates: HashMap<Vec<u8>, usize>,
+    next_record_id: usize,
+}
+
+impl<'h, I> Pileup<'h, I>
+where
+    I: Iterator<Item = io::Result<Box<dyn Record>>>,
+{
+    /// Creates an alignment pileup with the default options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_sam::{self as sam, alignment::Record};
+    /// use noodles_util::alignment::pileup::Pileup;
+    ///
+    /// let header = sam::Header::default();
+    /// let records = std::iter::empty::<io::Result<Box<dyn Record>>>();
+    ///
+    /// let mut pileup = Pileup::new(&header, records);
+    /// assert!(pileup.next().is_none());
+    /// ```
+    pub fn new(header: &'h sam::Header, records: I) -> Self {
+        Builder::default().build(header, records)
+    }
+
+    fn read_record(&mut self) -> io::Result<Option<(Box<dyn Record>, usize)>> {
+        if let Some(record) = self.next_record.take() {
+            return Ok(Some(record));
+        }
+
+        for result in &mut self.records {
+            let record = result?;
+
+            let flags = record.flags()?;
+
+            if flags.intersects(self.excluded_flags) {
+                continue;
+            }
+
+            let mapping_quality = record.mapping_quality().transpose()?;
+
+            if mapping_quality.is_some_and(|mapq| u8::from(mapq) < self.min_mapping_quality) {
+                continue;
+            }
+
+            let Some(sample_id) = self.sample_ids.get(&*record)? else {
+                continue;
+            };
+
+            return Ok(Some((record, sample_id)));
+        }
+
+        Ok(None)
+    }
+
+    // Reads and piles the next record. This returns `false` when there are no more columns.
+    fn step(&mut self) -> io::Result<bool> {
+        let Some((record, sample_id)) = self.read_record()? else {
+            if self.window.is_empty() {
+                return Ok(false);
+            }
+
+            self.limit = None;
+
+            return Ok(true);
+        };
+
+        let (reference_sequence_id, alignment_start) = alignment_context(self.header, &*record)?;
+
+        match self.reference_sequence_id {
+            Some(id) if reference_sequence_id == id => {
+                if self.limit.is_some_and(|limit| alignment_start < limit) {
+                    return Err(unsorted_error());
+                }
+            }
+            Some(id) if reference_sequence_id < id => return Err(unsorted_error()),
+            _ => {
+                if !self.window.is_empty() {
+                    self.next_record = Some((record, sample_id));
+                    self.limit = None;
+                    return Ok(true);
+                }
+
+                self.set_reference_sequence(reference_sequence_id)?;
+            }
+        }
+
+        if self.window.is_empty() {
+            self.window_start = alignment_start;
+        }
+
+        self.limit = Some(alignment_start);
+
+        self.pile(&*record, sample_id, reference_sequence_id, alignment_start)?;
+
+        Ok(true)
+    }
+
+    fn set_reference_sequence(&mut self, reference_sequence_id: usize) -> io::Result<()> {
+        self.reference_sequence_id = Some(reference_sequence_id);
+        self.mates.clear();
+
+        if let Some(repository) = &self.reference_sequence_repository {
+            let (name, _) = self
+                .header
+                .reference_sequences()
+                .get_index(reference_sequence_id)
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence ID")
+                })?;
+
+            let sequence = repository.get(name).transpose()?.ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("missing reference sequence: {name}"),
+                )
+            })?;
+
+            self.reference_sequence = Some(sequence);
+        }
+
+        Ok(())
+    }
+
+    fn pile(
+        &mut self,
+        record: &dyn Record,
+        sample_id: usize,
+        reference_sequence_id: usize,
+        alignment_start: Position,
+    ) -> io::Result<()> {
+        let record_id = self.next_record_id;
+        self.next_record_id += 1;
+
+        let mut entries = build_entries(record, record_id, sample_id)?;
+
+        let Some((alignment_end, _)) = entries.last() else {
+            return Ok(());
+        };
+
+        let alignment_end = *alignment_end;
+
+        if self.deduplicate_overlapping_mates && is_primary_mapped_pair(record.flags()?) {
+            if let Some(name) = record.name() {
+                if let Some(mate_record_id) = self.mates.remove(&**name) {
+                    self.resolve_overlaps(mate_record_id, &mut entries);
+                } else if mate_overlaps(
+                    self.header,
+                    record,
+                    reference_sequence_id,
+                    alignment_start,
+                    alignment_end,
+                )? {
+                    self.mates.insert(name.to_vec(), record_id);
+                }
+            }
+        }
+
+        let window_start = usize::from(self.window_start);
+
+        for (position, entry) in entries {
+            let i = usize::from(position) - window_start;
+
+            if i >= self.window.len() {
+                self.window.resize_with(i + 1, Vec::new);
+            }
+
+            self.window[i].push(entry);
+        }
+
+        Ok(())
+    }
+
+    fn resolve_overlaps(&mut self, mate_record_id: usize, entries: &mut Vec<(Position, Entry)>) {
+        let window_start = usize::from(self.window_start);
+
+        entries.retain_mut(|(position, entry)| {
+            let Kind::Base(base) = entry.kind else {
+                return true;
+            };
+
+            let Some(column) = self.window.get_mut(usize::from(*position) - window_start) else {
+                return true;
+            };
+
+            let Some(i) = column
+                .iter()
+                .position(|e| e.record_id == mate_record_id && matches!(e.kind, Kind::Base(_)))
+            else {
+                return true;
+            };
+
+            let mate = &mut column[i];
+
+            let Kind::Base(mate_base) = mate.kind else {
+                unreachable!();
+            };
+
+            let keep_mate = match (mate.quality_score, entry.quality_score) {
+                (Some(mate_quality_score), Some(quality_score)) => {
+                    if mate_base.eq_ignore_ascii_case(&base) {
+                        let score =
+                            (u16::from(mate_quality_score) + u16::from(quality_score)).min(200);
+                        mate.quality_score = Some(score as u8);
+                        true
+                    } else if mate_quality_score >= quality_score {
+                        mate.quality_score = Some(reduce_quality_score(mate_quality_score));
+                        true
+                    } else {
+                        entry.quality_score = Some(reduce_quality_score(quality_score));
+                        false
+                    }
+                }
+                _ => true,
+            };
+
+            // The kept entry takes the indel of the discarded one.
+            if keep_mate {
+                if mate.indel.is_none() {
+                    mate.indel = entry.indel.take();
+                }
+
+                false
+            } else {
+                let mate = column.remove(i);
+
+                if entry.indel.is_none() {
+                    entry.indel = mate.indel;
+                }
+
+                true
+            }
+        });
+    }
+
+    fn pop_column(&mut self) -> Option<Column> {
+        while self.limit.map_or(true, |limit| self.window_start < limit) {
+            let entries = self.window.pop_front()?;
+
+            let position = self.window_start;
+
+            self.window_start = self
+                .window_start
+                .checked_add(1)
+                .expect("attempt to add with overflow");
+
+            if self.window.is_empty() {
+                self.mates.clear();
+            }
+
+            if let Some(column) = self.build_column(position, entries) {
+                return Some(column);
+            }
+        }
+
+        None
+    }
+
+    fn build_column(&self, position: Position, mut entries: Vec<Entry>) -> Option<Column> {
+        entries.retain(|entry| match (entry.kind, entry.quality_score) {
+            (Kind::Base(_), Some(score)) => score >= self.min_base_quality,
+            _ => true,
+        });
+
+        if entries.is_empty() {
+            return None;
+        }
+
+        // SAFETY: A column is only built after its reference sequence is set.
+        let reference_sequence_id = self.reference_sequence_id.unwrap();
+
+        let reference_base = self
+            .reference_sequence
+            .as_ref()
+            .and_then(|sequence| sequence.get(position).copied());
+
+        Some(Column {
+            reference_sequence_id,
+            position,
+            reference_base,
+            entries,
+        })
+    }
+}
+
+impl<I> Iterator for Pileup<'_, I>
+where
+    I: Iterator<Item = io::Result<Box<dyn Record>>>,
+{
+    type Item = io::Result<Column>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(column) = self.pop_column() {
+                return Some(Ok(column));
+            }
+
+            match self.step() {
+                Ok(true) => {}
+                Ok(false) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+// Resolves the sample IDs of records. This is only used by multi-sample pileups.
+#[derive(Clone, Debug)]
+enum SampleIds {
+    Fixed(usize),
+    ReadGroups(HashMap<Vec<u8>, usize>),
+}
+
+impl SampleIds {
+    fn get(&self, record: &dyn Record) -> io::Result<Option<usize>> {
+        match self {
+            Self::Fixed(id) => Ok(Some(*id)),
+            Self::ReadGroups(ids) => match record.data().get(&Tag::READ_GROUP).transpose()? {
+                Some(Value::String(read_group_id)) => Ok(ids.get(&**read_group_id).copied()),
+                Some(_) => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid read group field type",
+                )),
+                None => Ok(None),
+            },
+        }
+    }
+}
+
+impl Default for SampleIds {
+    fn default() -> Self {
+        Self::Fixed(0)
+    }
+}
+
+fn alignment_context(header: &sam::Header, record: &dyn Record) -> io::Result<(usize, Position)> {
+    match (
+        record.reference_sequence_id(header).transpose()?,
+        record.alignment_start().transpose()?,
+    ) {
+        (Some(id), Some(start)) => Ok((id, start)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing reference sequence ID or alignment start",
+        )),
+    }
+}
+
+fn unsorted_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "records are not coordinate-sorted",
+    )
+}
+
+fn build_entries(
+    record: &dyn Record,
+    record_id: usize,
+    sample_id: usize,
+) -> io::Result<Vec<(Position, Entry)>> {
+    let flags = record.flags()?;
+    let mapping_quality = record.mapping_quality().transpose()?;
+
+    let mut entries: Vec<(Position, Entry)> = Vec::new();
+    let mut previous_kind = None;
+    let mut deletion_owner = None;
+
+    for pair in AlignedPairs::new(record)? {
+        let cigar_kind = pair.kind();
+
+        let kind = match cigar_kind {
+            CigarKind::Match | CigarKind::SequenceMatch | CigarKind::SequenceMismatch => {
+                Kind::Base(pair.read_base().unwrap_or(b'N'))
+            }
+            CigarKind::Deletion => Kind::Deletion,
+            CigarKind::Skip => Kind::Skip,
+            CigarKind::Insertion => {
+                if let Some((_, entry)) = entries.last_mut() {
+                    let base = pair.read_base().unwrap_or(b'N');
+
+                    match &mut entry.indel {
+                        Some(Indel::Insertion(bases)) => bases.push(base),
+                        indel => *indel = Some(Indel::Insertion(vec![base])),
+                    }
+                }
+
+                previous_kind = Some(cigar_kind);
+                continue;
+            }
+            CigarKind::SoftClip | CigarKind::HardClip | CigarKind::Pad => {
+                previous_kind = Some(cigar_kind);
+                continue;
+            }
+        };
+
+        if kind == Kind::Deletion {
+            if previous_kind != Some(CigarKind::Deletion) {
+                deletion_owner = entries.len().checked_sub(1);
+            }
+
+            if let Some((_, owner)) = deletion_owner.and_then(|i| entries.get_mut(i)) {
+                match &mut owner.indel {
+                    Some(Indel::Deletion(len)) => *len += 1,
+                    indel => *indel = Some(Indel::Deletion(1)),
+                }
+            }
+        }
+
+        previous_kind = Some(cigar_kind);
+
+        // SAFETY: Pairs of reference-consuming operations always have a reference position.
+        let position = pair.reference_position().unwrap();
+
+        let (quality_score, read_position) = if matches!(kind, Kind::Base(_)) {
+            (pair.quality_score(), pair.read_position())
+        } else {
+            (None, None)
+        };
+
+        entries.push((
+            position,
+            Entry {
+                record_id,
+                sample_id,
+                kind,
+                quality_score,
+                mapping_quality,
+                is_reverse_complemented: flags.is_reverse_complemented(),
+                read_position,
+                indel: None,
+                is_head: false,
+                is_tail: false,
+            },
+        ));
+    }
+
+    if let Some((_, entry)) = entries.first_mut() {
+        entry.is_head = true;
+    }
+
+    if let Some((_, entry)) = entries.last_mut() {
+        entry.is_tail = true;
+    }
+
+    Ok(entries)
+}
+
+fn is_primary_mapped_pair(flags: Flags) -> bool {
+    flags.is_segmented()
+        && !flags.is_mate_unmapped()
+        && !flags.is_secondary()
+        && !flags.is_supplementary()
+}
+
+fn mate_overlaps(
+    header: &sam::Header,
+    record: &dyn Record,
+    reference_sequence_id: usize,
+    alignment_start: Position,
+    alignment_end: Position,
+) -> io::Result<bool> {
+    let (Some(mate_reference_sequence_id), Some(mate_alignment_start)) = (
+        record.mate_reference_sequence_id(header).transpose()?,
+        record.mate_alignment_start().transpose()?,
+    ) else {
+        return Ok(false);
+    };
+
+    Ok(mate_reference_sequence_id == reference_sequence_id
+        && (alignment_start..=alignment_end).contains(&mate_alignment_start))
+}
+
+// Reduces a quality score to 80%.
+fn reduce_quality_score(score: u8) -> u8 {
+    (u16::from(score) * 4 / 5) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use sam::{
+        alignment::{
+            record::cigar::{op::Kind as CigarKind, Op},
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    fn build_header() -> sam::Header {
+        sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .add_reference_sequence("sq1", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .build()
+    }
+
+    fn build_record(
+        reference_sequence_id: usize,
+        alignment_start: usize,
+        ops: &[Op],
+        sequence: &[u8],
+    ) -> io::Result<Box<dyn Record>> {
+        let alignment_start = Position::new(alignment_start)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidInput))?;
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(reference_sequence_id)
+            .set_alignment_start(alignment_start)
+            .set_cigar(ops.iter().copied().collect())
+            .set_sequence(sequence.to_vec().into())
+            .set_quality_scores(vec![30; sequence.len()].into())
+            .build();
+
+        Ok(Box::new(record))
+    }
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let header = build_header();
+
+        let records = vec![
+            build_record(
+                0,
+                2,
+                &[
+                    Op::new(CigarKind::Match, 2),
+                    Op::new(CigarKind::Insertion, 2),
+                    Op::new(CigarKind::Match, 1),
+                ],
+                b"ACGTA",
+            ),
+            build_record(
+                0,
+                3,
+                &[
+                    Op::new(CigarKind::Match, 1),
+                    Op::new(CigarKind::Deletion, 1),
+                    Op::new(CigarKind::Match, 1),
+                ],
+                b"CT",
+            ),
+            build_record(1, 1, &[Op::new(CigarKind::Match, 1)], b"G"),
+        ];
+
+        let columns: Vec<_> =
+            Pileup::new(&header, records.into_iter()).collect::<io::Result<_>>()?;
+
+        let actual: Vec<_> = columns
+            .iter()
+            .map(|column| {
+                (
+                    column.reference_sequence_id(),
+                    usize::from(column.position()),
+                    column.depth(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            actual,
+            [(0, 2, 1), (0, 3, 2), (0, 4, 2), (0, 5, 1), (1, 1, 1)]
+        );
+
+        let entries = columns[1].entries();
+        assert_eq!(entries[0].kind(), Kind::Base(b'C'));
+        assert_eq!(entries[0].indel(), Some(&Indel::Insertion(b"GT".to_vec())));
+        assert_eq!(entries[1].read_position(), Position::new(1));
+        assert_eq!(entries[1].indel(), Some(&Indel::Deletion(1)));
+        assert!(entries[1].is_head());
+
+        let entries = columns[2].entries();
+        assert_eq!(entries[0].kind(), Kind::Base(b'A'));
+        assert!(entries[0].is_tail());
+        assert_eq!(entries[1].kind(), Kind::Deletion);
+        assert!(entries[1].quality_score().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_overlapping_mates() -> io::Result<()> {
+        fn build_mate(
+            flags: Flags,
+            alignment_start: usize,
+            mate_alignment_start: usize,
+            sequence: &[u8],
+            quality_scores: &[u8],
+        ) -> Box<dyn Record> {
+            let record = RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::SEGMENTED | flags)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(alignment_start).unwrap())
+                .set_cigar(
+                    [Op::new(CigarKind::Match, sequence.len())]
+                        .into_iter()
+                        .collect(),
+                )
+                .set_mate_reference_sequence_id(0)
+                .set_mate_alignment_start(Position::new(mate_alignment_start).unwrap())
+                .set_sequence(sequence.to_vec().into())
+                .set_quality_scores(quality_scores.to_vec().into())
+                .build();
+
+            Box::new(record)
+        }
+
+        let header = build_header();
+
+        let records = [
+            build_mate(Flags::FIRST_SEGMENT, 1, 2, b"ACG", &[30, 30, 20]),
+            build_mate(Flags::LAST_SEGMENT, 2, 1, b"CTA", &[20, 20, 30]),
+        ];
+
+        let columns: Vec<_> = Builder::default()
+            .set_min_base_quality(0)
+            .build(&header, records.into_iter().map(Ok))
+            .collect::<io::Result<_>>()?;
+
+        let actual: Vec<_> = columns
+            .iter()
+            .map(|column| {
+                column
+                    .entries()
+                    .iter()
+                    .map(|entry| (entry.kind(), entry.quality_score()))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let expected = [
+            vec![(Kind::Base(b'A'), Some(30))],
+            vec![(Kind::Base(b'C'), Some(50))],
+            vec![(Kind::Base(b'G'), Some(16))],
+            vec![(Kind::Base(b'A'), Some(30))],
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_unsorted_records() -> io::Result<()> {
+        let header = build_header();
+
+        let records = vec![
+            build_record(0, 8, &[Op::new(CigarKind::Match, 1)], b"A"),
+            build_record(0, 5, &[Op::new(CigarKind::Match, 1)], b"A"),
+        ];
+
+        let mut pileup = Pileup::new(&header, records.into_iter());
+
+        assert!(matches!(
+            pileup.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+//! Alignment pileup builder.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    io,
+};
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    alignment::{record::Flags, Record},
+};
+
+use super::{Pileup, SampleIds};
+
+const DEFAULT_MIN_BASE_QUALITY: u8 = 13;
+
+/// An alignment pileup builder.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    excluded_flags: Flags,
+    min_mapping_quality: u8,
+    min_base_quality: u8,
+    deduplicate_overlapping_mates: bool,
+    reference_sequence_repository: Option<fasta::Repository>,
+}
+
+impl Builder {
+    /// Sets the flags of records to exclude.
+    ///
+    /// A record is excluded if it has any of the given flags. By default, unmapped, secondary, QC
+    /// fail, and duplicate records are excluded. Unmapped records are always excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// use noodles_util::alignment::pileup;
+    /// let builder = pileup::Builder::default().set_excluded_flags(Flags::SECONDARY);
+    /// ```
+    pub fn set_excluded_flags(mut self, excluded_flags: Flags) -> Self {
+        self.excluded_flags = excluded_flags;
+        self
+    }
+
+    /// Sets the minimum mapping quality of records to include.
+    ///
+    /// Records with a missing mapping quality are always included. By default, this is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::pileup;
+    /// let builder = pileup::Builder::default().set_min_mapping_quality(20);
+    /// ```
+    pub fn set_min_mapping_quality(mut self, min_mapping_quality: u8) -> Self {
+        self.min_mapping_quality = min_mapping_quality;
+        self
+    }
+
+    /// Sets the minimum base quality score of bases to include.
+    ///
+    /// Bases with a missing quality score are always included. By default, this is 13.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::pileup;
+    /// let builder = pileup::Builder::default().set_min_base_quality(20);
+    /// ```
+    pub fn set_min_base_quality(mut self, min_base_quality: u8) -> Self {
+        self.min_base_quality = min_base_quality;
+        self
+    }
+
+    /// Sets whether to deduplicate bases of overlapping mates.
+    ///
+    /// When enabled, a position covered by both segments of a template only counts one base. If
+    /// the bases agree, the kept base quality score is the sum of both (capped at 200). Otherwise,
+    /// the base with the higher quality score is kept, and its quality score is reduced to 80%.
+    /// This is enabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::pileup;
+    /// let builder = pileup::Builder::default().set_deduplicate_overlapping_mates(false);
+    /// ```
+    pub fn set_deduplicate_overlapping_mates(
+        mut self,
+        deduplicate_overlapping_mates: bool,
+    ) -> Self {
+        self.deduplicate_overlapping_mates = deduplicate_overlapping_mates;
+        self
+    }
+
+    /// Sets the reference sequence repository.
+    ///
+    /// When set, columns include the reference base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use noodles_util::alignment::pileup;
+    ///
+    /// let builder = pileup::Builder::default()
+    ///     .set_reference_sequence_repository(fasta::Repository::default());
+    /// ```
+    pub fn set_reference_sequence_repository(
+        mut self,
+        reference_sequence_repository: fasta::Repository,
+    ) -> Self {
+        self.reference_sequence_repository = Some(reference_sequence_repository);
+        self
+    }
+
+    /// Builds an alignment pileup from an iterator of coordinate-sorted records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_sam::{self as sam, alignment::Record};
+    /// use noodles_util::alignment::pileup;
+    ///
+    /// let header = sam::Header::default();
+    /// let records = std::iter::empty::<io::Result<Box<dyn Record>>>();
+    ///
+    /// let mut pileup = pileup::Builder::default().build(&header, records);
+    /// assert!(pileup.next().is_none());
+    /// ```
+    pub fn build<I>(self, header: &sam::Header, records: I) -> Pileup<'_, I>
+    where
+        I: Iterator<Item = io::Result<Box<dyn Record>>>,
+    {
+        Pileup {
+            header,
+            records,
+            excluded_flags: self.excluded_flags | Flags::UNMAPPED,
+            min_mapping_quality: self.min_mapping_quality,
+            min_base_quality: self.min_base_quality,
+            deduplicate_overlapping_mates: self.deduplicate_overlapping_mates,
+            reference_sequence_repository: self.reference_sequence_repository,
+            sample_ids: SampleIds::default(),
+            reference_sequence_id: None,
+            reference_sequence: None,
+            window_start: Position::MIN,
+            window: VecDeque::new(),
+            limit: None,
+            next_record: None,
+            mates: HashMap::new(),
+            next_record_id: 0,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            excluded_flags: Flags::UNMAPPED | Flags::SECONDARY | Flags::QC_FAIL | Flags::DUPLICATE,
+            min_mapping_quality: 0,
+            min_base_quality: DEFAULT_MIN_BASE_QUALITY,
+            deduplicate_overlapping_mates: true,
+            reference_sequence_repository: None,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Alignment pileup column.
+
+pub mod entry;
+
+use noodles_core::Position;
+
+pub use self::entry::Entry;
+
+/// An alignment pileup column.
+///
+/// A column is the set of alignment entries that cover a single reference sequence position.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Column {
+    pub(super) reference_sequence_id: usize,
+    pub(super) position: Position,
+    pub(super) reference_base: Option<u8>,
+    pub(super) entries: Vec<Entry>,
+}
+
+impl Column {
+    /// Returns the reference sequence ID.
+    pub fn reference_sequence_id(&self) -> usize {
+        self.reference_sequence_id
+    }
+
+    /// Returns the reference sequence position.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the reference base.
+    ///
+    /// This is only set when the pileup has a reference sequence repository.
+    pub fn reference_base(&self) -> Option<u8> {
+        self.reference_base
+    }
+
+    /// Returns the entries.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Returns the depth.
+    ///
+    /// This is the number of base and deletion entries. Reference skips are not counted.
+    pub fn depth(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| !matches!(entry.kind(), entry::Kind::Skip))
+            .count()
+    }
+}
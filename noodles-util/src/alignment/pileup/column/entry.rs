@@ -0,0 +1,89 @@
+//! Alignment pileup column entry.
+
+use noodles_core::Position;
+use noodles_sam::alignment::record::MappingQuality;
+
+/// An alignment pileup column entry kind.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// An aligned read base.
+    ///
+    /// If the record has no sequence, this is `N`.
+    Base(u8),
+    /// A deleted reference base.
+    Deletion,
+    /// A skipped reference base, e.g., an intron.
+    Skip,
+}
+
+/// An indel that follows an alignment pileup column entry.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Indel {
+    /// Inserted read bases.
+    Insertion(Vec<u8>),
+    /// The number of deleted reference bases.
+    Deletion(usize),
+}
+
+/// An alignment pileup column entry.
+///
+/// An entry is the contribution of a single alignment record to a column.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    pub(in crate::alignment::pileup) record_id: usize,
+    pub(in crate::alignment::pileup) sample_id: usize,
+    pub(in crate::alignment::pileup) kind: Kind,
+    pub(in crate::alignment::pileup) quality_score: Option<u8>,
+    pub(in crate::alignment::pileup) mapping_quality: Option<MappingQuality>,
+    pub(in crate::alignment::pileup) is_reverse_complemented: bool,
+    pub(in crate::alignment::pileup) read_position: Option<Position>,
+    pub(in crate::alignment::pileup) indel: Option<Indel>,
+    pub(in crate::alignment::pileup) is_head: bool,
+    pub(in crate::alignment::pileup) is_tail: bool,
+}
+
+impl Entry {
+    /// Returns the kind.
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Returns the base quality score.
+    ///
+    /// This is only set for base entries of records with quality scores.
+    pub fn quality_score(&self) -> Option<u8> {
+        self.quality_score
+    }
+
+    /// Returns the mapping quality of the record.
+    pub fn mapping_quality(&self) -> Option<MappingQuality> {
+        self.mapping_quality
+    }
+
+    /// Returns whether the record is reverse complemented.
+    pub fn is_reverse_complemented(&self) -> bool {
+        self.is_reverse_complemented
+    }
+
+    /// Returns the 1-based position of the base in the read.
+    ///
+    /// This is only set for base entries.
+    pub fn read_position(&self) -> Option<Position> {
+        self.read_position
+    }
+
+    /// Returns the indel that immediately follows this entry, if any.
+    pub fn indel(&self) -> Option<&Indel> {
+        self.indel.as_ref()
+    }
+
+    /// Returns whether this is the first aligned position of the record.
+    pub fn is_head(&self) -> bool {
+        self.is_head
+    }
+
+    /// Returns whether this is the last aligned position of the record.
+    pub fn is_tail(&self) -> bool {
+        self.is_tail
+    }
+}
@@ -0,0 +1,397 @@
+//! Multi-sample alignment pileup.
+
+use std::{
+    collections::HashMap,
+    io::{self, Read, Seek},
+    iter::Peekable,
+};
+
+use noodles_core::{region::Interval, Position, Region};
+use noodles_sam::{
+    self as sam, alignment::Record, header::record::value::map::read_group::tag::SAMPLE,
+};
+
+use super::{Builder, Column, Pileup, SampleIds};
+use crate::alignment::io::IndexedReader;
+
+/// The samples of a multi-sample pileup input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Samples {
+    /// All records belong to the given sample.
+    Name(String),
+    /// Records are assigned samples by their read groups.
+    ///
+    /// The sample of a read group is its sample (`SM`) or, if missing, its ID. Records without a
+    /// read group in the header are excluded.
+    ReadGroups,
+}
+
+/// A multi-sample alignment pileup column.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiSampleColumn {
+    position: Position,
+    reference_base: Option<u8>,
+    columns: Vec<Option<Column>>,
+}
+
+impl MultiSampleColumn {
+    /// Returns the reference sequence position.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the reference base.
+    pub fn reference_base(&self) -> Option<u8> {
+        self.reference_base
+    }
+
+    /// Returns the column of each sample.
+    ///
+    /// These are in the same order as [`MultiSamplePileup::samples`]. A sample without any entries
+    /// at this position has no column.
+    pub fn columns(&self) -> &[Option<Column>] {
+        &self.columns
+    }
+}
+
+struct Input<R> {
+    reader: IndexedReader<R>,
+    header: sam::Header,
+    sample_ids: SampleIds,
+}
+
+/// A multi-sample alignment pileup.
+///
+/// This synchronizes pileups of several indexed alignment inputs by position. Samples with the
+/// same name are combined, even across inputs.
+pub struct MultiSamplePileup<R> {
+    builder: Builder,
+    inputs: Vec<Input<R>>,
+    samples: Vec<String>,
+}
+
+impl<R> MultiSamplePileup<R>
+where
+    R: Read + Seek,
+{
+    /// Creates a multi-sample pileup.
+    ///
+    /// The given builder configures the pileup of each input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fs::File;
+    /// use noodles_util::alignment::pileup::{self, MultiSamplePileup};
+    /// let pileup: MultiSamplePileup<File> = MultiSamplePileup::new(pileup::Builder::default());
+    /// ```
+    pub fn new(builder: Builder) -> Self {
+        Self {
+            builder,
+            inputs: Vec::new(),
+            samples: Vec::new(),
+        }
+    }
+
+    /// Adds an input.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_util::alignment::{
+    ///     self,
+    ///     pileup::{self, multi_sample_pileup::Samples, MultiSamplePileup},
+    /// };
+    ///
+    /// let mut reader = alignment::io::indexed_reader::Builder::default()
+    ///     .build_from_path("tumor.bam")?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let mut pileup = MultiSamplePileup::new(pileup::Builder::default());
+    /// pileup.add_input(reader, header, Samples::Name(String::from("tumor")));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn add_input(&mut self, reader: IndexedReader<R>, header: sam::Header, samples: Samples) {
+        let sample_ids = match samples {
+            Samples::Name(name) => SampleIds::Fixed(self.sample_id(name)),
+            Samples::ReadGroups => {
+                let mut ids = HashMap::new();
+
+                for (id, read_group) in header.read_groups() {
+                    let name = read_group
+                        .other_fields()
+                        .get(&SAMPLE)
+                        .unwrap_or(id)
+                        .to_string();
+
+                    ids.insert(id.to_vec(), self.sample_id(name));
+                }
+
+                SampleIds::ReadGroups(ids)
+            }
+        };
+
+        self.inputs.push(Input {
+            reader,
+            header,
+            sample_ids,
+        });
+    }
+
+    /// Returns the sample names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fs::File;
+    /// use noodles_util::alignment::pileup::{self, MultiSamplePileup};
+    /// let pileup: MultiSamplePileup<File> = MultiSamplePileup::new(pileup::Builder::default());
+    /// assert!(pileup.samples().is_empty());
+    /// ```
+    pub fn samples(&self) -> &[String] {
+        &self.samples
+    }
+
+    /// Returns an iterator over multi-sample columns in the given region.
+    ///
+    /// Only columns within the region are emitted. To pile up a list of regions, query each in
+    /// turn.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_util::alignment::{
+    ///     self,
+    ///     pileup::{self, multi_sample_pileup::Samples, MultiSamplePileup},
+    /// };
+    ///
+    /// let mut pileup = MultiSamplePileup::new(pileup::Builder::default());
+    ///
+    /// for src in ["normal.bam", "tumor.bam"] {
+    ///     let mut reader = alignment::io::indexed_reader::Builder::default().build_from_path(src)?;
+    ///     let header = reader.read_header()?;
+    ///     pileup.add_input(reader, header, Samples::ReadGroups);
+    /// }
+    ///
+    /// let region = "sq0:8-13".parse()?;
+    ///
+    /// for result in pileup.query(&region)? {
+    ///     let column = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query(&mut self, region: &Region) -> io::Result<Query<'_>> {
+        let mut pileups = Vec::with_capacity(self.inputs.len());
+
+        for input in &mut self.inputs {
+            let Input {
+                reader,
+                header,
+                sample_ids,
+            } = input;
+
+            let records: Records<'_> = Box::new(reader.query(header, region)?);
+
+            let mut pileup = self.builder.clone().build(header, records);
+            pileup.sample_ids = sample_ids.clone();
+
+            pileups.push(pileup.peekable());
+        }
+
+        Ok(Query {
+            interval: region.interval(),
+            pileups,
+            sample_count: self.samples.len(),
+        })
+    }
+
+    fn sample_id(&mut self, name: String) -> usize {
+        if let Some(i) = self.samples.iter().position(|n| *n == name) {
+            i
+        } else {
+            self.samples.push(name);
+            self.samples.len() - 1
+        }
+    }
+}
+
+type Records<'a> = Box<dyn Iterator<Item = io::Result<Box<dyn Record>>> + 'a>;
+
+/// An iterator over multi-sample columns in a region.
+///
+/// This is created by calling [`MultiSamplePileup::query`].
+pub struct Query<'a> {
+    interval: Interval,
+    pileups: Vec<Peekable<Pileup<'a, Records<'a>>>>,
+    sample_count: usize,
+}
+
+impl Iterator for Query<'_> {
+    type Item = io::Result<MultiSampleColumn>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mut position = None;
+
+            for pileup in &mut self.pileups {
+                match pileup.peek() {
+                    Some(Ok(column)) => {
+                        position = match position {
+                            Some(p) if p <= column.position() => Some(p),
+                            _ => Some(column.position()),
+                        };
+                    }
+                    Some(Err(_)) => {
+                        let Some(Err(e)) = pileup.next() else {
+                            unreachable!();
+                        };
+
+                        return Some(Err(e));
+                    }
+                    None => {}
+                }
+            }
+
+            let position = position?;
+
+            if self.interval.end().is_some_and(|end| position > end) {
+                return None;
+            }
+
+            let mut reference_base = None;
+            let mut columns: Vec<Option<Column>> = vec![None; self.sample_count];
+
+            for pileup in &mut self.pileups {
+                let Some(column) =
+                    pileup.next_if(|result| matches!(result, Ok(c) if c.position() == position))
+                else {
+                    continue;
+                };
+
+                // SAFETY: `next_if` only yields columns.
+                let column = column.unwrap();
+
+                reference_base = reference_base.or(column.reference_base);
+
+                for entry in column.entries {
+                    let sample_column = columns[entry.sample_id].get_or_insert_with(|| Column {
+                        reference_sequence_id: column.reference_sequence_id,
+                        position,
+                        reference_base: column.reference_base,
+                        entries: Vec::new(),
+                    });
+
+                    sample_column.entries.push(entry);
+                }
+            }
+
+            if !self.interval.contains(position) {
+                continue;
+            }
+
+            return Some(Ok(MultiSampleColumn {
+                position,
+                reference_base,
+                columns,
+            }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use sam::{
+        alignment::{
+            record::{
+                cigar::{op::Kind, Op},
+                data::field::Tag,
+                Flags,
+            },
+            record_buf::data::field::Value,
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        fn build_record(alignment_start: usize, len: usize, read_group: &str) -> Box<dyn Record> {
+            let record = RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(alignment_start).unwrap())
+                .set_cigar([Op::new(Kind::Match, len)].into_iter().collect())
+                .set_sequence(vec![b'A'; len].into())
+                .set_data(
+                    [(Tag::READ_GROUP, Value::from(read_group))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build();
+
+            Box::new(record)
+        }
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .build();
+
+        // sample 0: 2-4 (rg0); sample 1: 3-3 (rg1), 5-5 (input 1)
+        let inputs: [(Vec<Box<dyn Record>>, SampleIds); 2] = [
+            (
+                vec![build_record(2, 3, "rg0"), build_record(3, 1, "rg1")],
+                SampleIds::ReadGroups(
+                    [(b"rg0".to_vec(), 0), (b"rg1".to_vec(), 1)]
+                        .into_iter()
+                        .collect(),
+                ),
+            ),
+            (vec![build_record(5, 1, "rg0")], SampleIds::Fixed(1)),
+        ];
+
+        let pileups = inputs
+            .into_iter()
+            .map(|(records, sample_ids)| {
+                let records: Records<'_> = Box::new(records.into_iter().map(Ok));
+                let mut pileup = Builder::default().build(&header, records);
+                pileup.sample_ids = sample_ids;
+                pileup.peekable()
+            })
+            .collect();
+
+        let query = Query {
+            interval: (Position::try_from(3)?..=Position::try_from(5)?).into(),
+            pileups,
+            sample_count: 2,
+        };
+
+        let actual: Vec<_> = query
+            .map(|result| {
+                result.map(|column| {
+                    let depths: Vec<_> = column
+                        .columns()
+                        .iter()
+                        .map(|c| c.as_ref().map(|c| c.depth()))
+                        .collect();
+
+                    (usize::from(column.position()), depths)
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (3, vec![Some(1), Some(1)]),
+            (4, vec![Some(1), None]),
+            (5, vec![None, Some(1)]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,448 @@
+//! Alignment record sorting.
+//!
+//! [`Sorter`] is an external merge sort. Records are buffered in memory up to a memory limit.
+//! When the limit is reached, the buffered records are sorted and spilled as a BGZF-compressed run
+//! to a temporary file. When finished, all runs are merged.
+
+pub mod builder;
+pub(crate) mod key;
+pub mod records;
+mod run;
+mod sort_order;
+
+pub use self::{builder::Builder, records::Records, sort_order::SortOrder};
+
+use std::{io, mem, ops::Range, path::PathBuf};
+
+use noodles_bam as bam;
+use noodles_sam::{
+    self as sam,
+    alignment::{io::Write, Record},
+    header::record::value::{
+        map::{
+            self,
+            header::{group_order, sort_order as so, tag},
+        },
+        Map,
+    },
+};
+
+use self::{key::Key, run::Run};
+
+// The approximate number of bytes used by each buffered record in addition to its data.
+const ENTRY_OVERHEAD: usize = mem::size_of::<(Key, Range<usize>)>();
+
+/// An alignment record sorter.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_sam::{self as sam, alignment::RecordBuf};
+/// use noodles_util::alignment::sort::{self, SortOrder};
+///
+/// let header = sam::Header::default();
+///
+/// let mut sorter = sort::Builder::default()
+///     .set_sort_order(SortOrder::QueryName)
+///     .build(header);
+///
+/// sorter.push(&RecordBuf::builder().set_name("r2").build())?;
+/// sorter.push(&RecordBuf::builder().set_name("r10").build())?;
+/// sorter.push(&RecordBuf::builder().set_name("r1").build())?;
+///
+/// let names: Vec<_> = sorter
+///     .finish()?
+///     .map(|result| result.map(|record| record.name().map(|name| name.to_vec())))
+///     .collect::<io::Result<_>>()?;
+///
+/// assert_eq!(
+///     names,
+///     [Some(b"r1".to_vec()), Some(b"r2".to_vec()), Some(b"r10".to_vec())]
+/// );
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Sorter {
+    header: sam::Header,
+    sort_order: SortOrder,
+    memory_limit: usize,
+    temp_dir: PathBuf,
+    writer: bam::io::Writer<Vec<u8>>,
+    entries: Vec<(Key, Range<usize>)>,
+    memory_usage: usize,
+    runs: Vec<Run>,
+}
+
+impl Sorter {
+    /// Returns the header of the sorted records.
+    ///
+    /// The sort order (`SO`), group order (`GO`), and subsort order (`SS`) of the header are set
+    /// to describe the sort order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::map::header::{sort_order, tag},
+    /// };
+    /// use noodles_util::alignment::sort;
+    ///
+    /// let sorter = sort::Builder::default().build(sam::Header::default());
+    ///
+    /// let header = sorter.header().header().expect("missing header");
+    /// assert_eq!(
+    ///     header.other_fields().get(&tag::SORT_ORDER).map(|s| s.as_slice()),
+    ///     Some(sort_order::COORDINATE)
+    /// );
+    /// ```
+    pub fn header(&self) -> &sam::Header {
+        &self.header
+    }
+
+    /// Returns the sort order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::sort::{self, SortOrder};
+    ///
+    /// let sorter = sort::Builder::default()
+    ///     .set_sort_order(SortOrder::TemplateCoordinate)
+    ///     .build(sam::Header::default());
+    ///
+    /// assert_eq!(sorter.sort_order(), SortOrder::TemplateCoordinate);
+    /// ```
+    pub fn sort_order(&self) -> SortOrder {
+        self.sort_order
+    }
+
+    /// Adds a record to the sorter.
+    ///
+    /// If the memory limit is reached, the buffered records are sorted and spilled to a temporary
+    /// file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::sort;
+    ///
+    /// let mut sorter = sort::Builder::default().build(sam::Header::default());
+    /// sorter.push(&RecordBuf::default())?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn push<R>(&mut self, record: &R) -> io::Result<()>
+    where
+        R: Record,
+    {
+        let key = Key::new(&self.header, self.sort_order, record)?;
+
+        let start = self.writer.get_ref().len();
+        self.writer
+            .write_alignment_record(&self.header, record as &dyn Record)?;
+        let end = self.writer.get_ref().len();
+
+        self.memory_usage += (end - start) + ENTRY_OVERHEAD + key.heap_size();
+        self.entries.push((key, start..end));
+
+        if self.memory_usage >= self.memory_limit {
+            self.spill()?;
+        }
+
+        Ok(())
+    }
+
+    /// Adds all records from an iterator and returns the sorted records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::{self as sam, alignment::{Record, RecordBuf}};
+    /// use noodles_util::alignment::sort;
+    ///
+    /// let records = [RecordBuf::default(), RecordBuf::default()]
+    ///     .into_iter()
+    ///     .map(|record| Ok(Box::new(record) as Box<dyn Record>));
+    ///
+    /// let sorter = sort::Builder::default().build(sam::Header::default());
+    /// let sorted_records = sorter.sort(records)?;
+    ///
+    /// assert_eq!(sorted_records.count(), 2);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn sort<I, R>(mut self, records: I) -> io::Result<Records>
+    where
+        I: IntoIterator<Item = io::Result<R>>,
+        R: Record,
+    {
+        for result in records {
+            let record = result?;
+            self.push(&record)?;
+        }
+
+        self.finish()
+    }
+
+    /// Sorts the remaining buffered records and returns an iterator over all sorted records.
+    ///
+    /// Temporary files are removed when the returned iterator is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::sort;
+    ///
+    /// let sorter = sort::Builder::default().build(sam::Header::default());
+    /// let mut records = sorter.finish()?;
+    /// assert!(records.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish(mut self) -> io::Result<Records> {
+        sort_entries(&mut self.entries);
+
+        let buf = mem::take(self.writer.get_mut());
+        let entries = mem::take(&mut self.entries);
+
+        Records::new(self.header, self.sort_order, self.runs, buf, entries)
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        sort_entries(&mut self.entries);
+
+        let buf = self.writer.get_ref();
+        let ranges = self.entries.iter().map(|(_, range)| &buf[range.clone()]);
+        let run = Run::write(&self.temp_dir, ranges)?;
+        self.runs.push(run);
+
+        self.writer.get_mut().clear();
+        self.entries.clear();
+        self.memory_usage = 0;
+
+        Ok(())
+    }
+}
+
+fn sort_entries(entries: &mut [(Key, Range<usize>)]) {
+    // This is a stable sort, i.e., records with equal keys keep their input order.
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+}
+
+pub(crate) fn set_sort_order(header: &mut sam::Header, sort_order: SortOrder) {
+    let hdr = header
+        .header_mut()
+        .get_or_insert_with(Map::<map::Header>::default);
+    let other_fields = hdr.other_fields_mut();
+
+    match sort_order {
+        SortOrder::Coordinate => {
+            other_fields.insert(tag::SORT_ORDER, so::COORDINATE.into());
+            other_fields.swap_remove(&tag::GROUP_ORDER);
+            other_fields.swap_remove(&tag::SUBSORT_ORDER);
+        }
+        SortOrder::QueryName => {
+            other_fields.insert(tag::SORT_ORDER, so::QUERY_NAME.into());
+            other_fields.swap_remove(&tag::GROUP_ORDER);
+            other_fields.swap_remove(&tag::SUBSORT_ORDER);
+        }
+        SortOrder::TemplateCoordinate => {
+            other_fields.insert(tag::SORT_ORDER, so::UNSORTED.into());
+            other_fields.insert(tag::GROUP_ORDER, group_order::QUERY.into());
+            other_fields.insert(tag::SUBSORT_ORDER, "unsorted:template-coordinate".into());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use sam::alignment::{record::Flags, RecordBuf};
+
+    use super::*;
+
+    fn build_header() -> sam::Header {
+        use std::num::NonZeroUsize;
+
+        use sam::header::record::value::map::ReferenceSequence;
+
+        sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .build()
+    }
+
+    fn build_record(
+        name: &str,
+        flags: Flags,
+        reference_sequence_id: Option<usize>,
+        alignment_start: Option<usize>,
+    ) -> RecordBuf {
+        let mut builder = RecordBuf::builder().set_name(name).set_flags(flags);
+
+        if let Some(id) = reference_sequence_id {
+            builder = builder.set_reference_sequence_id(id);
+        }
+
+        if let Some(position) = alignment_start.and_then(Position::new) {
+            builder = builder.set_alignment_start(position);
+        }
+
+        builder.build()
+    }
+
+    fn sort_names(
+        sort_order: SortOrder,
+        memory_limit: usize,
+        records: Vec<RecordBuf>,
+    ) -> io::Result<Vec<Vec<u8>>> {
+        let sorter = Builder::default()
+            .set_sort_order(sort_order)
+            .set_memory_limit(memory_limit)
+            .build(build_header());
+
+        sorter
+            .sort(records.into_iter().map(Ok))?
+            .map(|result| {
+                result.map(|record| record.name().map(|name| name.to_vec()).unwrap_or_default())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sort_by_coordinate() -> io::Result<()> {
+        let records = vec![
+            build_record("r0", Flags::UNMAPPED, None, None),
+            build_record("r1", Flags::empty(), Some(1), Some(5)),
+            build_record("r2", Flags::empty(), Some(0), Some(8)),
+            build_record("r3", Flags::REVERSE_COMPLEMENTED, Some(0), Some(5)),
+            build_record("r4", Flags::empty(), Some(0), Some(5)),
+            build_record("r5", Flags::empty(), Some(0), Some(5)),
+        ];
+
+        let expected = [b"r4", b"r5", b"r3", b"r2", b"r1", b"r0"];
+
+        // in memory
+        assert_eq!(
+            sort_names(SortOrder::Coordinate, usize::MAX, records.clone())?,
+            expected
+        );
+
+        // spills every record
+        assert_eq!(sort_names(SortOrder::Coordinate, 1, records)?, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_by_query_name() -> io::Result<()> {
+        let records = vec![
+            build_record("r10", Flags::UNMAPPED, None, None),
+            build_record(
+                "r2",
+                Flags::SEGMENTED | Flags::LAST_SEGMENT,
+                Some(0),
+                Some(5),
+            ),
+            build_record(
+                "r2",
+                Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::SUPPLEMENTARY,
+                Some(0),
+                Some(3),
+            ),
+            build_record(
+                "r2",
+                Flags::SEGMENTED | Flags::FIRST_SEGMENT,
+                Some(0),
+                Some(8),
+            ),
+            build_record("r1", Flags::empty(), Some(1), Some(1)),
+        ];
+
+        let actual = sort_names(SortOrder::QueryName, 64, records)?;
+        assert_eq!(actual, [&b"r1"[..], b"r2", b"r2", b"r2", b"r10"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_by_template_coordinate() -> io::Result<()> {
+        fn build_pair(
+            name: &str,
+            alignment_start: usize,
+            mate_alignment_start: usize,
+        ) -> [RecordBuf; 2] {
+            let position = Position::new(alignment_start).unwrap();
+            let mate_position = Position::new(mate_alignment_start).unwrap();
+
+            let r1 = RecordBuf::builder()
+                .set_name(name)
+                .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(position)
+                .set_mate_reference_sequence_id(0)
+                .set_mate_alignment_start(mate_position)
+                .build();
+
+            let r2 = RecordBuf::builder()
+                .set_name(name)
+                .set_flags(Flags::SEGMENTED | Flags::LAST_SEGMENT)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(mate_position)
+                .set_mate_reference_sequence_id(0)
+                .set_mate_alignment_start(position)
+                .build();
+
+            [r1, r2]
+        }
+
+        let [a1, a2] = build_pair("a", 50, 10);
+        let [b1, b2] = build_pair("b", 20, 30);
+        let c = build_record("c", Flags::UNMAPPED, None, None);
+
+        let records = vec![a1, c, b1, a2, b2];
+
+        let actual = sort_names(SortOrder::TemplateCoordinate, usize::MAX, records)?;
+        assert_eq!(actual, [&b"a"[..], b"a", b"b", b"b", b"c"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_sort_order() {
+        fn get(header: &sam::Header, key: map::tag::Other<tag::Standard>) -> Option<&[u8]> {
+            header
+                .header()
+                .and_then(|hdr| hdr.other_fields().get(&key))
+                .map(|value| value.as_slice())
+        }
+
+        let mut header = sam::Header::default();
+
+        set_sort_order(&mut header, SortOrder::TemplateCoordinate);
+        assert_eq!(get(&header, tag::SORT_ORDER), Some(so::UNSORTED));
+        assert_eq!(get(&header, tag::GROUP_ORDER), Some(group_order::QUERY));
+        assert_eq!(
+            get(&header, tag::SUBSORT_ORDER),
+            Some(&b"unsorted:template-coordinate"[..])
+        );
+
+        set_sort_order(&mut header, SortOrder::QueryName);
+        assert_eq!(get(&header, tag::SORT_ORDER), Some(so::QUERY_NAME));
+        assert!(get(&header, tag::GROUP_ORDER).is_none());
+        assert!(get(&header, tag::SUBSORT_ORDER).is_none());
+
+        set_sort_order(&mut header, SortOrder::Coordinate);
+        assert_eq!(get(&header, tag::SORT_ORDER), Some(so::COORDINATE));
+    }
+}
@@ -0,0 +1,97 @@
+//! Alignment record sorter builder.
+
+use std::{env, path::PathBuf};
+
+use noodles_bam as bam;
+use noodles_sam as sam;
+
+use super::{set_sort_order, SortOrder, Sorter};
+
+// 768 MiB
+const DEFAULT_MEMORY_LIMIT: usize = 768 << 20;
+
+/// An alignment record sorter builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    sort_order: SortOrder,
+    memory_limit: Option<usize>,
+    temp_dir: Option<PathBuf>,
+}
+
+impl Builder {
+    /// Sets the sort order.
+    ///
+    /// By default, records are sorted by coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::sort::{self, SortOrder};
+    /// let builder = sort::Builder::default().set_sort_order(SortOrder::QueryName);
+    /// ```
+    pub fn set_sort_order(mut self, sort_order: SortOrder) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+
+    /// Sets the approximate maximum number of bytes of records to buffer in memory.
+    ///
+    /// When the limit is reached, the buffered records are spilled to a temporary file. By
+    /// default, this is 768 MiB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::sort;
+    /// let builder = sort::Builder::default().set_memory_limit(1 << 30);
+    /// ```
+    pub fn set_memory_limit(mut self, memory_limit: usize) -> Self {
+        self.memory_limit = Some(memory_limit);
+        self
+    }
+
+    /// Sets the directory to write temporary files to.
+    ///
+    /// By default, this is the system temporary directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::sort;
+    /// let builder = sort::Builder::default().set_temp_dir("/tmp");
+    /// ```
+    pub fn set_temp_dir<P>(mut self, temp_dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.temp_dir = Some(temp_dir.into());
+        self
+    }
+
+    /// Builds an alignment record sorter.
+    ///
+    /// The given header is used to resolve reference sequences and read groups. The sorter's
+    /// header is a copy with its sort order updated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::sort;
+    /// let sorter = sort::Builder::default().build(sam::Header::default());
+    /// ```
+    pub fn build(self, mut header: sam::Header) -> Sorter {
+        set_sort_order(&mut header, self.sort_order);
+
+        Sorter {
+            header,
+            sort_order: self.sort_order,
+            memory_limit: self.memory_limit.unwrap_or(DEFAULT_MEMORY_LIMIT),
+            temp_dir: self.temp_dir.unwrap_or_else(env::temp_dir),
+            writer: bam::io::Writer::from(Vec::new()),
+            entries: Vec::new(),
+            memory_usage: 0,
+            runs: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,387 @@
+use std::{cmp::Ordering, io};
+
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        record::{
+            cigar::{op::Kind, Op},
+            data::field::{Tag, Value},
+            Flags,
+        },
+        Record,
+    },
+    header::record::value::map::read_group::tag::LIBRARY,
+};
+
+use super::SortOrder;
+
+/// A record sort key.
+///
+/// Keys of different sort orders are never compared with each other.
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) enum Key {
+    Coordinate {
+        reference_sequence_id: usize,
+        alignment_start: usize,
+        is_reverse_complemented: bool,
+    },
+    QueryName {
+        name: Name,
+        segment: u16,
+        kind: u16,
+    },
+    TemplateCoordinate(TemplateCoordinateKey),
+}
+
+impl Key {
+    pub(crate) fn new(
+        header: &sam::Header,
+        sort_order: SortOrder,
+        record: &dyn Record,
+    ) -> io::Result<Self> {
+        match sort_order {
+            SortOrder::Coordinate => coordinate_key(header, record),
+            SortOrder::QueryName => query_name_key(record),
+            SortOrder::TemplateCoordinate => template_coordinate_key(header, record),
+        }
+    }
+
+    /// Returns the approximate number of heap bytes owned by the key.
+    pub(super) fn heap_size(&self) -> usize {
+        match self {
+            Self::Coordinate { .. } => 0,
+            Self::QueryName { name, .. } => name.0.len(),
+            Self::TemplateCoordinate(key) => key.library.len() + key.mid.len() + key.name.0.len(),
+        }
+    }
+}
+
+fn coordinate_key(header: &sam::Header, record: &dyn Record) -> io::Result<Key> {
+    let flags = record.flags()?;
+
+    let reference_sequence_id = record
+        .reference_sequence_id(header)
+        .transpose()?
+        .unwrap_or(usize::MAX);
+
+    let alignment_start = record
+        .alignment_start()
+        .transpose()?
+        .map(usize::from)
+        .unwrap_or_default();
+
+    Ok(Key::Coordinate {
+        reference_sequence_id,
+        alignment_start,
+        is_reverse_complemented: flags.is_reverse_complemented(),
+    })
+}
+
+fn query_name_key(record: &dyn Record) -> io::Result<Key> {
+    const SEGMENT_MASK: u16 = Flags::FIRST_SEGMENT.bits() | Flags::LAST_SEGMENT.bits();
+    const KIND_MASK: u16 = Flags::SECONDARY.bits() | Flags::SUPPLEMENTARY.bits();
+
+    let flags = record.flags()?;
+    let name = Name::from(record);
+
+    Ok(Key::QueryName {
+        name,
+        segment: flags.bits() & SEGMENT_MASK,
+        kind: flags.bits() & KIND_MASK,
+    })
+}
+
+/// A template-coordinate sort key.
+///
+/// This is the same key as samtools's `template-coordinate` sort order: the lower and upper
+/// unclipped 5' positions of the template, followed by the library, molecular identifier (`MI`),
+/// and name.
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) struct TemplateCoordinateKey {
+    lower: End,
+    upper: End,
+    library: Vec<u8>,
+    mid: Vec<u8>,
+    name: Name,
+    is_upper: bool,
+}
+
+/// A template end.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct End {
+    reference_sequence_id: usize,
+    position: i64,
+    is_reverse_complemented: bool,
+}
+
+impl End {
+    const UNMAPPED: Self = Self {
+        reference_sequence_id: usize::MAX,
+        position: i64::MAX,
+        is_reverse_complemented: false,
+    };
+}
+
+fn template_coordinate_key(header: &sam::Header, record: &dyn Record) -> io::Result<Key> {
+    let flags = record.flags()?;
+
+    let end = if flags.is_unmapped() {
+        None
+    } else {
+        record_end(header, record, flags)?
+    };
+
+    let mate_end = if flags.is_segmented() && !flags.is_mate_unmapped() {
+        mate_end(header, record, flags)?
+    } else {
+        None
+    };
+
+    let (lower, upper, is_upper) = match (end, mate_end) {
+        (Some(a), Some(b)) => match a.cmp(&b) {
+            Ordering::Less => (a, b, false),
+            Ordering::Greater => (b, a, true),
+            Ordering::Equal => (a, b, flags.is_last_segment()),
+        },
+        (Some(a), None) => (a, a, false),
+        // An unmapped record is placed with its mapped mate.
+        (None, Some(b)) => (b, b, true),
+        (None, None) => (End::UNMAPPED, End::UNMAPPED, false),
+    };
+
+    Ok(Key::TemplateCoordinate(TemplateCoordinateKey {
+        lower,
+        upper,
+        library: library(header, record)?,
+        mid: string_field(record, Tag::UMI_ID)?.unwrap_or_default(),
+        name: Name::from(record),
+        is_upper,
+    }))
+}
+
+fn record_end(header: &sam::Header, record: &dyn Record, flags: Flags) -> io::Result<Option<End>> {
+    let (Some(reference_sequence_id), Some(alignment_start)) = (
+        record.reference_sequence_id(header).transpose()?,
+        record.alignment_start().transpose()?,
+    ) else {
+        return Ok(None);
+    };
+
+    let ops: Vec<_> = record.cigar().iter().collect::<io::Result<_>>()?;
+    let is_reverse_complemented = flags.is_reverse_complemented();
+    let position = unclipped_5_prime_position(alignment_start, &ops, is_reverse_complemented);
+
+    Ok(Some(End {
+        reference_sequence_id,
+        position,
+        is_reverse_complemented,
+    }))
+}
+
+fn mate_end(header: &sam::Header, record: &dyn Record, flags: Flags) -> io::Result<Option<End>> {
+    let (Some(reference_sequence_id), Some(alignment_start)) = (
+        record.mate_reference_sequence_id(header).transpose()?,
+        record.mate_alignment_start().transpose()?,
+    ) else {
+        return Ok(None);
+    };
+
+    let is_reverse_complemented = flags.is_mate_reverse_complemented();
+
+    // Without the mate CIGAR (`MC`), the mate alignment start is used as its 5' position.
+    let position = match mate_cigar_ops(record)? {
+        Some(ops) => unclipped_5_prime_position(alignment_start, &ops, is_reverse_complemented),
+        None => usize::from(alignment_start) as i64,
+    };
+
+    Ok(Some(End {
+        reference_sequence_id,
+        position,
+        is_reverse_complemented,
+    }))
+}
+
+pub(crate) fn mate_cigar_ops(record: &dyn Record) -> io::Result<Option<Vec<Op>>> {
+    let Some(buf) = string_field(record, Tag::MATE_CIGAR)? else {
+        return Ok(None);
+    };
+
+    sam::record::Cigar::new(&buf)
+        .iter()
+        .collect::<Result<_, _>>()
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+pub(crate) fn unclipped_5_prime_position(
+    alignment_start: noodles_core::Position,
+    ops: &[Op],
+    is_reverse_complemented: bool,
+) -> i64 {
+    fn is_clip(op: &&Op) -> bool {
+        matches!(op.kind(), Kind::SoftClip | Kind::HardClip)
+    }
+
+    let start = usize::from(alignment_start) as i64;
+
+    if is_reverse_complemented {
+        let span: usize = ops
+            .iter()
+            .filter(|op| op.kind().consumes_reference())
+            .map(|op| op.len())
+            .sum();
+
+        let trailing_clips: usize = ops
+            .iter()
+            .rev()
+            .take_while(is_clip)
+            .map(|op| op.len())
+            .sum();
+
+        start + span.max(1) as i64 - 1 + trailing_clips as i64
+    } else {
+        let leading_clips: usize = ops.iter().take_while(is_clip).map(|op| op.len()).sum();
+        start - leading_clips as i64
+    }
+}
+
+fn library(header: &sam::Header, record: &dyn Record) -> io::Result<Vec<u8>> {
+    let Some(id) = string_field(record, Tag::READ_GROUP)? else {
+        return Ok(Vec::new());
+    };
+
+    let library = header
+        .read_groups()
+        .iter()
+        .find(|(rg_id, _)| rg_id.as_slice() == id.as_slice())
+        .and_then(|(_, read_group)| read_group.other_fields().get(&LIBRARY))
+        .map(|lb| lb.to_vec())
+        .unwrap_or_default();
+
+    Ok(library)
+}
+
+pub(crate) fn string_field(record: &dyn Record, tag: Tag) -> io::Result<Option<Vec<u8>>> {
+    match record.data().get(&tag).transpose()? {
+        Some(Value::String(s)) => Ok(Some(s.to_vec())),
+        Some(_) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid {tag:?} field type"),
+        )),
+        None => Ok(None),
+    }
+}
+
+/// A record name compared using natural ordering.
+///
+/// Runs of digits are compared by their numeric value, e.g., `r2` < `r10`.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub(crate) struct Name(Vec<u8>);
+
+impl From<&dyn Record> for Name {
+    fn from(record: &dyn Record) -> Self {
+        Self(record.name().map(|name| name.to_vec()).unwrap_or_default())
+    }
+}
+
+impl Ord for Name {
+    fn cmp(&self, other: &Self) -> Ordering {
+        natural_cmp(&self.0, &other.0)
+    }
+}
+
+impl PartialOrd for Name {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn natural_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    fn split_digits(s: &[u8]) -> (&[u8], &[u8]) {
+        let i = s
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .unwrap_or(s.len());
+        s.split_at(i)
+    }
+
+    fn trim_leading_zeros(s: &[u8]) -> &[u8] {
+        let i = s.iter().position(|&b| b != b'0').unwrap_or(s.len());
+        &s[i..]
+    }
+
+    let (mut a, mut b) = (a, b);
+
+    loop {
+        match (a.first(), b.first()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let (a_digits, a_rest) = split_digits(a);
+                let (b_digits, b_rest) = split_digits(b);
+
+                let (m, n) = (trim_leading_zeros(a_digits), trim_leading_zeros(b_digits));
+
+                let ordering = m
+                    .len()
+                    .cmp(&n.len())
+                    .then_with(|| m.cmp(n))
+                    // e.g., `r01` > `r1`
+                    .then_with(|| a_digits.len().cmp(&b_digits.len()));
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+
+                a = a_rest;
+                b = b_rest;
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(y);
+                }
+
+                a = &a[1..];
+                b = &b[1..];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_cmp() {
+        assert_eq!(natural_cmp(b"", b""), Ordering::Equal);
+        assert_eq!(natural_cmp(b"r1", b"r1"), Ordering::Equal);
+        assert_eq!(natural_cmp(b"r1", b"r2"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r2", b"r10"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r10", b"r2"), Ordering::Greater);
+        assert_eq!(natural_cmp(b"r1", b"r01"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r1:5", b"r1:10"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r", b"r1"), Ordering::Less);
+        assert_eq!(natural_cmp(b"ra", b"r1"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_unclipped_5_prime_position() -> Result<(), noodles_core::position::TryFromIntError> {
+        let alignment_start = noodles_core::Position::try_from(8)?;
+
+        let ops = [
+            Op::new(Kind::HardClip, 1),
+            Op::new(Kind::SoftClip, 2),
+            Op::new(Kind::Match, 5),
+            Op::new(Kind::Deletion, 3),
+            Op::new(Kind::Match, 2),
+            Op::new(Kind::SoftClip, 4),
+        ];
+
+        assert_eq!(unclipped_5_prime_position(alignment_start, &ops, false), 5);
+        assert_eq!(unclipped_5_prime_position(alignment_start, &ops, true), 21);
+
+        Ok(())
+    }
+}
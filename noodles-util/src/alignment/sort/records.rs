@@ -0,0 +1,123 @@
+//! Sorted alignment records.
+
+use std::{cmp::Reverse, collections::BinaryHeap, fs::File, io, ops::Range, vec};
+
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_sam as sam;
+
+use super::{key::Key, run::Run, SortOrder};
+
+/// An iterator over sorted alignment records.
+///
+/// This is created by calling [`super::Sorter::finish`].
+pub struct Records {
+    header: sam::Header,
+    sort_order: SortOrder,
+    sources: Vec<Source>,
+    records: Vec<Option<bam::Record>>,
+    heap: BinaryHeap<Reverse<(Key, usize)>>,
+}
+
+impl Records {
+    pub(super) fn new(
+        header: sam::Header,
+        sort_order: SortOrder,
+        runs: Vec<Run>,
+        buf: Vec<u8>,
+        entries: Vec<(Key, Range<usize>)>,
+    ) -> io::Result<Self> {
+        let mut sources = Vec::with_capacity(runs.len() + 1);
+
+        for run in runs {
+            let reader = run.reader()?;
+            sources.push(Source::Run { reader, _run: run });
+        }
+
+        sources.push(Source::Memory {
+            buf,
+            entries: entries.into_iter(),
+        });
+
+        let mut records = Self {
+            header,
+            sort_order,
+            records: vec![None; sources.len()],
+            sources,
+            heap: BinaryHeap::new(),
+        };
+
+        for i in 0..records.sources.len() {
+            records.fill(i)?;
+        }
+
+        Ok(records)
+    }
+
+    /// Returns the header of the sorted records.
+    pub fn header(&self) -> &sam::Header {
+        &self.header
+    }
+
+    fn fill(&mut self, i: usize) -> io::Result<()> {
+        let mut record = bam::Record::default();
+
+        if let Some(key) =
+            self.sources[i].read_record(&self.header, self.sort_order, &mut record)?
+        {
+            self.records[i] = Some(record);
+            self.heap.push(Reverse((key, i)));
+        }
+
+        Ok(())
+    }
+}
+
+impl Iterator for Records {
+    type Item = io::Result<bam::Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((_, i)) = self.heap.pop()?;
+        let record = self.records[i].take()?;
+
+        match self.fill(i) {
+            Ok(()) => Some(Ok(record)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+enum Source {
+    Run {
+        reader: bam::io::Reader<bgzf::Reader<File>>,
+        _run: Run,
+    },
+    Memory {
+        buf: Vec<u8>,
+        entries: vec::IntoIter<(Key, Range<usize>)>,
+    },
+}
+
+impl Source {
+    fn read_record(
+        &mut self,
+        header: &sam::Header,
+        sort_order: SortOrder,
+        record: &mut bam::Record,
+    ) -> io::Result<Option<Key>> {
+        match self {
+            Self::Run { reader, .. } => match reader.read_record(record)? {
+                0 => Ok(None),
+                _ => Key::new(header, sort_order, record).map(Some),
+            },
+            Self::Memory { buf, entries } => match entries.next() {
+                Some((key, range)) => {
+                    let mut reader = bam::io::Reader::from(&buf[range]);
+                    reader.read_record(record)?;
+                    Ok(Some(key))
+                }
+                None => Ok(None),
+            },
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+
+static RUN_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A sorted run of records spilled to a temporary file.
+///
+/// The file is a headerless BGZF-compressed stream of BAM records. It is removed when the run is
+/// dropped.
+pub(super) struct Run {
+    path: PathBuf,
+}
+
+impl Run {
+    pub(super) fn write<'a, I>(temp_dir: &Path, records: I) -> io::Result<Self>
+    where
+        I: IntoIterator<Item = &'a [u8]>,
+    {
+        let (path, file) = create_temp_file(temp_dir)?;
+        let run = Self { path };
+
+        let mut writer = bgzf::writer::Builder::default()
+            .set_compression_level(bgzf::writer::CompressionLevel::FAST)
+            .build_from_writer(BufWriter::new(file));
+
+        for buf in records {
+            writer.write_all(buf)?;
+        }
+
+        writer.finish()?.flush()?;
+
+        Ok(run)
+    }
+
+    pub(super) fn reader(&self) -> io::Result<bam::io::Reader<bgzf::Reader<File>>> {
+        File::open(&self.path).map(bam::io::Reader::new)
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn create_temp_file(temp_dir: &Path) -> io::Result<(PathBuf, File)> {
+    loop {
+        let id = RUN_ID.fetch_add(1, Ordering::Relaxed);
+        let path = temp_dir.join(format!("noodles-sort.{}.{id}.tmp", process::id()));
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
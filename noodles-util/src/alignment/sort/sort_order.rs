@@ -0,0 +1,19 @@
+/// An alignment record sort order.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SortOrder {
+    /// Records are sorted by reference sequence, alignment start, and strand (`coordinate`).
+    ///
+    /// Unmapped records without a reference sequence are placed last.
+    #[default]
+    Coordinate,
+    /// Records are sorted by name using natural ordering (`queryname`).
+    ///
+    /// Records with the same name are ordered by segment (READ1 before READ2) and then primary
+    /// alignments before secondary and supplementary alignments.
+    QueryName,
+    /// Records are sorted by the unclipped 5' positions of their template (`template-coordinate`).
+    ///
+    /// This groups records of the same template together while keeping templates in coordinate
+    /// order.
+    TemplateCoordinate,
+}
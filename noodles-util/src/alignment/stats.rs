@@ -0,0 +1,791 @@
+//! Alignment statistics.
+//!
+//! This is similar to `samtools stats`. A [`Collector`] accumulates statistics of a stream of
+//! records, and the resulting [`Stats`] of separate streams, e.g., of each region of an indexed
+//! file, can be merged.
+
+mod builder;
+mod fragment_stats;
+
+pub use self::{builder::Builder, fragment_stats::FragmentStats};
+
+use std::{collections::VecDeque, io};
+
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    alignment::{record::cigar::op::Kind, Record},
+};
+
+use super::iter::AlignedPairs;
+
+const MISSING_QUALITY_SCORE: u8 = 0xff;
+
+/// Alignment statistics.
+///
+/// Histograms are indexed by value. Secondary and supplementary records are only counted by
+/// [`Self::non_primary_record_count`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    record_count: u64,
+    non_primary_record_count: u64,
+    qc_fail_record_count: u64,
+    duplicate_record_count: u64,
+    mapped_record_count: u64,
+    mapped_and_paired_record_count: u64,
+    properly_paired_record_count: u64,
+    mapping_quality_0_record_count: u64,
+    total_length: u64,
+    mapped_length: u64,
+    mapped_cigar_length: u64,
+    mismatch_count: u64,
+    first_fragments: FragmentStats,
+    last_fragments: FragmentStats,
+    insert_sizes: Vec<u64>,
+    inward_pair_count: u64,
+    outward_pair_count: u64,
+    other_pair_count: u64,
+    coverage: Vec<u64>,
+}
+
+impl Stats {
+    /// Returns the number of primary records.
+    pub fn record_count(&self) -> u64 {
+        self.record_count
+    }
+
+    /// Returns the number of secondary and supplementary records.
+    pub fn non_primary_record_count(&self) -> u64 {
+        self.non_primary_record_count
+    }
+
+    /// Returns the number of records that fail quality controls.
+    pub fn qc_fail_record_count(&self) -> u64 {
+        self.qc_fail_record_count
+    }
+
+    /// Returns the number of duplicate records.
+    pub fn duplicate_record_count(&self) -> u64 {
+        self.duplicate_record_count
+    }
+
+    /// Returns the number of mapped records.
+    pub fn mapped_record_count(&self) -> u64 {
+        self.mapped_record_count
+    }
+
+    /// Returns the number of unmapped records.
+    pub fn unmapped_record_count(&self) -> u64 {
+        self.record_count - self.mapped_record_count
+    }
+
+    /// Returns the number of mapped segmented records with a mapped mate.
+    pub fn mapped_and_paired_record_count(&self) -> u64 {
+        self.mapped_and_paired_record_count
+    }
+
+    /// Returns the number of mapped, properly segmented records.
+    pub fn properly_paired_record_count(&self) -> u64 {
+        self.properly_paired_record_count
+    }
+
+    /// Returns the number of mapped records with a mapping quality of 0.
+    pub fn mapping_quality_0_record_count(&self) -> u64 {
+        self.mapping_quality_0_record_count
+    }
+
+    /// Returns the total number of bases.
+    pub fn total_length(&self) -> u64 {
+        self.total_length
+    }
+
+    /// Returns the number of bases of mapped records.
+    pub fn mapped_length(&self) -> u64 {
+        self.mapped_length
+    }
+
+    /// Returns the number of aligned and inserted bases of mapped records.
+    pub fn mapped_cigar_length(&self) -> u64 {
+        self.mapped_cigar_length
+    }
+
+    /// Returns the number of mismatches with the reference sequence.
+    ///
+    /// This is only computed when a reference sequence repository is set.
+    pub fn mismatch_count(&self) -> u64 {
+        self.mismatch_count
+    }
+
+    /// Returns the statistics of first fragments.
+    ///
+    /// This includes records that are not segmented.
+    pub fn first_fragments(&self) -> &FragmentStats {
+        &self.first_fragments
+    }
+
+    /// Returns the statistics of last fragments.
+    pub fn last_fragments(&self) -> &FragmentStats {
+        &self.last_fragments
+    }
+
+    /// Returns the insert size histogram.
+    ///
+    /// Each pair mapped to the same reference sequence is counted once, by the record with the
+    /// positive template length.
+    pub fn insert_sizes(&self) -> &[u64] {
+        &self.insert_sizes
+    }
+
+    /// Returns the number of pairs where the leftmost segment is forward and its mate, reverse.
+    pub fn inward_pair_count(&self) -> u64 {
+        self.inward_pair_count
+    }
+
+    /// Returns the number of pairs where the leftmost segment is reverse and its mate, forward.
+    pub fn outward_pair_count(&self) -> u64 {
+        self.outward_pair_count
+    }
+
+    /// Returns the number of pairs where both segments are on the same strand.
+    pub fn other_pair_count(&self) -> u64 {
+        self.other_pair_count
+    }
+
+    /// Returns the coverage histogram.
+    ///
+    /// This is the number of reference sequence positions at each depth. Positions that are not
+    /// covered are not counted.
+    pub fn coverage(&self) -> &[u64] {
+        &self.coverage
+    }
+
+    /// Returns the mean read length.
+    pub fn average_length(&self) -> Option<f64> {
+        if self.record_count == 0 {
+            None
+        } else {
+            Some(self.total_length as f64 / self.record_count as f64)
+        }
+    }
+
+    /// Returns the rate of mismatches per aligned and inserted base.
+    pub fn error_rate(&self) -> Option<f64> {
+        if self.mapped_cigar_length == 0 {
+            None
+        } else {
+            Some(self.mismatch_count as f64 / self.mapped_cigar_length as f64)
+        }
+    }
+
+    /// Returns the mean base quality score.
+    pub fn average_quality(&self) -> Option<f64> {
+        let histograms = self
+            .first_fragments
+            .quality_scores
+            .iter()
+            .chain(&self.last_fragments.quality_scores);
+
+        let (mut sum, mut n) = (0, 0);
+
+        for histogram in histograms {
+            for (score, count) in histogram.iter().enumerate() {
+                sum += score as u64 * count;
+                n += count;
+            }
+        }
+
+        if n == 0 {
+            None
+        } else {
+            Some(sum as f64 / n as f64)
+        }
+    }
+
+    /// Returns the mean and standard deviation of the insert sizes.
+    pub fn insert_size_mean_and_standard_deviation(&self) -> Option<(f64, f64)> {
+        let n: u64 = self.insert_sizes.iter().sum();
+
+        if n == 0 {
+            return None;
+        }
+
+        let n = n as f64;
+
+        let mean = self
+            .insert_sizes
+            .iter()
+            .enumerate()
+            .map(|(insert_size, &count)| insert_size as f64 * count as f64)
+            .sum::<f64>()
+            / n;
+
+        let variance = self
+            .insert_sizes
+            .iter()
+            .enumerate()
+            .map(|(insert_size, &count)| (insert_size as f64 - mean).powi(2) * count as f64)
+            .sum::<f64>()
+            / n;
+
+        Some((mean, variance.sqrt()))
+    }
+
+    /// Merges other statistics into these.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::stats::Stats;
+    /// let mut stats = Stats::default();
+    /// stats.merge(&Stats::default());
+    /// assert_eq!(stats.record_count(), 0);
+    /// ```
+    pub fn merge(&mut self, other: &Self) {
+        self.record_count += other.record_count;
+        self.non_primary_record_count += other.non_primary_record_count;
+        self.qc_fail_record_count += other.qc_fail_record_count;
+        self.duplicate_record_count += other.duplicate_record_count;
+        self.mapped_record_count += other.mapped_record_count;
+        self.mapped_and_paired_record_count += other.mapped_and_paired_record_count;
+        self.properly_paired_record_count += other.properly_paired_record_count;
+        self.mapping_quality_0_record_count += other.mapping_quality_0_record_count;
+        self.total_length += other.total_length;
+        self.mapped_length += other.mapped_length;
+        self.mapped_cigar_length += other.mapped_cigar_length;
+        self.mismatch_count += other.mismatch_count;
+
+        self.first_fragments.merge(&other.first_fragments);
+        self.last_fragments.merge(&other.last_fragments);
+
+        merge_counts(&mut self.insert_sizes, &other.insert_sizes);
+        self.inward_pair_count += other.inward_pair_count;
+        self.outward_pair_count += other.outward_pair_count;
+        self.other_pair_count += other.other_pair_count;
+
+        merge_counts(&mut self.coverage, &other.coverage);
+    }
+}
+
+/// An alignment statistics collector.
+///
+/// Records must be coordinate-sorted. When collecting statistics of regions separately, only add
+/// records that start in each region to avoid counting them more than once. The coverage of
+/// positions near region boundaries is then split between the partial results.
+pub struct Collector<'h> {
+    header: &'h sam::Header,
+    max_insert_size: usize,
+    max_coverage: usize,
+    reference_sequence_repository: Option<fasta::Repository>,
+    stats: Stats,
+    coverage: Coverage,
+}
+
+impl<'h> Collector<'h> {
+    /// Creates an alignment statistics collector with the default options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::stats::Collector;
+    ///
+    /// let header = sam::Header::default();
+    /// let collector = Collector::new(&header);
+    /// ```
+    pub fn new(header: &'h sam::Header) -> Self {
+        Builder::default().build(header)
+    }
+
+    /// Adds a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::stats::Collector;
+    ///
+    /// let header = sam::Header::default();
+    /// let mut collector = Collector::new(&header);
+    ///
+    /// collector.add(&RecordBuf::default())?;
+    ///
+    /// let stats = collector.finish();
+    /// assert_eq!(stats.record_count(), 1);
+    /// assert_eq!(stats.unmapped_record_count(), 1);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn add(&mut self, record: &dyn Record) -> io::Result<()> {
+        let flags = record.flags()?;
+
+        if flags.is_secondary() || flags.is_supplementary() {
+            self.stats.non_primary_record_count += 1;
+            return Ok(());
+        }
+
+        self.stats.record_count += 1;
+
+        if flags.is_qc_fail() {
+            self.stats.qc_fail_record_count += 1;
+        }
+
+        if flags.is_duplicate() {
+            self.stats.duplicate_record_count += 1;
+        }
+
+        let read_length = record.sequence().len();
+        self.stats.total_length += read_length as u64;
+
+        let is_last_fragment = flags.is_segmented() && flags.is_last_segment();
+
+        let fragment_stats = if is_last_fragment {
+            &mut self.stats.last_fragments
+        } else {
+            &mut self.stats.first_fragments
+        };
+
+        add_fragment(fragment_stats, record, flags.is_reverse_complemented())?;
+
+        if flags.is_unmapped() {
+            return Ok(());
+        }
+
+        self.stats.mapped_record_count += 1;
+        self.stats.mapped_length += read_length as u64;
+
+        if record
+            .mapping_quality()
+            .transpose()?
+            .is_some_and(|mapping_quality| u8::from(mapping_quality) == 0)
+        {
+            self.stats.mapping_quality_0_record_count += 1;
+        }
+
+        let reference_sequence_id = record
+            .reference_sequence_id(self.header)
+            .transpose()?
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "missing reference sequence ID")
+            })?;
+
+        let alignment_start = record
+            .alignment_start()
+            .transpose()?
+            .map(usize::from)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing alignment start"))?;
+
+        self.coverage.advance(
+            reference_sequence_id,
+            alignment_start,
+            &mut self.stats.coverage,
+            self.max_coverage,
+        )?;
+
+        let mut position = alignment_start;
+
+        for result in record.cigar().iter() {
+            let op = result?;
+
+            match op.kind() {
+                Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                    self.coverage.add(position, op.len());
+                    self.stats.mapped_cigar_length += op.len() as u64;
+                }
+                Kind::Insertion => self.stats.mapped_cigar_length += op.len() as u64,
+                _ => {}
+            }
+
+            if op.kind().consumes_reference() {
+                position += op.len();
+            }
+        }
+
+        if flags.is_segmented() && !flags.is_mate_unmapped() {
+            self.stats.mapped_and_paired_record_count += 1;
+
+            if flags.is_properly_segmented() {
+                self.stats.properly_paired_record_count += 1;
+            }
+
+            let mate_reference_sequence_id =
+                record.mate_reference_sequence_id(self.header).transpose()?;
+
+            let template_length = record.template_length()?;
+
+            if mate_reference_sequence_id == Some(reference_sequence_id) && template_length > 0 {
+                let insert_size = (template_length as usize).min(self.max_insert_size);
+                increment(&mut self.stats.insert_sizes, insert_size);
+
+                match (
+                    flags.is_reverse_complemented(),
+                    flags.is_mate_reverse_complemented(),
+                ) {
+                    (false, true) => self.stats.inward_pair_count += 1,
+                    (true, false) => self.stats.outward_pair_count += 1,
+                    _ => self.stats.other_pair_count += 1,
+                }
+            }
+        }
+
+        if let Some(repository) = &self.reference_sequence_repository {
+            let pairs =
+                AlignedPairs::with_reference_sequence_repository(self.header, repository, record)?;
+
+            let mismatches = if is_last_fragment {
+                &mut self.stats.last_fragments.mismatches
+            } else {
+                &mut self.stats.first_fragments.mismatches
+            };
+
+            for pair in pairs {
+                if !matches!(
+                    pair.kind(),
+                    Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch
+                ) {
+                    continue;
+                }
+
+                let (Some(read_base), Some(reference_base), Some(read_position)) = (
+                    pair.read_base(),
+                    pair.reference_base(),
+                    pair.read_position(),
+                ) else {
+                    continue;
+                };
+
+                let (read_base, reference_base) = (
+                    read_base.to_ascii_uppercase(),
+                    reference_base.to_ascii_uppercase(),
+                );
+
+                if read_base == reference_base || read_base == b'N' || reference_base == b'N' {
+                    continue;
+                }
+
+                self.stats.mismatch_count += 1;
+
+                let i = usize::from(read_position) - 1;
+                let cycle = cycle(read_length, i, flags.is_reverse_complemented());
+                increment(mismatches, cycle);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the collected statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::stats::{Collector, Stats};
+    ///
+    /// let header = sam::Header::default();
+    /// let collector = Collector::new(&header);
+    ///
+    /// assert_eq!(collector.finish(), Stats::default());
+    /// ```
+    pub fn finish(mut self) -> Stats {
+        self.coverage
+            .flush(&mut self.stats.coverage, self.max_coverage);
+
+        self.stats
+    }
+}
+
+fn add_fragment(
+    fragment_stats: &mut FragmentStats,
+    record: &dyn Record,
+    is_reverse_complemented: bool,
+) -> io::Result<()> {
+    let sequence = record.sequence();
+    let read_length = sequence.len();
+
+    fragment_stats.record_count += 1;
+    increment(&mut fragment_stats.read_lengths, read_length);
+
+    let gc_count = sequence
+        .iter()
+        .filter(|b| matches!(b.to_ascii_uppercase(), b'C' | b'G'))
+        .count();
+
+    if let Some(gc_content) = (gc_count * 100 + read_length / 2).checked_div(read_length) {
+        increment(&mut fragment_stats.gc_content, gc_content);
+    }
+
+    let quality_scores = record.quality_scores();
+    let len = quality_scores.len();
+
+    for (i, result) in quality_scores.iter().enumerate() {
+        let score = result?;
+
+        if score == MISSING_QUALITY_SCORE {
+            continue;
+        }
+
+        let cycle = cycle(len, i, is_reverse_complemented);
+
+        if fragment_stats.quality_scores.len() <= cycle {
+            fragment_stats
+                .quality_scores
+                .resize_with(cycle + 1, Vec::new);
+        }
+
+        increment(
+            &mut fragment_stats.quality_scores[cycle],
+            usize::from(score),
+        );
+    }
+
+    Ok(())
+}
+
+fn cycle(len: usize, i: usize, is_reverse_complemented: bool) -> usize {
+    if is_reverse_complemented {
+        len - i - 1
+    } else {
+        i
+    }
+}
+
+fn increment(counts: &mut Vec<u64>, i: usize) {
+    if counts.len() <= i {
+        counts.resize(i + 1, 0);
+    }
+
+    counts[i] += 1;
+}
+
+fn merge_counts(dst: &mut Vec<u64>, src: &[u64]) {
+    if dst.len() < src.len() {
+        dst.resize(src.len(), 0);
+    }
+
+    for (a, b) in dst.iter_mut().zip(src) {
+        *a += b;
+    }
+}
+
+#[derive(Debug, Default)]
+struct Coverage {
+    reference_sequence_id: Option<usize>,
+    start: usize,
+    depths: VecDeque<u64>,
+}
+
+impl Coverage {
+    fn advance(
+        &mut self,
+        reference_sequence_id: usize,
+        position: usize,
+        histogram: &mut Vec<u64>,
+        max_depth: usize,
+    ) -> io::Result<()> {
+        match self.reference_sequence_id {
+            Some(id) if id == reference_sequence_id => {
+                if position < self.start {
+                    return Err(unsorted_error());
+                }
+
+                let n = (position - self.start).min(self.depths.len());
+
+                for depth in self.depths.drain(..n) {
+                    add_depth(histogram, depth, max_depth);
+                }
+            }
+            Some(id) if reference_sequence_id < id => return Err(unsorted_error()),
+            _ => {
+                self.flush(histogram, max_depth);
+                self.reference_sequence_id = Some(reference_sequence_id);
+            }
+        }
+
+        self.start = position;
+
+        Ok(())
+    }
+
+    fn add(&mut self, start: usize, len: usize) {
+        let offset = start - self.start;
+        let end = offset + len;
+
+        if self.depths.len() < end {
+            self.depths.resize(end, 0);
+        }
+
+        for depth in self.depths.range_mut(offset..end) {
+            *depth += 1;
+        }
+    }
+
+    fn flush(&mut self, histogram: &mut Vec<u64>, max_depth: usize) {
+        for depth in self.depths.drain(..) {
+            add_depth(histogram, depth, max_depth);
+        }
+    }
+}
+
+fn add_depth(histogram: &mut Vec<u64>, depth: u64, max_depth: usize) {
+    if depth > 0 {
+        let i = usize::try_from(depth).unwrap_or(usize::MAX).min(max_depth);
+        increment(histogram, i);
+    }
+}
+
+fn unsorted_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "records are not coordinate-sorted",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use sam::{
+        alignment::{
+            record::{cigar::Op, Flags, MappingQuality},
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    fn build_header() -> sam::Header {
+        sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .build()
+    }
+
+    fn build_record(
+        flags: Flags,
+        alignment_start: usize,
+        template_length: i32,
+    ) -> Result<RecordBuf, Box<dyn std::error::Error>> {
+        Ok(RecordBuf::builder()
+            .set_flags(flags)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(alignment_start)?)
+            .set_mapping_quality(MappingQuality::MIN)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_mate_reference_sequence_id(0)
+            .set_template_length(template_length)
+            .set_sequence(b"ACGG".to_vec().into())
+            .set_quality_scores(vec![10, 20, 30, 40].into())
+            .build())
+    }
+
+    #[test]
+    fn test_add() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+        let mut collector = Collector::new(&header);
+
+        let records = [
+            build_record(
+                Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::MATE_REVERSE_COMPLEMENTED,
+                1,
+                6,
+            )?,
+            build_record(
+                Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::REVERSE_COMPLEMENTED,
+                3,
+                -6,
+            )?,
+            build_record(Flags::SECONDARY, 3, 0)?,
+        ];
+
+        for record in &records {
+            collector.add(record)?;
+        }
+
+        let stats = collector.finish();
+
+        assert_eq!(stats.record_count(), 2);
+        assert_eq!(stats.non_primary_record_count(), 1);
+        assert_eq!(stats.mapped_record_count(), 2);
+        assert_eq!(stats.mapped_and_paired_record_count(), 2);
+        assert_eq!(stats.mapping_quality_0_record_count(), 2);
+        assert_eq!(stats.total_length(), 8);
+        assert_eq!(stats.mapped_cigar_length(), 8);
+
+        assert_eq!(stats.first_fragments().record_count(), 1);
+        assert_eq!(stats.first_fragments().read_lengths(), [0, 0, 0, 0, 1]);
+        assert_eq!(stats.first_fragments().gc_content()[75], 1);
+        assert_eq!(stats.first_fragments().quality_scores()[0][10], 1);
+        assert_eq!(stats.last_fragments().quality_scores()[0][40], 1);
+
+        assert_eq!(stats.insert_sizes(), [0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(stats.inward_pair_count(), 1);
+
+        // 1-2: 1, 3-4: 2, 5-6: 1
+        assert_eq!(stats.coverage(), [0, 4, 2]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_with_reference_sequence_repository() -> Result<(), Box<dyn std::error::Error>> {
+        use fasta::record::{Definition, Sequence};
+
+        let header = build_header();
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGTACGT".to_vec()),
+        )]);
+
+        let mut collector = Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build(&header);
+
+        collector.add(&build_record(Flags::empty(), 1, 0)?)?;
+        collector.add(&build_record(Flags::REVERSE_COMPLEMENTED, 1, 0)?)?;
+
+        let stats = collector.finish();
+
+        assert_eq!(stats.mismatch_count(), 2);
+        assert_eq!(stats.first_fragments().mismatches(), [1, 0, 0, 1]);
+        assert_eq!(stats.error_rate(), Some(0.25));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+        let mut collector = Collector::new(&header);
+
+        collector.add(&build_record(Flags::empty(), 3, 0)?)?;
+
+        assert!(matches!(
+            collector.add(&build_record(Flags::empty(), 1, 0)?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let mut collector = Collector::new(&header);
+        collector.add(&build_record(Flags::empty(), 1, 0)?)?;
+        let mut stats = collector.finish();
+
+        let mut collector = Collector::new(&header);
+        collector.add(&build_record(Flags::empty(), 8, 0)?)?;
+        collector.add(&build_record(Flags::empty(), 8, 0)?)?;
+        stats.merge(&collector.finish());
+
+        assert_eq!(stats.record_count(), 3);
+        assert_eq!(stats.first_fragments().read_lengths(), [0, 0, 0, 0, 3]);
+        assert_eq!(stats.first_fragments().quality_scores()[3][40], 3);
+        assert_eq!(stats.coverage(), [0, 4, 4]);
+
+        Ok(())
+    }
+}
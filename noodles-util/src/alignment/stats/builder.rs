@@ -0,0 +1,102 @@
+//! Alignment statistics collector builder.
+
+use noodles_fasta as fasta;
+use noodles_sam as sam;
+
+use super::Collector;
+
+const DEFAULT_MAX_INSERT_SIZE: usize = 8000;
+const DEFAULT_MAX_COVERAGE: usize = 1000;
+
+/// An alignment statistics collector builder.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    max_insert_size: usize,
+    max_coverage: usize,
+    reference_sequence_repository: Option<fasta::Repository>,
+}
+
+impl Builder {
+    /// Sets the maximum insert size.
+    ///
+    /// Larger insert sizes are counted as this value. By default, this is 8000.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::stats;
+    /// let builder = stats::Builder::default().set_max_insert_size(1000);
+    /// ```
+    pub fn set_max_insert_size(mut self, max_insert_size: usize) -> Self {
+        self.max_insert_size = max_insert_size;
+        self
+    }
+
+    /// Sets the maximum coverage.
+    ///
+    /// Larger depths are counted as this value. By default, this is 1000.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::stats;
+    /// let builder = stats::Builder::default().set_max_coverage(100);
+    /// ```
+    pub fn set_max_coverage(mut self, max_coverage: usize) -> Self {
+        self.max_coverage = max_coverage;
+        self
+    }
+
+    /// Sets the reference sequence repository.
+    ///
+    /// When set, mismatches with the reference sequence are counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use noodles_util::alignment::stats;
+    ///
+    /// let builder = stats::Builder::default()
+    ///     .set_reference_sequence_repository(fasta::Repository::default());
+    /// ```
+    pub fn set_reference_sequence_repository(
+        mut self,
+        reference_sequence_repository: fasta::Repository,
+    ) -> Self {
+        self.reference_sequence_repository = Some(reference_sequence_repository);
+        self
+    }
+
+    /// Builds an alignment statistics collector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::stats;
+    ///
+    /// let header = sam::Header::default();
+    /// let collector = stats::Builder::default().build(&header);
+    /// ```
+    pub fn build(self, header: &sam::Header) -> Collector<'_> {
+        Collector {
+            header,
+            max_insert_size: self.max_insert_size,
+            max_coverage: self.max_coverage,
+            reference_sequence_repository: self.reference_sequence_repository,
+            stats: Default::default(),
+            coverage: Default::default(),
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            max_insert_size: DEFAULT_MAX_INSERT_SIZE,
+            max_coverage: DEFAULT_MAX_COVERAGE,
+            reference_sequence_repository: None,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Alignment fragment statistics.
+
+use super::merge_counts;
+
+/// Alignment statistics of first or last fragments.
+///
+/// Histograms are indexed by value, and per-cycle counts are indexed by 0-based cycle, i.e., the
+/// position of the base in the order it was sequenced.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FragmentStats {
+    pub(super) record_count: u64,
+    pub(super) read_lengths: Vec<u64>,
+    pub(super) quality_scores: Vec<Vec<u64>>,
+    pub(super) gc_content: Vec<u64>,
+    pub(super) mismatches: Vec<u64>,
+}
+
+impl FragmentStats {
+    /// Returns the number of records.
+    pub fn record_count(&self) -> u64 {
+        self.record_count
+    }
+
+    /// Returns the read length histogram.
+    pub fn read_lengths(&self) -> &[u64] {
+        &self.read_lengths
+    }
+
+    /// Returns the quality score histogram of each cycle.
+    pub fn quality_scores(&self) -> &[Vec<u64>] {
+        &self.quality_scores
+    }
+
+    /// Returns the GC content histogram.
+    ///
+    /// This is indexed by the percentage of G and C bases in a read, rounded to the nearest
+    /// integer.
+    pub fn gc_content(&self) -> &[u64] {
+        &self.gc_content
+    }
+
+    /// Returns the number of mismatches with the reference sequence of each cycle.
+    ///
+    /// This is only computed when a reference sequence repository is set.
+    pub fn mismatches(&self) -> &[u64] {
+        &self.mismatches
+    }
+
+    pub(super) fn merge(&mut self, other: &Self) {
+        self.record_count += other.record_count;
+
+        merge_counts(&mut self.read_lengths, &other.read_lengths);
+
+        if self.quality_scores.len() < other.quality_scores.len() {
+            self.quality_scores
+                .resize_with(other.quality_scores.len(), Vec::new);
+        }
+
+        for (dst, src) in self.quality_scores.iter_mut().zip(&other.quality_scores) {
+            merge_counts(dst, src);
+        }
+
+        merge_counts(&mut self.gc_content, &other.gc_content);
+        merge_counts(&mut self.mismatches, &other.mismatches);
+    }
+}
@@ -3,4 +3,9 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 
+pub mod concat;
+pub mod genotypes;
 pub mod io;
+pub mod merge;
+pub mod multiallelic;
+pub mod norm;
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{num::NonZeroUsize, path::Path};
 
 use noodles_bcf as bcf;
 use noodles_bgzf as bgzf;
@@ -16,6 +16,7 @@ use crate::variant::io::{CompressionMethod, Format};
 pub struct Builder {
     compression_method: Option<Option<CompressionMethod>>,
     format: Option<Format>,
+    worker_count: Option<NonZeroUsize>,
 }
 
 impl Builder {
@@ -50,6 +51,23 @@ impl Builder {
         self
     }
 
+    /// Sets the number of workers used to decompress BGZF blocks.
+    ///
+    /// This is only used when the input is BGZF-compressed. By default, the worker count is set to
+    /// the number of available logical CPUs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_util::variant::r#async::io::reader::Builder;
+    /// let _builder = Builder::default().set_worker_count(NonZeroUsize::MIN);
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
     /// Builds an async variant reader from a path.
     ///
     /// By default, the format and compression method will be autodetected. This can be overridden
@@ -126,7 +144,7 @@ impl Builder {
             }
             (Format::Vcf, Some(CompressionMethod::Bgzf)) => {
                 let decoder: Box<dyn AsyncBufRead + Unpin> =
-                    Box::new(bgzf::r#async::Reader::new(reader));
+                    Box::new(self.build_bgzf_reader(reader));
                 Reader::Vcf(vcf::r#async::io::Reader::new(decoder))
             }
             (Format::Bcf, None) => {
@@ -135,11 +153,24 @@ impl Builder {
             }
             (Format::Bcf, Some(CompressionMethod::Bgzf)) => {
                 let decoder: Box<dyn AsyncBufRead + Unpin> =
-                    Box::new(bgzf::r#async::Reader::new(reader));
+                    Box::new(self.build_bgzf_reader(reader));
                 Reader::Bcf(bcf::r#async::io::Reader::from(decoder))
             }
         };
 
         Ok(reader)
     }
+
+    fn build_bgzf_reader<R>(&self, reader: R) -> bgzf::AsyncReader<R>
+    where
+        R: AsyncRead,
+    {
+        let mut builder = bgzf::r#async::reader::Builder::default();
+
+        if let Some(worker_count) = self.worker_count {
+            builder = builder.set_worker_count(worker_count);
+        }
+
+        builder.build_from_reader(reader)
+    }
 }
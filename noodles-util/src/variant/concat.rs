@@ -0,0 +1,878 @@
+//! Variant file concatenation.
+//!
+//! [`concat`] and [`concat_naive`] join sorted VCF or BCF files with compatible headers, e.g.,
+//! per-chromosome shards, into a single bgzip-compressed file and build a CSI index of the
+//! output.
+//!
+//! These are similar to `bcftools concat` and `bcftools concat --naive`, respectively.
+
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+use noodles_bcf as bcf;
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::{
+    self as csi,
+    binning_index::index::{
+        header::Builder as IndexHeaderBuilder,
+        reference_sequence::{bin::Chunk, index::BinnedIndex},
+    },
+};
+use noodles_vcf::{self as vcf, variant::Record};
+
+use super::io::{
+    reader::{
+        self,
+        builder::{detect_compression_method, detect_format},
+    },
+    writer::builder::detect_format_from_path_extension,
+    CompressionMethod, Format,
+};
+
+// The BGZF end-of-file marker, an empty block.
+const BGZF_EOF: [u8; 28] = [
+    0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43, 0x02, 0x00,
+    0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+/// Concatenates variant files by decoding and re-encoding their records.
+///
+/// The inputs can be any mix of VCF and BCF, compressed or not. Their headers must be compatible,
+/// i.e., have the same samples and the same contig, INFO, FILTER, and FORMAT definitions. The
+/// output uses the header of the first input and is written as BGZF-compressed BCF if `dst` has a
+/// `.bcf` extension and as BGZF-compressed VCF otherwise.
+///
+/// The records of the output must be sorted by position, with contigs in header order. This is
+/// checked while the records are written. If a record is out of order, an error is returned, and
+/// the partial output at `dst` is removed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_csi as csi;
+/// use noodles_util::variant;
+///
+/// let index = variant::concat::concat(&["sq0.vcf.gz", "sq1.vcf.gz"], "out.vcf.gz")?;
+/// csi::fs::write("out.vcf.gz.csi", &index)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn concat<P, Q>(srcs: &[P], dst: Q) -> io::Result<csi::Index>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let dst = dst.as_ref();
+
+    let mut readers = srcs
+        .iter()
+        .map(|src| reader::Builder::default().build_from_path(src))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let format = detect_format_from_path_extension(dst).unwrap_or(Format::Vcf);
+
+    let mut writer = File::create(dst).map(BufWriter::new)?;
+
+    remove_on_error(dst, move || {
+        concat_inner(&mut readers, format, &mut writer)?;
+        writer.flush()?;
+        File::open(dst).and_then(|file| index(file, format))
+    })
+}
+
+/// Concatenates bgzip-compressed variant files without re-encoding their records.
+///
+/// All inputs must be BGZF-compressed and of the same format (VCF or BCF), and their headers must
+/// be compatible (see [`concat`]). The output is written in the same format. Only the header and
+/// the first block of records of each input are recompressed; all other blocks are copied as is.
+///
+/// The records of the output must be sorted by position, with contigs in header order. Because
+/// records are not decoded when copied, the first records of the inputs are checked to be in order
+/// before anything is written, and all records are checked while building the returned CSI index.
+/// If the records are unsorted, an error is returned, and the output at `dst` is removed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_csi as csi;
+/// use noodles_util::variant;
+///
+/// let index = variant::concat::concat_naive(&["sq0.bcf", "sq1.bcf"], "out.bcf")?;
+/// csi::fs::write("out.bcf.csi", &index)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn concat_naive<P, Q>(srcs: &[P], dst: Q) -> io::Result<csi::Index>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let dst = dst.as_ref();
+
+    let mut srcs = srcs
+        .iter()
+        .map(File::open)
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let mut writer = File::create(dst).map(BufWriter::new)?;
+
+    remove_on_error(dst, move || {
+        let format = concat_naive_inner(&mut srcs, &mut writer)?;
+        writer.flush()?;
+        File::open(dst).and_then(|file| index(file, format))
+    })
+}
+
+// Removes the output if writing or indexing it fails, e.g., because the input is unsorted.
+fn remove_on_error<F>(dst: &Path, f: F) -> io::Result<csi::Index>
+where
+    F: FnOnce() -> io::Result<csi::Index>,
+{
+    let result = f();
+
+    if result.is_err() {
+        // The original error is more useful than one from removing the output.
+        let _ = fs::remove_file(dst);
+    }
+
+    result
+}
+
+fn concat_inner<R, W>(readers: &mut [reader::Reader<R>], format: Format, dst: W) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    let headers: Vec<_> = readers
+        .iter_mut()
+        .map(|reader| reader.read_header())
+        .collect::<io::Result<_>>()?;
+
+    check_headers(&headers)?;
+
+    let header = headers
+        .first()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing inputs"))?;
+
+    match format {
+        Format::Vcf => {
+            let mut writer = vcf::io::Writer::new(bgzf::Writer::new(dst));
+            write_records(&mut writer, header, readers, &headers)?;
+            writer.get_mut().try_finish()
+        }
+        Format::Bcf => {
+            let mut writer = bcf::io::Writer::new(dst);
+            write_records(&mut writer, header, readers, &headers)?;
+            writer.try_finish()
+        }
+    }
+}
+
+fn write_records<W, R>(
+    writer: &mut W,
+    header: &vcf::Header,
+    readers: &mut [reader::Reader<R>],
+    headers: &[vcf::Header],
+) -> io::Result<()>
+where
+    W: vcf::variant::io::Write,
+    R: BufRead,
+{
+    writer.write_variant_header(header)?;
+
+    let mut sort_order_checker = SortOrderChecker::default();
+
+    for (reader, src_header) in readers.iter_mut().zip(headers) {
+        for result in reader.records(src_header) {
+            let record = result?;
+            sort_order_checker.check(header, src_header, record.as_ref())?;
+            writer.write_variant_record(header, record.as_ref())?;
+        }
+    }
+
+    Ok(())
+}
+
+// The header of an input and the position of its records.
+struct Shard {
+    format: Format,
+    header: vcf::Header,
+    // The contig index and start of the first record.
+    first_key: Option<(usize, Position)>,
+    // The uncompressed data of the first block of records.
+    data: Vec<u8>,
+    // The compressed position of the block after `data`.
+    position: u64,
+}
+
+fn concat_naive_inner<R, W>(srcs: &mut [R], dst: &mut W) -> io::Result<Format>
+where
+    R: Read + Seek,
+    W: Write,
+{
+    let mut shards = Vec::with_capacity(srcs.len());
+
+    for src in srcs.iter_mut() {
+        shards.push(read_shard(src)?);
+    }
+
+    let first_shard = shards
+        .first()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing inputs"))?;
+
+    let format = first_shard.format;
+
+    if shards.iter().any(|shard| shard.format != format) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "inputs have different formats",
+        ));
+    }
+
+    let headers: Vec<_> = shards.iter().map(|shard| shard.header.clone()).collect();
+    check_headers(&headers)?;
+
+    // The records are not decoded when copied, so only shard boundaries are checked here.
+    let mut sort_order_checker = SortOrderChecker::default();
+
+    for key in shards.iter().filter_map(|shard| shard.first_key) {
+        sort_order_checker.check_key(key)?;
+    }
+
+    write_header(dst, format, &first_shard.header)?;
+
+    for (src, shard) in srcs.iter_mut().zip(&shards) {
+        let mut writer = bgzf::Writer::new(&mut *dst);
+        writer.write_all(&shard.data)?;
+        writer.flush()?;
+        writer.into_inner();
+
+        copy_blocks(src, shard.position, dst)?;
+    }
+
+    dst.write_all(&BGZF_EOF)?;
+
+    Ok(format)
+}
+
+fn read_shard<R>(src: &mut R) -> io::Result<Shard>
+where
+    R: Read + Seek,
+{
+    let format = {
+        let mut reader = BufReader::new(&mut *src);
+
+        if detect_compression_method(&mut reader)? != Some(CompressionMethod::Bgzf) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "input is not bgzip-compressed",
+            ));
+        }
+
+        detect_format(&mut reader, Some(CompressionMethod::Bgzf))?
+    };
+
+    src.seek(SeekFrom::Start(0))?;
+
+    let (header, mut reader) = match format {
+        Format::Vcf => {
+            let mut reader = vcf::io::Reader::new(bgzf::Reader::new(&mut *src));
+            (reader.read_header()?, reader.into_inner())
+        }
+        Format::Bcf => {
+            let mut reader = bcf::io::Reader::from(bgzf::Reader::new(&mut *src));
+            (reader.read_header()?, reader.into_inner())
+        }
+    };
+
+    // This is either the remainder of the block containing the end of the header or, if the
+    // header ends on a block boundary, the next nonempty block.
+    let data = reader.fill_buf()?.to_vec();
+    let position = reader.position();
+
+    let first_key = read_first_key(src, format, &header)?;
+
+    Ok(Shard {
+        format,
+        header,
+        first_key,
+        data,
+        position,
+    })
+}
+
+fn read_first_key<R>(
+    src: &mut R,
+    format: Format,
+    header: &vcf::Header,
+) -> io::Result<Option<(usize, Position)>>
+where
+    R: Read + Seek,
+{
+    src.seek(SeekFrom::Start(0))?;
+
+    match format {
+        Format::Vcf => {
+            let mut reader = vcf::io::Reader::new(bgzf::Reader::new(&mut *src));
+            reader.read_header()?;
+
+            let mut record = vcf::Record::default();
+
+            if reader.read_record(&mut record)? == 0 {
+                return Ok(None);
+            }
+
+            sort_key(header, header, &record).map(Some)
+        }
+        Format::Bcf => {
+            let mut reader = bcf::io::Reader::new(&mut *src);
+            reader.read_header()?;
+
+            let mut record = bcf::Record::default();
+
+            if reader.read_record(&mut record)? == 0 {
+                return Ok(None);
+            }
+
+            sort_key(header, header, &record).map(Some)
+        }
+    }
+}
+
+fn write_header<W>(dst: &mut W, format: Format, header: &vcf::Header) -> io::Result<()>
+where
+    W: Write,
+{
+    // The inner writers are unwrapped to avoid writing an EOF block.
+    let mut writer = match format {
+        Format::Vcf => {
+            let mut writer = vcf::io::Writer::new(bgzf::Writer::new(dst));
+            writer.write_header(header)?;
+            writer.into_inner()
+        }
+        Format::Bcf => {
+            let mut writer = bcf::io::Writer::new(dst);
+            writer.write_header(header)?;
+            writer.into_inner()
+        }
+    };
+
+    writer.flush()?;
+    writer.into_inner();
+
+    Ok(())
+}
+
+// Copies the raw blocks from `start` to the end of the input, excluding a final EOF block.
+fn copy_blocks<R, W>(src: &mut R, start: u64, dst: &mut W) -> io::Result<()>
+where
+    R: Read + Seek,
+    W: Write,
+{
+    let mut end = src.seek(SeekFrom::End(0))?;
+
+    if let Some(eof_start) = end
+        .checked_sub(BGZF_EOF.len() as u64)
+        .filter(|&eof_start| eof_start >= start)
+    {
+        src.seek(SeekFrom::Start(eof_start))?;
+
+        let mut buf = [0; BGZF_EOF.len()];
+        src.read_exact(&mut buf)?;
+
+        if buf == BGZF_EOF {
+            end = eof_start;
+        }
+    }
+
+    if start < end {
+        src.seek(SeekFrom::Start(start))?;
+        io::copy(&mut src.take(end - start), dst)?;
+    }
+
+    Ok(())
+}
+
+fn check_headers(headers: &[vcf::Header]) -> io::Result<()> {
+    let Some((first_header, headers)) = headers.split_first() else {
+        return Ok(());
+    };
+
+    for header in headers {
+        if !header
+            .sample_names()
+            .iter()
+            .eq(first_header.sample_names().iter())
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "sample names mismatch",
+            ));
+        }
+
+        let contigs = |header: &vcf::Header| {
+            header
+                .contigs()
+                .iter()
+                .map(|(name, contig)| (name.clone(), contig.length()))
+                .collect::<Vec<_>>()
+        };
+
+        let infos = |header: &vcf::Header| {
+            header
+                .infos()
+                .iter()
+                .map(|(id, info)| (id.clone(), info.number(), info.ty()))
+                .collect::<Vec<_>>()
+        };
+
+        let formats = |header: &vcf::Header| {
+            header
+                .formats()
+                .iter()
+                .map(|(id, format)| (id.clone(), format.number(), format.ty()))
+                .collect::<Vec<_>>()
+        };
+
+        let is_compatible = contigs(header) == contigs(first_header)
+            && infos(header) == infos(first_header)
+            && formats(header) == formats(first_header)
+            && header.string_maps() == first_header.string_maps();
+
+        if !is_compatible {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "incompatible headers",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn index<R>(src: R, format: Format) -> io::Result<csi::Index>
+where
+    R: Read,
+{
+    match format {
+        Format::Vcf => {
+            let mut reader = vcf::io::Reader::new(bgzf::Reader::new(src));
+            let header = reader.read_header()?;
+            let mut indexer = Indexer::new(&header, format);
+
+            let mut record = vcf::Record::default();
+            let mut start_position = reader.get_ref().virtual_position();
+
+            while reader.read_record(&mut record)? != 0 {
+                let end_position = reader.get_ref().virtual_position();
+                let chunk = Chunk::new(start_position, end_position);
+                indexer.add_record(&header, &record, chunk)?;
+                start_position = end_position;
+            }
+
+            Ok(indexer.build(&header))
+        }
+        Format::Bcf => {
+            let mut reader = bcf::io::Reader::new(src);
+            let header = reader.read_header()?;
+            let mut indexer = Indexer::new(&header, format);
+
+            let mut record = bcf::Record::default();
+            let mut start_position = reader.get_ref().virtual_position();
+
+            while reader.read_record(&mut record)? != 0 {
+                let end_position = reader.get_ref().virtual_position();
+                let chunk = Chunk::new(start_position, end_position);
+                indexer.add_record(&header, &record, chunk)?;
+                start_position = end_position;
+            }
+
+            Ok(indexer.build(&header))
+        }
+    }
+}
+
+// Returns the contig index in `header` and the start of a record.
+fn sort_key<R>(
+    header: &vcf::Header,
+    src_header: &vcf::Header,
+    record: &R,
+) -> io::Result<(usize, Position)>
+where
+    R: Record + ?Sized,
+{
+    let name = record.reference_sequence_name(src_header)?;
+
+    let reference_sequence_id = header.contigs().get_index_of(name).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("missing contig in header: {name}"),
+        )
+    })?;
+
+    let start = record
+        .variant_start()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing variant start"))?;
+
+    Ok((reference_sequence_id, start))
+}
+
+// Checks that records are sorted by position, with contigs in header order.
+#[derive(Default)]
+struct SortOrderChecker {
+    last_key: Option<(usize, Position)>,
+}
+
+impl SortOrderChecker {
+    fn check<R>(
+        &mut self,
+        header: &vcf::Header,
+        src_header: &vcf::Header,
+        record: &R,
+    ) -> io::Result<(usize, Position)>
+    where
+        R: Record + ?Sized,
+    {
+        let key = sort_key(header, src_header, record)?;
+        self.check_key(key)?;
+        Ok(key)
+    }
+
+    fn check_key(&mut self, key: (usize, Position)) -> io::Result<()> {
+        if self.last_key.is_some_and(|last_key| key < last_key) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "unsorted records",
+            ));
+        }
+
+        self.last_key = Some(key);
+
+        Ok(())
+    }
+}
+
+// A CSI indexer that checks the sort order of records.
+struct Indexer {
+    inner: csi::binning_index::Indexer<BinnedIndex>,
+    sort_order_checker: SortOrderChecker,
+}
+
+impl Indexer {
+    fn new(header: &vcf::Header, format: Format) -> Self {
+        let mut inner = csi::binning_index::Indexer::default();
+
+        // Like tabix indices, CSI indices of bgzipped VCF files carry the reference sequence
+        // names.
+        if format == Format::Vcf {
+            let reference_sequence_names = header
+                .contigs()
+                .keys()
+                .map(|name| name.as_bytes().to_vec().into())
+                .collect();
+
+            inner = inner.set_header(
+                IndexHeaderBuilder::vcf()
+                    .set_reference_sequence_names(reference_sequence_names)
+                    .build(),
+            );
+        }
+
+        Self {
+            inner,
+            sort_order_checker: SortOrderChecker::default(),
+        }
+    }
+
+    fn add_record<R>(&mut self, header: &vcf::Header, record: &R, chunk: Chunk) -> io::Result<()>
+    where
+        R: Record,
+    {
+        let (reference_sequence_id, start) =
+            self.sort_order_checker.check(header, header, record)?;
+        let end = record.variant_end(header)?;
+
+        self.inner
+            .add_record(Some((reference_sequence_id, start, end, true)), chunk)
+    }
+
+    fn build(self, header: &vcf::Header) -> csi::Index {
+        self.inner.build(header.contigs().len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, io::Cursor, process};
+
+    use super::*;
+
+    const HEADER: &[u8] = b"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+";
+
+    fn compress(header: &[u8], records: &[u8]) -> io::Result<Vec<u8>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(header)?;
+        writer.write_all(records)?;
+        writer.finish()
+    }
+
+    fn decompress(src: &[u8]) -> io::Result<Vec<u8>> {
+        let mut reader = bgzf::Reader::new(src);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+
+    #[test]
+    fn test_concat_inner() -> io::Result<()> {
+        let srcs = [
+            compress(HEADER, b"sq0\t1\t.\tA\t.\t.\tPASS\tDP=1\tGT\t0/0\n")?,
+            compress(HEADER, b"sq1\t2\t.\tC\t.\t.\tPASS\tDP=2\tGT\t0/0\n")?,
+        ];
+
+        let mut readers = srcs
+            .into_iter()
+            .map(|src| reader::Builder::default().build_from_reader(Cursor::new(src)))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let mut dst = Vec::new();
+        concat_inner(&mut readers, Format::Vcf, &mut dst)?;
+
+        let mut expected = HEADER.to_vec();
+        expected.extend_from_slice(b"sq0\t1\t.\tA\t.\t.\tPASS\tDP=1\tGT\t0/0\n");
+        expected.extend_from_slice(b"sq1\t2\t.\tC\t.\t.\tPASS\tDP=2\tGT\t0/0\n");
+        assert_eq!(decompress(&dst)?, expected);
+
+        let index = index(&dst[..], Format::Vcf)?;
+        assert_eq!(index.reference_sequences().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_inner_with_unsorted_records() -> io::Result<()> {
+        let srcs = [
+            compress(HEADER, b"sq1\t2\t.\tC\t.\t.\tPASS\tDP=2\tGT\t0/0\n")?,
+            compress(HEADER, b"sq0\t1\t.\tA\t.\t.\tPASS\tDP=1\tGT\t0/0\n")?,
+        ];
+
+        let mut readers = srcs
+            .into_iter()
+            .map(|src| reader::Builder::default().build_from_reader(Cursor::new(src)))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert!(matches!(
+            concat_inner(&mut readers, Format::Vcf, io::sink()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_naive_inner() -> io::Result<()> {
+        // The first shard spans multiple blocks.
+        let sq0_records: Vec<u8> = (1..=4096)
+            .flat_map(|position| {
+                format!("sq0\t{position}\t.\tA\t.\t.\tPASS\tDP=1\tGT\t0/0\n").into_bytes()
+            })
+            .collect();
+
+        let sq1_records = b"sq1\t2\t.\tC\t.\t.\tPASS\tDP=2\tGT\t0/0\n";
+
+        let mut srcs = vec![
+            Cursor::new(compress(HEADER, &sq0_records)?),
+            Cursor::new(compress(HEADER, sq1_records)?),
+        ];
+
+        let mut dst = Vec::new();
+        let format = concat_naive_inner(&mut srcs, &mut dst)?;
+        assert_eq!(format, Format::Vcf);
+        assert!(dst.ends_with(&BGZF_EOF));
+
+        let mut expected = HEADER.to_vec();
+        expected.extend_from_slice(&sq0_records);
+        expected.extend_from_slice(sq1_records);
+        assert_eq!(decompress(&dst)?, expected);
+
+        let index = index(&dst[..], Format::Vcf)?;
+        assert_eq!(index.reference_sequences().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_naive_inner_with_bcf() -> io::Result<()> {
+        use vcf::variant::io::Write as _;
+
+        fn build_bcf(records: &[u8], is_header_block_aligned: bool) -> io::Result<Vec<u8>> {
+            let mut reader = vcf::io::Reader::new(HEADER);
+            let header = reader.read_header()?;
+
+            let mut writer = bcf::io::Writer::new(Vec::new());
+            writer.write_header(&header)?;
+
+            if is_header_block_aligned {
+                writer.get_mut().flush()?;
+            }
+
+            for result in vcf::io::Reader::new(records).records() {
+                let record = result?;
+                writer.write_variant_record(&header, &record)?;
+            }
+
+            writer.into_inner().finish()
+        }
+
+        // The first shard spans multiple blocks.
+        let sq0_records: Vec<u8> = (1..=4096)
+            .flat_map(|position| {
+                format!("sq0\t{position}\t.\tA\t.\t.\tPASS\tDP=1\tGT\t0/0\n").into_bytes()
+            })
+            .collect();
+
+        let sq1_records = b"sq1\t2\t.\tC\t.\t.\tPASS\tDP=2\tGT\t0/0\n";
+
+        let sq1_src = build_bcf(sq1_records, true)?;
+
+        // The header of the second shard ends on a block boundary.
+        let shard = read_shard(&mut Cursor::new(&sq1_src))?;
+        let mut reader = bcf::io::Reader::new(&sq1_src[..]);
+        reader.read_header()?;
+        assert_eq!(reader.get_ref().virtual_position().uncompressed(), 0);
+        assert!(!shard.data.is_empty());
+
+        let mut srcs = vec![
+            Cursor::new(build_bcf(&sq0_records, false)?),
+            Cursor::new(sq1_src),
+        ];
+
+        let mut dst = Vec::new();
+        let format = concat_naive_inner(&mut srcs, &mut dst)?;
+        assert_eq!(format, Format::Bcf);
+        assert!(dst.ends_with(&BGZF_EOF));
+
+        let mut reader = bcf::io::Reader::new(&dst[..]);
+        let header = reader.read_header()?;
+
+        let mut writer = vcf::io::Writer::new(Vec::new());
+
+        for result in reader.records() {
+            let record = result?;
+            writer.write_variant_record(&header, &record)?;
+        }
+
+        let mut expected = sq0_records;
+        expected.extend_from_slice(sq1_records);
+        assert_eq!(writer.get_ref(), &expected);
+
+        let index = index(&dst[..], Format::Bcf)?;
+        assert_eq!(index.reference_sequences().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_naive_inner_with_unsorted_shards() -> io::Result<()> {
+        let mut srcs = vec![
+            Cursor::new(compress(
+                HEADER,
+                b"sq1\t2\t.\tC\t.\t.\tPASS\tDP=2\tGT\t0/0\n",
+            )?),
+            Cursor::new(compress(
+                HEADER,
+                b"sq0\t1\t.\tA\t.\t.\tPASS\tDP=1\tGT\t0/0\n",
+            )?),
+        ];
+
+        let mut dst = Vec::new();
+
+        assert!(matches!(
+            concat_naive_inner(&mut srcs, &mut dst),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(dst.is_empty());
+
+        // Records within a shard are checked while indexing.
+        let mut srcs = vec![Cursor::new(compress(
+            HEADER,
+            b"sq1\t2\t.\tC\t.\t.\tPASS\tDP=2\tGT\t0/0\nsq0\t1\t.\tA\t.\t.\tPASS\tDP=1\tGT\t0/0\n",
+        )?)];
+
+        let mut dst = Vec::new();
+        concat_naive_inner(&mut srcs, &mut dst)?;
+
+        assert!(matches!(
+            index(&dst[..], Format::Vcf),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_with_unsorted_records() -> io::Result<()> {
+        let dir = env::temp_dir().join(format!("noodles-util-concat-{}", process::id()));
+        fs::create_dir_all(&dir)?;
+
+        let srcs = [dir.join("sq1.vcf.gz"), dir.join("sq0.vcf.gz")];
+        fs::write(
+            &srcs[0],
+            compress(HEADER, b"sq1\t2\t.\tC\t.\t.\tPASS\tDP=2\tGT\t0/0\n")?,
+        )?;
+        fs::write(
+            &srcs[1],
+            compress(HEADER, b"sq0\t1\t.\tA\t.\t.\tPASS\tDP=1\tGT\t0/0\n")?,
+        )?;
+
+        let dst = dir.join("out.vcf.gz");
+
+        assert!(matches!(
+            concat(&srcs, &dst),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(!dst.exists());
+
+        assert!(matches!(
+            concat_naive(&srcs, &dst),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(!dst.exists());
+
+        fs::remove_dir_all(dir)
+    }
+
+    #[test]
+    fn test_check_headers() -> io::Result<()> {
+        let header = vcf::io::Reader::new(HEADER).read_header()?;
+        assert!(check_headers(&[header.clone(), header.clone()]).is_ok());
+
+        let mut other_header = header.clone();
+        other_header
+            .sample_names_mut()
+            .insert(String::from("sample1"));
+        assert!(matches!(
+            check_headers(&[header.clone(), other_header]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut other_header = header.clone();
+        other_header.contigs_mut().swap_indices(0, 1);
+        assert!(matches!(
+            check_headers(&[header, other_header]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
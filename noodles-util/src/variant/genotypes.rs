@@ -0,0 +1,576 @@
+//! Variant record genotypes and allele counts.
+//!
+//! [`genotypes`] decodes the genotypes (`GT`) of the samples of any variant record, e.g., a
+//! `vcf::Record`, `bcf::Record`, or `vcf::variant::RecordBuf`. [`count`] summarizes them per
+//! site, and [`fill_allele_counts`] sets the `AC`, `AN`, and `AF` info fields of a record.
+//!
+//! The latter is similar to `bcftools +fill-tags -- -t AC,AN,AF`.
+
+use std::io;
+
+use noodles_vcf::{
+    self as vcf,
+    variant::{
+        record::{
+            info::field::key as info_key,
+            samples::{keys::key, series::Value},
+            Samples as _,
+        },
+        record_buf::info::field::{value::Array, Value as InfoValue},
+        Record, RecordBuf,
+    },
+};
+
+pub use noodles_vcf::variant::record::samples::series::value::genotype::Phasing;
+
+/// A decoded genotype.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Genotype {
+    alleles: Vec<Option<usize>>,
+    phasing: Phasing,
+}
+
+impl Genotype {
+    /// Creates a genotype.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::genotypes::{Genotype, Phasing};
+    /// let genotype = Genotype::new(vec![Some(0), Some(1)], Phasing::Phased);
+    /// ```
+    pub fn new(alleles: Vec<Option<usize>>, phasing: Phasing) -> Self {
+        Self { alleles, phasing }
+    }
+
+    /// Returns the allele positions.
+    ///
+    /// Position 0 is the reference allele, and position `i` > 0 is the `i`-th alternate allele. A
+    /// missing allele is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::genotypes::{Genotype, Phasing};
+    /// let genotype = Genotype::new(vec![Some(0), None], Phasing::Unphased);
+    /// assert_eq!(genotype.alleles(), [Some(0), None]);
+    /// ```
+    pub fn alleles(&self) -> &[Option<usize>] {
+        &self.alleles
+    }
+
+    /// Returns the phasing.
+    ///
+    /// A genotype is phased if all its alleles are phased.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::genotypes::{Genotype, Phasing};
+    /// let genotype = Genotype::new(vec![Some(0), Some(1)], Phasing::Phased);
+    /// assert_eq!(genotype.phasing(), Phasing::Phased);
+    /// ```
+    pub fn phasing(&self) -> Phasing {
+        self.phasing
+    }
+
+    /// Returns the number of alleles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::genotypes::{Genotype, Phasing};
+    /// let genotype = Genotype::new(vec![Some(0), Some(1)], Phasing::Phased);
+    /// assert_eq!(genotype.ploidy(), 2);
+    /// ```
+    pub fn ploidy(&self) -> usize {
+        self.alleles.len()
+    }
+
+    /// Returns whether all alleles are missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::genotypes::{Genotype, Phasing};
+    ///
+    /// let genotype = Genotype::new(vec![None, None], Phasing::Unphased);
+    /// assert!(genotype.is_missing());
+    ///
+    /// let genotype = Genotype::new(vec![Some(0), None], Phasing::Unphased);
+    /// assert!(!genotype.is_missing());
+    /// ```
+    pub fn is_missing(&self) -> bool {
+        self.alleles.iter().all(Option::is_none)
+    }
+
+    /// Returns the zygosity.
+    ///
+    /// This is `None` if any allele is missing. Haploid genotypes are homozygous.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::genotypes::{Genotype, Phasing, Zygosity};
+    ///
+    /// let genotype = Genotype::new(vec![Some(0), Some(1)], Phasing::Unphased);
+    /// assert_eq!(genotype.zygosity(), Some(Zygosity::Heterozygous));
+    ///
+    /// let genotype = Genotype::new(vec![Some(1), Some(1)], Phasing::Unphased);
+    /// assert_eq!(genotype.zygosity(), Some(Zygosity::HomozygousAlternate));
+    ///
+    /// let genotype = Genotype::new(vec![Some(0), None], Phasing::Unphased);
+    /// assert!(genotype.zygosity().is_none());
+    /// ```
+    pub fn zygosity(&self) -> Option<Zygosity> {
+        let mut alleles = self.alleles.iter().copied();
+        let first_allele = alleles.next()??;
+
+        let mut is_homozygous = true;
+
+        for allele in alleles {
+            if allele? != first_allele {
+                is_homozygous = false;
+            }
+        }
+
+        if !is_homozygous {
+            Some(Zygosity::Heterozygous)
+        } else if first_allele == 0 {
+            Some(Zygosity::HomozygousReference)
+        } else {
+            Some(Zygosity::HomozygousAlternate)
+        }
+    }
+}
+
+impl TryFrom<&dyn vcf::variant::record::samples::series::value::Genotype> for Genotype {
+    type Error = io::Error;
+
+    fn try_from(
+        genotype: &dyn vcf::variant::record::samples::series::value::Genotype,
+    ) -> Result<Self, Self::Error> {
+        let mut alleles = Vec::new();
+        let mut phasings = Vec::new();
+
+        for result in genotype.iter() {
+            let (position, phasing) = result?;
+            alleles.push(position);
+            phasings.push(phasing);
+        }
+
+        // The phasing of the first allele only applies to haploid genotypes.
+        let phasing = match phasings.split_first() {
+            Some((&first_phasing, [])) => first_phasing,
+            Some((_, phasings)) if phasings.contains(&Phasing::Unphased) => Phasing::Unphased,
+            _ => Phasing::Phased,
+        };
+
+        Ok(Self::new(alleles, phasing))
+    }
+}
+
+/// A genotype zygosity.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Zygosity {
+    /// All alleles are the reference allele.
+    HomozygousReference,
+    /// The alleles differ.
+    Heterozygous,
+    /// All alleles are the same alternate allele.
+    HomozygousAlternate,
+}
+
+/// Site-level genotype counts.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Counts {
+    allele_counts: Vec<usize>,
+    sample_count: usize,
+    missing_count: usize,
+    homozygous_reference_count: usize,
+    heterozygous_count: usize,
+    homozygous_alternate_count: usize,
+}
+
+impl Counts {
+    /// Returns the number of called alleles for each allele (`AC`), starting with the reference
+    /// allele.
+    pub fn allele_counts(&self) -> &[usize] {
+        &self.allele_counts
+    }
+
+    /// Returns the total number of called alleles (`AN`).
+    pub fn allele_number(&self) -> usize {
+        self.allele_counts.iter().sum()
+    }
+
+    /// Returns the frequency of each alternate allele (`AF`).
+    ///
+    /// This is `None` if no alleles are called.
+    pub fn allele_frequencies(&self) -> Option<Vec<f32>> {
+        let allele_number = self.allele_number();
+
+        if allele_number == 0 {
+            return None;
+        }
+
+        let frequencies = self
+            .allele_counts
+            .iter()
+            .skip(1)
+            .map(|&n| n as f32 / allele_number as f32)
+            .collect();
+
+        Some(frequencies)
+    }
+
+    /// Returns the number of samples.
+    pub fn sample_count(&self) -> usize {
+        self.sample_count
+    }
+
+    /// Returns the number of samples with a missing genotype.
+    ///
+    /// A genotype is missing if it is not set or if all its alleles are missing.
+    pub fn missing_count(&self) -> usize {
+        self.missing_count
+    }
+
+    /// Returns the number of homozygous reference genotypes.
+    pub fn homozygous_reference_count(&self) -> usize {
+        self.homozygous_reference_count
+    }
+
+    /// Returns the number of heterozygous genotypes.
+    pub fn heterozygous_count(&self) -> usize {
+        self.heterozygous_count
+    }
+
+    /// Returns the number of homozygous alternate genotypes.
+    pub fn homozygous_alternate_count(&self) -> usize {
+        self.homozygous_alternate_count
+    }
+
+    /// Returns the fraction of samples with a non-missing genotype.
+    ///
+    /// This is `None` if there are no samples.
+    pub fn call_rate(&self) -> Option<f32> {
+        if self.sample_count == 0 {
+            None
+        } else {
+            let called_count = self.sample_count - self.missing_count;
+            Some(called_count as f32 / self.sample_count as f32)
+        }
+    }
+}
+
+/// Decodes the genotypes (`GT`) of the samples of a variant record.
+///
+/// There is one genotype per sample in the header. A genotype is `None` if it is not set.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::variant::genotypes::{self, Phasing};
+/// use noodles_vcf as vcf;
+///
+/// let data = b"##fileformat=VCFv4.3
+/// ###FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1
+/// sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0|1\t.
+/// ";
+///
+/// let mut reader = vcf::io::Reader::new(&data[..]);
+/// let header = reader.read_header()?;
+///
+/// let mut record = vcf::Record::default();
+/// reader.read_record(&mut record)?;
+///
+/// let genotypes = genotypes::genotypes(&header, &record)?;
+/// assert_eq!(genotypes.len(), 2);
+///
+/// let genotype = genotypes[0].as_ref().unwrap();
+/// assert_eq!(genotype.alleles(), [Some(0), Some(1)]);
+/// assert_eq!(genotype.phasing(), Phasing::Phased);
+///
+/// assert!(genotypes[1].is_none());
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn genotypes<R>(header: &vcf::Header, record: &R) -> io::Result<Vec<Option<Genotype>>>
+where
+    R: Record + ?Sized,
+{
+    let sample_count = header.sample_names().len();
+    let mut genotypes = Vec::with_capacity(sample_count);
+
+    let samples = record.samples()?;
+
+    if let Some(series) = samples.select(header, key::GENOTYPE).transpose()? {
+        for result in series.iter(header).take(sample_count) {
+            let genotype = match result? {
+                Some(Value::Genotype(genotype)) => Some(Genotype::try_from(genotype.as_ref())?),
+                Some(_) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "invalid genotype value",
+                    ))
+                }
+                None => None,
+            };
+
+            genotypes.push(genotype);
+        }
+    }
+
+    genotypes.resize(sample_count, None);
+
+    Ok(genotypes)
+}
+
+/// Counts the alleles and genotypes of the samples of a variant record.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::variant::genotypes;
+/// use noodles_vcf as vcf;
+///
+/// let data = b"##fileformat=VCFv4.3
+/// ###FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1\tsample2
+/// sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/1\t1/1\t./.
+/// ";
+///
+/// let mut reader = vcf::io::Reader::new(&data[..]);
+/// let header = reader.read_header()?;
+///
+/// let mut record = vcf::Record::default();
+/// reader.read_record(&mut record)?;
+///
+/// let counts = genotypes::count(&header, &record)?;
+/// assert_eq!(counts.allele_counts(), [1, 3]);
+/// assert_eq!(counts.allele_number(), 4);
+/// assert_eq!(counts.allele_frequencies(), Some(vec![0.75]));
+/// assert_eq!(counts.missing_count(), 1);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn count<R>(header: &vcf::Header, record: &R) -> io::Result<Counts>
+where
+    R: Record + ?Sized,
+{
+    let allele_count = record.alternate_bases().len() + 1;
+
+    let mut counts = Counts {
+        allele_counts: vec![0; allele_count],
+        ..Default::default()
+    };
+
+    for genotype in genotypes(header, record)? {
+        counts.sample_count += 1;
+
+        let Some(genotype) = genotype.filter(|genotype| !genotype.is_missing()) else {
+            counts.missing_count += 1;
+            continue;
+        };
+
+        for &position in genotype.alleles().iter().flatten() {
+            let n = counts.allele_counts.get_mut(position).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "invalid allele position")
+            })?;
+
+            *n += 1;
+        }
+
+        match genotype.zygosity() {
+            Some(Zygosity::HomozygousReference) => counts.homozygous_reference_count += 1,
+            Some(Zygosity::Heterozygous) => counts.heterozygous_count += 1,
+            Some(Zygosity::HomozygousAlternate) => counts.homozygous_alternate_count += 1,
+            None => {}
+        }
+    }
+
+    Ok(counts)
+}
+
+/// Sets the allele count (`AC`), total allele count (`AN`), and allele frequency (`AF`) info
+/// fields of a variant record from its genotypes.
+///
+/// The allele frequencies are set to missing if no alleles are called.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::variant::genotypes;
+/// use noodles_vcf::{
+///     self as vcf,
+///     variant::{
+///         record::info::field::key,
+///         record_buf::info::field::Value,
+///     },
+/// };
+///
+/// let data = b"##fileformat=VCFv4.3
+/// ###FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+/// sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/1
+/// ";
+///
+/// let mut reader = vcf::io::Reader::new(&data[..]);
+/// let header = reader.read_header()?;
+///
+/// let mut record = reader.record_bufs(&header).next().transpose()?.unwrap();
+/// genotypes::fill_allele_counts(&header, &mut record)?;
+///
+/// assert_eq!(
+///     record.info().get(key::TOTAL_ALLELE_COUNT),
+///     Some(Some(&Value::Integer(2)))
+/// );
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn fill_allele_counts(header: &vcf::Header, record: &mut RecordBuf) -> io::Result<()> {
+    let counts = count(header, record)?;
+
+    let allele_counts = counts
+        .allele_counts()
+        .iter()
+        .skip(1)
+        .map(|&n| {
+            i32::try_from(n)
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .collect::<io::Result<_>>()?;
+
+    let allele_number = i32::try_from(counts.allele_number())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let allele_frequencies = match counts.allele_frequencies() {
+        Some(frequencies) => frequencies.into_iter().map(Some).collect(),
+        None => vec![None; counts.allele_counts().len() - 1],
+    };
+
+    let info = record.info_mut();
+
+    info.insert(
+        String::from(info_key::ALLELE_COUNT),
+        Some(InfoValue::Array(Array::Integer(allele_counts))),
+    );
+
+    info.insert(
+        String::from(info_key::TOTAL_ALLELE_COUNT),
+        Some(InfoValue::Integer(allele_number)),
+    );
+
+    info.insert(
+        String::from(info_key::ALLELE_FREQUENCIES),
+        Some(InfoValue::Array(Array::Float(allele_frequencies))),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bcf as bcf;
+    use vcf::variant::io::Write as _;
+
+    use super::*;
+
+    const DATA: &[u8] = b"##fileformat=VCFv4.3
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1\tsample2\tsample3\tsample4
+sq0\t1\t.\tA\tC,G\t.\tPASS\t.\tGT\t0/0\t0|2\t1/1\t./.\t1
+";
+
+    fn expected_genotypes() -> Vec<Option<Genotype>> {
+        vec![
+            Some(Genotype::new(vec![Some(0), Some(0)], Phasing::Unphased)),
+            Some(Genotype::new(vec![Some(0), Some(2)], Phasing::Phased)),
+            Some(Genotype::new(vec![Some(1), Some(1)], Phasing::Unphased)),
+            Some(Genotype::new(vec![None, None], Phasing::Unphased)),
+            Some(Genotype::new(vec![Some(1)], Phasing::Phased)),
+        ]
+    }
+
+    #[test]
+    fn test_genotypes() -> io::Result<()> {
+        let mut reader = vcf::io::Reader::new(DATA);
+        let header = reader.read_header()?;
+
+        let mut record = vcf::Record::default();
+        reader.read_record(&mut record)?;
+
+        assert_eq!(genotypes(&header, &record)?, expected_genotypes());
+
+        let mut writer = bcf::io::Writer::from(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_variant_record(&header, &record)?;
+
+        let mut reader = bcf::io::Reader::from(&writer.get_ref()[..]);
+        let header = reader.read_header()?;
+
+        let mut record = bcf::Record::default();
+        reader.read_record(&mut record)?;
+
+        assert_eq!(genotypes(&header, &record)?, expected_genotypes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count() -> io::Result<()> {
+        let mut reader = vcf::io::Reader::new(DATA);
+        let header = reader.read_header()?;
+
+        let mut record = vcf::Record::default();
+        reader.read_record(&mut record)?;
+
+        let counts = count(&header, &record)?;
+
+        assert_eq!(counts.allele_counts(), [3, 3, 1]);
+        assert_eq!(counts.allele_number(), 7);
+        assert_eq!(counts.sample_count(), 5);
+        assert_eq!(counts.missing_count(), 1);
+        assert_eq!(counts.homozygous_reference_count(), 1);
+        assert_eq!(counts.heterozygous_count(), 1);
+        assert_eq!(counts.homozygous_alternate_count(), 2);
+        assert_eq!(counts.call_rate(), Some(0.8));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_allele_counts() -> io::Result<()> {
+        let mut reader = vcf::io::Reader::new(DATA);
+        let header = reader.read_header()?;
+
+        let mut record = RecordBuf::default();
+        reader.read_record_buf(&header, &mut record)?;
+
+        fill_allele_counts(&header, &mut record)?;
+
+        let info = record.info();
+
+        assert_eq!(
+            info.get(info_key::ALLELE_COUNT),
+            Some(Some(&InfoValue::Array(Array::Integer(vec![
+                Some(3),
+                Some(1)
+            ]))))
+        );
+        assert_eq!(
+            info.get(info_key::TOTAL_ALLELE_COUNT),
+            Some(Some(&InfoValue::Integer(7)))
+        );
+        assert_eq!(
+            info.get(info_key::ALLELE_FREQUENCIES),
+            Some(Some(&InfoValue::Array(Array::Float(vec![
+                Some(3.0 / 7.0),
+                Some(1.0 / 7.0)
+            ]))))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,387 @@
+//! Variant record merging.
+//!
+//! [`Merge`] combines sorted variant inputs into a single multi-sample output. The input headers
+//! are merged into a single header, and records at the same position with the same reference
+//! bases are merged into one record with the samples of all inputs. The alternate bases of these
+//! records are unioned, i.e., records with different alternate bases are joined into a
+//! multiallelic record. Records with different reference bases are not joined.
+//!
+//! This is closest to `bcftools merge --merge all` restricted to records with identical reference
+//! bases. Samples of inputs without a record at a site are set to missing, with a missing genotype
+//! of the same ploidy as the other samples, e.g., `./.`.
+
+pub mod builder;
+mod header;
+
+pub use self::builder::Builder;
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    io,
+};
+
+use noodles_core::Position;
+use noodles_vcf::{
+    self as vcf,
+    variant::{
+        record::samples::{keys::key, series::value::genotype::Phasing},
+        record_buf::{
+            samples::{
+                sample::{
+                    value::{genotype::Allele, Genotype},
+                    Value,
+                },
+                Keys,
+            },
+            AlternateBases, Info, Samples,
+        },
+        RecordBuf,
+    },
+};
+
+use super::multiallelic::{join_info, join_sample, union_alternate_bases};
+
+type Records<'a> = Box<dyn Iterator<Item = io::Result<RecordBuf>> + 'a>;
+
+// The contig index in the merged header and the variant start.
+type Key = (usize, Option<Position>);
+
+/// An iterator over merged variant records.
+///
+/// This is created by calling [`Builder::build`].
+pub struct Merge<'a> {
+    header: vcf::Header,
+    sources: Vec<Source<'a>>,
+    records: Vec<Option<RecordBuf>>,
+    heap: BinaryHeap<Reverse<(Key, usize)>>,
+    merged_records: VecDeque<RecordBuf>,
+}
+
+struct Source<'a> {
+    records: Records<'a>,
+    sample_count: usize,
+}
+
+impl<'a> Merge<'a> {
+    fn new(header: vcf::Header, sources: Vec<Source<'a>>) -> io::Result<Self> {
+        let mut merge = Self {
+            header,
+            records: vec![None; sources.len()],
+            sources,
+            heap: BinaryHeap::new(),
+            merged_records: VecDeque::new(),
+        };
+
+        for i in 0..merge.sources.len() {
+            if let Some(key) = merge.fill(i)? {
+                merge.heap.push(Reverse((key, i)));
+            }
+        }
+
+        Ok(merge)
+    }
+
+    /// Returns the merged header.
+    pub fn header(&self) -> &vcf::Header {
+        &self.header
+    }
+
+    // Reads the next record of the given source and returns its key.
+    fn fill(&mut self, i: usize) -> io::Result<Option<Key>> {
+        let Some(record) = self.sources[i].records.next().transpose()? else {
+            return Ok(None);
+        };
+
+        let name = record.reference_sequence_name();
+
+        let contig_index = self.header.contigs().get_index_of(name).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("missing contig in header: {name}"),
+            )
+        })?;
+
+        let key = (contig_index, record.variant_start());
+        self.records[i] = Some(record);
+
+        Ok(Some(key))
+    }
+
+    // Reads all records at the next position and merges them.
+    fn read_position(&mut self) -> io::Result<bool> {
+        let Some(Reverse((key, i))) = self.heap.pop() else {
+            return Ok(false);
+        };
+
+        let mut source_indices = vec![i];
+
+        while let Some(Reverse((next_key, j))) = self.heap.peek() {
+            if *next_key != key {
+                break;
+            }
+
+            source_indices.push(*j);
+            self.heap.pop();
+        }
+
+        let mut source_records = vec![Vec::new(); self.sources.len()];
+
+        for i in source_indices {
+            while let Some(record) = self.records[i].take() {
+                source_records[i].push(record);
+
+                match self.fill(i)? {
+                    Some(next_key) if next_key == key => {}
+                    Some(next_key) if next_key < key => {
+                        return Err(io::Error::new(io::ErrorKind::InvalidData, "unsorted input"));
+                    }
+                    Some(next_key) => {
+                        self.heap.push(Reverse((next_key, i)));
+                        break;
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        let sample_counts: Vec<_> = self
+            .sources
+            .iter()
+            .map(|source| source.sample_count)
+            .collect();
+
+        // Records with the same reference bases are merged. Each group takes at most one record
+        // from each source.
+        while let Some(reference_bases) = source_records
+            .iter()
+            .find_map(|records| records.first())
+            .map(|record| record.reference_bases().to_string())
+        {
+            let records: Vec<_> = source_records
+                .iter_mut()
+                .map(|records| {
+                    records
+                        .iter()
+                        .position(|record| record.reference_bases() == reference_bases)
+                        .map(|j| records.remove(j))
+                })
+                .collect();
+
+            let record = merge_records(&self.header, &sample_counts, &records)?;
+            self.merged_records.push_back(record);
+        }
+
+        Ok(true)
+    }
+}
+
+impl Iterator for Merge<'_> {
+    type Item = io::Result<RecordBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.merged_records.pop_front() {
+                return Some(Ok(record));
+            }
+
+            match self.read_position() {
+                Ok(true) => {}
+                Ok(false) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+// Merges records at the same position with the same reference bases.
+//
+// There is one optional record per source. Samples of sources without a record are set to
+// missing.
+fn merge_records(
+    header: &vcf::Header,
+    sample_counts: &[usize],
+    records: &[Option<RecordBuf>],
+) -> io::Result<RecordBuf> {
+    let first_record = records
+        .iter()
+        .flatten()
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no records to merge"))?;
+
+    let (alternate_bases, record_alleles) = union_alternate_bases(records.iter().flatten());
+    let allele_count = alternate_bases.len() + 1;
+
+    let mut info = Info::default();
+
+    for (record, alleles) in records.iter().flatten().zip(&record_alleles) {
+        join_info(header, &mut info, record.info(), allele_count, alleles)?;
+    }
+
+    let has_genotypes = records
+        .iter()
+        .flatten()
+        .any(|record| record.samples().keys().as_ref().contains(key::GENOTYPE));
+
+    // The genotype (`GT`) must be the first key.
+    let keys: Keys = has_genotypes
+        .then(|| String::from(key::GENOTYPE))
+        .into_iter()
+        .chain(
+            records
+                .iter()
+                .flatten()
+                .flat_map(|record| record.samples().keys().as_ref().iter().cloned()),
+        )
+        .collect();
+
+    let mut values = Vec::with_capacity(sample_counts.iter().sum());
+    let mut missing_sample_indices = Vec::new();
+    let mut record_alleles = record_alleles.iter();
+
+    for (record, &sample_count) in records.iter().zip(sample_counts) {
+        let start = values.len();
+
+        if let Some(record) = record {
+            // SAFETY: There is a list of alleles for each record.
+            let alleles = record_alleles.next().unwrap();
+
+            for sample in record.samples().values().take(sample_count) {
+                let mut sample_values = vec![None; keys.as_ref().len()];
+                join_sample(
+                    header,
+                    &keys,
+                    &mut sample_values,
+                    sample,
+                    allele_count,
+                    alleles,
+                )?;
+                values.push(sample_values);
+            }
+        }
+
+        missing_sample_indices.extend(values.len()..start + sample_count);
+        values.resize(start + sample_count, vec![None; keys.as_ref().len()]);
+    }
+
+    if has_genotypes {
+        fill_missing_genotypes(&mut values, &missing_sample_indices);
+    }
+
+    let quality_score = records
+        .iter()
+        .flatten()
+        .filter_map(|record| record.quality_score())
+        .reduce(f32::max);
+
+    let mut record = first_record.clone();
+    *record.alternate_bases_mut() = AlternateBases::from(alternate_bases);
+    *record.quality_score_mut() = quality_score;
+    *record.info_mut() = info;
+    *record.samples_mut() = Samples::new(keys, values);
+
+    Ok(record)
+}
+
+// Sets the genotypes of the given samples to missing with the largest ploidy of the other samples.
+//
+// The genotype (`GT`) must be the first key.
+fn fill_missing_genotypes(values: &mut [Vec<Option<Value>>], sample_indices: &[usize]) {
+    let ploidy = values
+        .iter()
+        .filter_map(|sample_values| match sample_values.first() {
+            Some(Some(Value::Genotype(genotype))) => Some(genotype.as_ref().len()),
+            _ => None,
+        })
+        .max();
+
+    let Some(ploidy) = ploidy else {
+        return;
+    };
+
+    let genotype: Genotype = (0..ploidy)
+        .map(|_| Allele::new(None, Phasing::Unphased))
+        .collect();
+
+    for &i in sample_indices {
+        if let Some(value) = values[i].first_mut() {
+            *value = Some(Value::Genotype(genotype.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use noodles_bgzf as bgzf;
+    use noodles_csi as csi;
+
+    use super::*;
+    use crate::variant::io::IndexedReader;
+
+    fn build_reader(src: &[u8]) -> io::Result<IndexedReader<bgzf::Reader<Cursor<Vec<u8>>>>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(src)?;
+        let data = writer.finish()?;
+
+        Ok(IndexedReader::Vcf(vcf::io::IndexedReader::new(
+            Cursor::new(data),
+            csi::Index::default(),
+        )))
+    }
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        const SRC_A: &[u8] = b"##fileformat=VCFv4.3
+##INFO=<ID=AC,Number=A,Type=Integer,Description=\"Allele count\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##FORMAT=<ID=AD,Number=R,Type=Integer,Description=\"Read depths\">
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0\t1\t.\tA\tC\t10\tPASS\tAC=1\tGT:AD\t0/1:3,4
+sq0\t3\t.\tG\tT\t.\tPASS\tAC=2\tGT:AD\t1/1:0,5
+";
+
+        const SRC_B: &[u8] = b"##fileformat=VCFv4.3
+##INFO=<ID=AC,Number=A,Type=Integer,Description=\"Allele count\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##FORMAT=<ID=AD,Number=R,Type=Integer,Description=\"Read depths\">
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample1
+sq0\t1\t.\tA\tG\t20\tPASS\tAC=1\tGT:AD\t1/0:6,7
+sq0\t2\t.\tC\tA\t.\tPASS\tAC=1\tGT:AD\t0/1:8,9
+";
+
+        let mut readers = vec![build_reader(SRC_A)?, build_reader(SRC_B)?];
+
+        let headers: Vec<_> = readers
+            .iter_mut()
+            .map(|reader| reader.read_header())
+            .collect::<io::Result<_>>()?;
+
+        let merge = Builder::default().build(&mut readers, &headers)?;
+
+        let sample_names: Vec<_> = merge.header().sample_names().iter().collect();
+        assert_eq!(sample_names, ["sample0", "sample1"]);
+
+        let header = merge.header().clone();
+
+        let mut writer = vcf::io::Writer::new(Vec::new());
+
+        for result in merge {
+            let record = result?;
+            vcf::variant::io::Write::write_variant_record(&mut writer, &header, &record)?;
+        }
+
+        let expected = b"\
+sq0\t1\t.\tA\tC,G\t20\tPASS\tAC=1,1\tGT:AD\t0/1:3,4,.\t2/0:6,.,7
+sq0\t2\t.\tC\tA\t.\tPASS\tAC=1\tGT:AD\t./.:.\t0/1:8,9
+sq0\t3\t.\tG\tT\t.\tPASS\tAC=2\tGT:AD\t1/1:0,5\t./.:.
+";
+
+        assert_eq!(writer.get_ref(), expected);
+
+        Ok(())
+    }
+}
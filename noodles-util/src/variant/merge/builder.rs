@@ -0,0 +1,123 @@
+//! Variant record merge builder.
+
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_vcf::{self as vcf, variant::RecordBuf};
+
+use super::{header::merge_headers, Merge, Records, Source};
+use crate::variant::io::IndexedReader;
+
+/// A variant record merge builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    region: Option<Region>,
+}
+
+impl Builder {
+    /// Sets a region to query.
+    ///
+    /// By default, all records are merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Region;
+    /// use noodles_util::variant;
+    ///
+    /// let region = "sq0:8-13".parse()?;
+    /// let builder = variant::merge::Builder::default().set_region(region);
+    /// # Ok::<_, noodles_core::region::ParseError>(())
+    /// ```
+    pub fn set_region(mut self, region: Region) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Builds a merge iterator from indexed variant readers and their headers.
+    ///
+    /// Each reader is expected to be positioned at the start of its records, i.e., its header has
+    /// already been read into `headers`. Records of all inputs must be sorted by position, with
+    /// contigs in the order of the merged header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_util::variant;
+    ///
+    /// let mut readers = vec![
+    ///     variant::io::indexed_reader::Builder::default().build_from_path("sample0.vcf.gz")?,
+    ///     variant::io::indexed_reader::Builder::default().build_from_path("sample1.bcf")?,
+    /// ];
+    ///
+    /// let headers: Vec<_> = readers
+    ///     .iter_mut()
+    ///     .map(|reader| reader.read_header())
+    ///     .collect::<io::Result<_>>()?;
+    ///
+    /// let merge = variant::merge::Builder::default().build(&mut readers, &headers)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build<'a, R>(
+        self,
+        readers: &'a mut [IndexedReader<R>],
+        headers: &'a [vcf::Header],
+    ) -> io::Result<Merge<'a>>
+    where
+        R: bgzf::io::BufRead + bgzf::io::Seek,
+    {
+        if readers.len() != headers.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "reader and header count mismatch",
+            ));
+        }
+
+        let header = merge_headers(headers)?;
+
+        let sources = readers
+            .iter_mut()
+            .zip(headers)
+            .map(|(reader, header)| {
+                records(reader, header, self.region.as_ref()).map(|records| Source {
+                    records,
+                    sample_count: header.sample_names().len(),
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        Merge::new(header, sources)
+    }
+}
+
+fn records<'a, R>(
+    reader: &'a mut IndexedReader<R>,
+    header: &'a vcf::Header,
+    region: Option<&Region>,
+) -> io::Result<Records<'a>>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    let records: Records<'a> = match (reader, region) {
+        (IndexedReader::Vcf(reader), None) => Box::new(reader.records().map(|result| {
+            result.and_then(|record| RecordBuf::try_from_variant_record(header, &record))
+        })),
+        (IndexedReader::Vcf(reader), Some(region)) => {
+            Box::new(reader.query(header, region)?.map(|result| {
+                result.and_then(|record| RecordBuf::try_from_variant_record(header, &record))
+            }))
+        }
+        (IndexedReader::Bcf(reader), None) => Box::new(reader.records().map(|result| {
+            result.and_then(|record| RecordBuf::try_from_variant_record(header, &record))
+        })),
+        (IndexedReader::Bcf(reader), Some(region)) => {
+            Box::new(reader.query(header, region)?.map(|result| {
+                result.and_then(|record| RecordBuf::try_from_variant_record(header, &record))
+            }))
+        }
+    };
+
+    Ok(records)
+}
@@ -0,0 +1,193 @@
+use std::io;
+
+use noodles_vcf::{self as vcf, header::StringMaps};
+
+/// Merges the headers of the inputs.
+///
+/// The file format and other records are taken from the first header. Contigs, INFO, FILTER,
+/// FORMAT, and ALT records are combined in order of first appearance. Contigs with the same name
+/// must have the same length, and INFO and FORMAT records with the same ID must have the same
+/// number and type.
+///
+/// Samples are concatenated in input order and must be unique across all inputs.
+pub(super) fn merge_headers(headers: &[vcf::Header]) -> io::Result<vcf::Header> {
+    let mut merged_header = headers.first().cloned().unwrap_or_default();
+    merged_header.sample_names_mut().clear();
+
+    for header in headers {
+        for (name, contig) in header.contigs() {
+            match merged_header.contigs_mut().get_mut(name) {
+                Some(merged_contig) => match (merged_contig.length(), contig.length()) {
+                    (Some(a), Some(b)) if a != b => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("contig length mismatch: {name}"),
+                        ));
+                    }
+                    (None, Some(length)) => *merged_contig.length_mut() = Some(length),
+                    _ => {}
+                },
+                None => {
+                    merged_header
+                        .contigs_mut()
+                        .insert(name.clone(), contig.clone());
+                }
+            }
+        }
+
+        for (id, info) in header.infos() {
+            match merged_header.infos().get(id) {
+                Some(merged_info) => {
+                    if merged_info.number() != info.number() || merged_info.ty() != info.ty() {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("conflicting INFO definitions: {id}"),
+                        ));
+                    }
+                }
+                None => {
+                    merged_header.infos_mut().insert(id.clone(), info.clone());
+                }
+            }
+        }
+
+        for (id, filter) in header.filters() {
+            merged_header
+                .filters_mut()
+                .entry(id.clone())
+                .or_insert_with(|| filter.clone());
+        }
+
+        for (id, format) in header.formats() {
+            match merged_header.formats().get(id) {
+                Some(merged_format) => {
+                    if merged_format.number() != format.number()
+                        || merged_format.ty() != format.ty()
+                    {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("conflicting FORMAT definitions: {id}"),
+                        ));
+                    }
+                }
+                None => {
+                    merged_header
+                        .formats_mut()
+                        .insert(id.clone(), format.clone());
+                }
+            }
+        }
+
+        for (id, alternative_allele) in header.alternative_alleles() {
+            merged_header
+                .alternative_alleles_mut()
+                .entry(id.clone())
+                .or_insert_with(|| alternative_allele.clone());
+        }
+
+        for sample_name in header.sample_names() {
+            if !merged_header.sample_names_mut().insert(sample_name.clone()) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("duplicate sample name: {sample_name}"),
+                ));
+            }
+        }
+    }
+
+    // The string dictionaries of the inputs are not compatible with each other, so they are
+    // rebuilt from the merged records.
+    for contig in merged_header.contigs_mut().values_mut() {
+        *contig.idx_mut() = None;
+    }
+
+    for info in merged_header.infos_mut().values_mut() {
+        *info.idx_mut() = None;
+    }
+
+    for filter in merged_header.filters_mut().values_mut() {
+        *filter.idx_mut() = None;
+    }
+
+    for format in merged_header.formats_mut().values_mut() {
+        *format.idx_mut() = None;
+    }
+
+    *merged_header.string_maps_mut() = StringMaps::try_from(&merged_header)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(merged_header)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_vcf::header::record::value::{
+        map::{Contig, Format, Info},
+        Map,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_merge_headers() -> io::Result<()> {
+        let headers = [
+            vcf::Header::builder()
+                .add_contig("sq0", Map::<Contig>::new())
+                .add_info("DP", Map::<Info>::from("DP"))
+                .add_format("GT", Map::<Format>::from("GT"))
+                .add_sample_name("sample0")
+                .build(),
+            vcf::Header::builder()
+                .add_contig("sq0", Map::<Contig>::new())
+                .add_contig("sq1", Map::<Contig>::new())
+                .add_format("GT", Map::<Format>::from("GT"))
+                .add_format("AD", Map::<Format>::from("AD"))
+                .add_sample_name("sample1")
+                .build(),
+        ];
+
+        let header = merge_headers(&headers)?;
+
+        let contig_names: Vec<_> = header.contigs().keys().collect();
+        assert_eq!(contig_names, ["sq0", "sq1"]);
+
+        let info_ids: Vec<_> = header.infos().keys().collect();
+        assert_eq!(info_ids, ["DP"]);
+
+        let format_ids: Vec<_> = header.formats().keys().collect();
+        assert_eq!(format_ids, ["GT", "AD"]);
+
+        let sample_names: Vec<_> = header.sample_names().iter().collect();
+        assert_eq!(sample_names, ["sample0", "sample1"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_headers_with_conflicts() {
+        let mut info = Map::<Info>::from("DP");
+        *info.number_mut() = vcf::header::record::value::map::info::Number::Unknown;
+
+        let headers = [
+            vcf::Header::builder()
+                .add_info("DP", Map::<Info>::from("DP"))
+                .build(),
+            vcf::Header::builder().add_info("DP", info).build(),
+        ];
+
+        assert!(matches!(
+            merge_headers(&headers),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let headers = [
+            vcf::Header::builder().add_sample_name("sample0").build(),
+            vcf::Header::builder().add_sample_name("sample0").build(),
+        ];
+
+        assert!(matches!(
+            merge_headers(&headers),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+}
@@ -0,0 +1,975 @@
+//! Multiallelic variant record splitting and joining.
+//!
+//! This is similar to `bcftools norm --multiallelics -any` and `bcftools norm --multiallelics
+//! +any`, respectively.
+
+use std::io;
+
+use noodles_vcf::{
+    self as vcf,
+    header::record::value::map::{format, info},
+    variant::{
+        record::samples::keys::key,
+        record_buf::{
+            info::field::{value::Array as InfoArray, Value as InfoValue},
+            samples::{
+                sample::{
+                    value::{Array as SampleArray, Genotype},
+                    Value as SampleValue,
+                },
+                Keys, Sample,
+            },
+            AlternateBases, Info, Samples,
+        },
+        RecordBuf,
+    },
+};
+
+/// Splits a multiallelic variant record into biallelic records.
+///
+/// A record is created for each alternate allele. INFO and FORMAT fields with a number of `A`,
+/// `R`, or `G` are subset to the values of the reference allele and the alternate allele, and
+/// other alternate alleles in genotypes (`GT`) are set to the reference allele. Other fields are
+/// copied.
+///
+/// Records with fewer than two alternate alleles are returned as is.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_util::variant::multiallelic;
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::record::value::{map::Info, Map},
+///     variant::{
+///         record::info::field::key,
+///         record_buf::info::field::{value::Array, Value},
+///         RecordBuf,
+///     },
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_info(key::ALLELE_COUNT, Map::<Info>::from(key::ALLELE_COUNT))
+///     .build();
+///
+/// let record = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::MIN)
+///     .set_reference_bases("A")
+///     .set_alternate_bases(vec![String::from("C"), String::from("G")].into())
+///     .set_info(
+///         [(
+///             String::from(key::ALLELE_COUNT),
+///             Some(Value::Array(Array::Integer(vec![Some(3), Some(5)]))),
+///         )]
+///         .into_iter()
+///         .collect(),
+///     )
+///     .build();
+///
+/// let records = multiallelic::split(&header, &record)?;
+///
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records[1].alternate_bases().as_ref(), [String::from("G")]);
+/// assert_eq!(
+///     records[1].info().get(key::ALLELE_COUNT),
+///     Some(Some(&Value::Array(Array::Integer(vec![Some(5)])))),
+/// );
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn split(header: &vcf::Header, record: &RecordBuf) -> io::Result<Vec<RecordBuf>> {
+    let alternate_bases = record.alternate_bases().as_ref();
+
+    if alternate_bases.len() < 2 {
+        return Ok(vec![record.clone()]);
+    }
+
+    let allele_count = alternate_bases.len() + 1;
+
+    (1..allele_count)
+        .map(|i| {
+            let alleles = [0, i];
+
+            let mut biallelic_record = record.clone();
+
+            *biallelic_record.alternate_bases_mut() =
+                AlternateBases::from(vec![alternate_bases[i - 1].clone()]);
+
+            *biallelic_record.info_mut() =
+                split_info(header, record.info(), allele_count, &alleles)?;
+
+            *biallelic_record.samples_mut() =
+                split_samples(header, record.samples(), allele_count, &alleles)?;
+
+            Ok(biallelic_record)
+        })
+        .collect()
+}
+
+/// Joins variant records at the same position into a multiallelic record.
+///
+/// The records must have the same reference sequence name, variant start, and reference bases.
+/// The alternate alleles are the union of the alternate alleles of the records, in order of first
+/// appearance. INFO and FORMAT fields with a number of `A`, `R`, or `G` are merged, where values
+/// not in any record are missing. Genotype (`GT`) alleles are merged by taking the first
+/// non-reference allele at each position. All other fields are taken from the first record that
+/// has them.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_util::variant::multiallelic;
+/// use noodles_vcf::{self as vcf, variant::RecordBuf};
+///
+/// let header = vcf::Header::default();
+///
+/// let build_record = |alternate_bases: &str| {
+///     RecordBuf::builder()
+///         .set_reference_sequence_name("sq0")
+///         .set_variant_start(Position::MIN)
+///         .set_reference_bases("A")
+///         .set_alternate_bases(vec![String::from(alternate_bases)].into())
+///         .build()
+/// };
+///
+/// let records = [build_record("C"), build_record("G"), build_record("C")];
+/// let record = multiallelic::join(&header, &records)?;
+///
+/// assert_eq!(
+///     record.alternate_bases().as_ref(),
+///     [String::from("C"), String::from("G")],
+/// );
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn join(header: &vcf::Header, records: &[RecordBuf]) -> io::Result<RecordBuf> {
+    let (first_record, other_records) = records
+        .split_first()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no records to join"))?;
+
+    for record in other_records {
+        if record.reference_sequence_name() != first_record.reference_sequence_name()
+            || record.variant_start() != first_record.variant_start()
+            || record.reference_bases() != first_record.reference_bases()
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "records have different positions or reference bases",
+            ));
+        }
+    }
+
+    let sample_count = first_record.samples().values().count();
+
+    if other_records
+        .iter()
+        .any(|record| record.samples().values().count() != sample_count)
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "records have different numbers of samples",
+        ));
+    }
+
+    let (alternate_bases, record_alleles) = union_alternate_bases(records);
+
+    let allele_count = alternate_bases.len() + 1;
+
+    let mut info = Info::default();
+
+    for (record, alleles) in records.iter().zip(&record_alleles) {
+        join_info(header, &mut info, record.info(), allele_count, alleles)?;
+    }
+
+    let keys: Keys = records
+        .iter()
+        .flat_map(|record| record.samples().keys().as_ref().iter().cloned())
+        .collect();
+
+    let mut values = vec![vec![None; keys.as_ref().len()]; sample_count];
+
+    for (record, alleles) in records.iter().zip(&record_alleles) {
+        for (dst_values, sample) in values.iter_mut().zip(record.samples().values()) {
+            join_sample(header, &keys, dst_values, sample, allele_count, alleles)?;
+        }
+    }
+
+    let mut record = first_record.clone();
+    *record.alternate_bases_mut() = AlternateBases::from(alternate_bases);
+    *record.info_mut() = info;
+    *record.samples_mut() = Samples::new(keys, values);
+
+    Ok(record)
+}
+
+/// Returns the union of the alternate bases of the records and, for each record, the indices of
+/// its alleles in the union.
+///
+/// The reference allele is always at index 0.
+pub(super) fn union_alternate_bases<'r, I>(records: I) -> (Vec<String>, Vec<Vec<usize>>)
+where
+    I: IntoIterator<Item = &'r RecordBuf>,
+{
+    let mut alternate_bases: Vec<String> = Vec::new();
+
+    let record_alleles = records
+        .into_iter()
+        .map(|record| {
+            let mut alleles = vec![0];
+
+            for bases in record.alternate_bases().as_ref() {
+                let i = match alternate_bases.iter().position(|b| b == bases) {
+                    Some(i) => i,
+                    None => {
+                        alternate_bases.push(bases.clone());
+                        alternate_bases.len() - 1
+                    }
+                };
+
+                alleles.push(i + 1);
+            }
+
+            alleles
+        })
+        .collect();
+
+    (alternate_bases, record_alleles)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Cardinality {
+    AlternateBases,
+    ReferenceAlternateBases,
+    Genotypes,
+}
+
+impl Cardinality {
+    fn from_info_number(number: info::Number) -> Option<Self> {
+        match number {
+            info::Number::AlternateBases => Some(Self::AlternateBases),
+            info::Number::ReferenceAlternateBases => Some(Self::ReferenceAlternateBases),
+            info::Number::Samples => Some(Self::Genotypes),
+            _ => None,
+        }
+    }
+
+    fn from_format_number(number: format::Number) -> Option<Self> {
+        match number {
+            format::Number::AlternateBases => Some(Self::AlternateBases),
+            format::Number::ReferenceAlternateBases => Some(Self::ReferenceAlternateBases),
+            format::Number::Samples => Some(Self::Genotypes),
+            _ => None,
+        }
+    }
+
+    // Returns the ploidy of a field value with the given number of alleles and values.
+    //
+    // Only haploid and diploid genotype fields are supported.
+    fn ploidy(self, allele_count: usize, len: usize) -> io::Result<usize> {
+        if self != Self::Genotypes {
+            return Ok(1);
+        }
+
+        [1, 2]
+            .into_iter()
+            .find(|&ploidy| genotype_count(allele_count, ploidy) == len)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid genotype field value length",
+                )
+            })
+    }
+
+    fn len(self, allele_count: usize, ploidy: usize) -> usize {
+        match self {
+            Self::AlternateBases => allele_count - 1,
+            Self::ReferenceAlternateBases => allele_count,
+            Self::Genotypes => genotype_count(allele_count, ploidy),
+        }
+    }
+
+    // Returns, for each value of a field with the given alleles, the index of the value in the
+    // field with all alleles.
+    fn indices(self, alleles: &[usize], ploidy: usize) -> Vec<usize> {
+        match self {
+            Self::AlternateBases => alleles[1..].iter().map(|a| a - 1).collect(),
+            Self::ReferenceAlternateBases => alleles.to_vec(),
+            Self::Genotypes if ploidy == 1 => alleles.to_vec(),
+            Self::Genotypes => (0..alleles.len())
+                .flat_map(|k| (0..=k).map(move |j| genotype_index(alleles[j], alleles[k])))
+                .collect(),
+        }
+    }
+}
+
+fn genotype_count(allele_count: usize, ploidy: usize) -> usize {
+    if ploidy == 1 {
+        allele_count
+    } else {
+        allele_count * (allele_count + 1) / 2
+    }
+}
+
+// Returns the index of a diploid genotype in VCF order.
+fn genotype_index(a: usize, b: usize) -> usize {
+    let (a, b) = if a <= b { (a, b) } else { (b, a) };
+    b * (b + 1) / 2 + a
+}
+
+fn split_info(
+    header: &vcf::Header,
+    info: &Info,
+    allele_count: usize,
+    alleles: &[usize],
+) -> io::Result<Info> {
+    info.as_ref()
+        .iter()
+        .map(|(key, value)| {
+            let cardinality = header
+                .infos()
+                .get(key)
+                .and_then(|info| Cardinality::from_info_number(info.number()));
+
+            let value = match (value, cardinality) {
+                (Some(value), Some(cardinality)) => {
+                    Some(split_value(value, cardinality, allele_count, alleles)?)
+                }
+                (value, _) => value.clone(),
+            };
+
+            Ok((key.clone(), value))
+        })
+        .collect()
+}
+
+fn split_samples(
+    header: &vcf::Header,
+    samples: &Samples,
+    allele_count: usize,
+    alleles: &[usize],
+) -> io::Result<Samples> {
+    let keys = samples.keys().clone();
+
+    let values = samples
+        .values()
+        .map(|sample| {
+            keys.as_ref()
+                .iter()
+                .zip(sample.values())
+                .map(|(key, value)| {
+                    let Some(value) = value else {
+                        return Ok(None);
+                    };
+
+                    if let (key::GENOTYPE, SampleValue::Genotype(genotype)) = (key.as_str(), value)
+                    {
+                        let genotype = split_genotype(genotype, alleles);
+                        return Ok(Some(SampleValue::Genotype(genotype)));
+                    }
+
+                    let cardinality = header
+                        .formats()
+                        .get(key)
+                        .and_then(|format| Cardinality::from_format_number(format.number()));
+
+                    match cardinality {
+                        Some(cardinality) => {
+                            split_value(value, cardinality, allele_count, alleles).map(Some)
+                        }
+                        None => Ok(Some(value.clone())),
+                    }
+                })
+                .collect()
+        })
+        .collect::<io::Result<_>>()?;
+
+    Ok(Samples::new(keys, values))
+}
+
+fn split_genotype(genotype: &Genotype, alleles: &[usize]) -> Genotype {
+    genotype
+        .as_ref()
+        .iter()
+        .map(|allele| {
+            let mut allele = allele.clone();
+
+            if let Some(position) = allele.position_mut() {
+                *position = alleles.iter().position(|a| a == position).unwrap_or(0);
+            }
+
+            allele
+        })
+        .collect()
+}
+
+fn split_value<V>(
+    value: &V,
+    cardinality: Cardinality,
+    allele_count: usize,
+    alleles: &[usize],
+) -> io::Result<V>
+where
+    V: FieldValue,
+{
+    let Some(array) = value.to_array() else {
+        return Ok(value.clone());
+    };
+
+    let ploidy = cardinality.ploidy(allele_count, array.len())?;
+    let indices = cardinality.indices(alleles, ploidy);
+
+    array.select(&indices).map(V::from_array)
+}
+
+/// Merges the fields of `info` into `dst`.
+///
+/// `alleles` are the indices of the alleles of the record of `info` in a record with
+/// `allele_count` alleles.
+pub(super) fn join_info(
+    header: &vcf::Header,
+    dst: &mut Info,
+    info: &Info,
+    allele_count: usize,
+    alleles: &[usize],
+) -> io::Result<()> {
+    for (key, value) in info.as_ref() {
+        let dst_value = dst.as_mut().entry(key.clone()).or_insert(None);
+
+        let Some(value) = value else {
+            continue;
+        };
+
+        let cardinality = header
+            .infos()
+            .get(key)
+            .and_then(|info| Cardinality::from_info_number(info.number()));
+
+        join_value(dst_value, value, cardinality, allele_count, alleles)?;
+    }
+
+    Ok(())
+}
+
+/// Merges the values of a sample into `dst`.
+///
+/// `keys` are the keys of `dst`, and `alleles` are the indices of the alleles of the sample's
+/// record in a record with `allele_count` alleles.
+pub(super) fn join_sample(
+    header: &vcf::Header,
+    keys: &Keys,
+    dst: &mut [Option<SampleValue>],
+    sample: Sample<'_>,
+    allele_count: usize,
+    alleles: &[usize],
+) -> io::Result<()> {
+    for (key, value) in sample.keys().as_ref().iter().zip(sample.values()) {
+        let Some(value) = value else {
+            continue;
+        };
+
+        let i = keys.as_ref().get_index_of(key).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("missing sample key: {key}"),
+            )
+        })?;
+
+        let dst_value = &mut dst[i];
+
+        if let (key::GENOTYPE, SampleValue::Genotype(genotype)) = (key.as_str(), value) {
+            join_genotype(dst_value, genotype, alleles)?;
+            continue;
+        }
+
+        let cardinality = header
+            .formats()
+            .get(key)
+            .and_then(|format| Cardinality::from_format_number(format.number()));
+
+        join_value(dst_value, value, cardinality, allele_count, alleles)?;
+    }
+
+    Ok(())
+}
+
+fn join_genotype(
+    dst: &mut Option<SampleValue>,
+    genotype: &Genotype,
+    alleles: &[usize],
+) -> io::Result<()> {
+    let genotype: Genotype = genotype
+        .as_ref()
+        .iter()
+        .map(|allele| {
+            let mut allele = allele.clone();
+
+            if let Some(position) = allele.position_mut() {
+                *position = alleles.get(*position).copied().ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "invalid genotype allele")
+                })?;
+            }
+
+            Ok(allele)
+        })
+        .collect::<io::Result<_>>()?;
+
+    match dst {
+        Some(SampleValue::Genotype(dst_genotype)) => {
+            for (dst_allele, allele) in dst_genotype.as_mut().iter_mut().zip(genotype.as_ref()) {
+                if allele.position().is_some() && matches!(dst_allele.position(), None | Some(0)) {
+                    *dst_allele.position_mut() = allele.position();
+                }
+            }
+        }
+        Some(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "field value type mismatch",
+            ))
+        }
+        None => *dst = Some(SampleValue::Genotype(genotype)),
+    }
+
+    Ok(())
+}
+
+fn join_value<V>(
+    dst: &mut Option<V>,
+    value: &V,
+    cardinality: Option<Cardinality>,
+    allele_count: usize,
+    alleles: &[usize],
+) -> io::Result<()>
+where
+    V: FieldValue,
+{
+    let (Some(cardinality), Some(array)) = (cardinality, value.to_array()) else {
+        if dst.is_none() {
+            *dst = Some(value.clone());
+        }
+
+        return Ok(());
+    };
+
+    let ploidy = cardinality.ploidy(alleles.len(), array.len())?;
+    let indices = cardinality.indices(alleles, ploidy);
+    let len = cardinality.len(allele_count, ploidy);
+
+    let mut dst_array = match dst.take() {
+        Some(dst_value) => dst_value.to_array().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "field value type mismatch")
+        })?,
+        None => array.empty(len),
+    };
+
+    if dst_array.len() != len {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "field value length mismatch",
+        ));
+    }
+
+    dst_array.place(&array, &indices)?;
+    *dst = Some(V::from_array(dst_array));
+
+    Ok(())
+}
+
+trait FieldValue: Clone {
+    type Array: FieldArray;
+
+    // Returns the value as an array, treating a scalar as an array with one value.
+    fn to_array(&self) -> Option<Self::Array>;
+
+    fn from_array(array: Self::Array) -> Self;
+}
+
+impl FieldValue for InfoValue {
+    type Array = InfoArray;
+
+    fn to_array(&self) -> Option<InfoArray> {
+        match self {
+            Self::Integer(n) => Some(InfoArray::Integer(vec![Some(*n)])),
+            Self::Float(n) => Some(InfoArray::Float(vec![Some(*n)])),
+            Self::Flag => None,
+            Self::Character(c) => Some(InfoArray::Character(vec![Some(*c)])),
+            Self::String(s) => Some(InfoArray::String(vec![Some(s.clone())])),
+            Self::Array(array) => Some(array.clone()),
+        }
+    }
+
+    fn from_array(array: InfoArray) -> Self {
+        Self::Array(array)
+    }
+}
+
+impl FieldValue for SampleValue {
+    type Array = SampleArray;
+
+    fn to_array(&self) -> Option<SampleArray> {
+        match self {
+            Self::Integer(n) => Some(SampleArray::Integer(vec![Some(*n)])),
+            Self::Float(n) => Some(SampleArray::Float(vec![Some(*n)])),
+            Self::Character(c) => Some(SampleArray::Character(vec![Some(*c)])),
+            Self::String(s) => Some(SampleArray::String(vec![Some(s.clone())])),
+            Self::Genotype(_) => None,
+            Self::Array(array) => Some(array.clone()),
+        }
+    }
+
+    fn from_array(array: SampleArray) -> Self {
+        Self::Array(array)
+    }
+}
+
+trait FieldArray: Sized {
+    fn len(&self) -> usize;
+
+    // Returns an array with the values at the given indices.
+    fn select(&self, indices: &[usize]) -> io::Result<Self>;
+
+    // Returns an array of the same type with the given number of missing values.
+    fn empty(&self, len: usize) -> Self;
+
+    // Sets the missing values at the given indices to the values of `src`.
+    fn place(&mut self, src: &Self, indices: &[usize]) -> io::Result<()>;
+}
+
+impl FieldArray for InfoArray {
+    fn len(&self) -> usize {
+        match self {
+            Self::Integer(values) => values.len(),
+            Self::Float(values) => values.len(),
+            Self::Character(values) => values.len(),
+            Self::String(values) => values.len(),
+        }
+    }
+
+    fn select(&self, indices: &[usize]) -> io::Result<Self> {
+        match self {
+            Self::Integer(values) => select_values(values, indices).map(Self::Integer),
+            Self::Float(values) => select_values(values, indices).map(Self::Float),
+            Self::Character(values) => select_values(values, indices).map(Self::Character),
+            Self::String(values) => select_values(values, indices).map(Self::String),
+        }
+    }
+
+    fn empty(&self, len: usize) -> Self {
+        match self {
+            Self::Integer(_) => Self::Integer(vec![None; len]),
+            Self::Float(_) => Self::Float(vec![None; len]),
+            Self::Character(_) => Self::Character(vec![None; len]),
+            Self::String(_) => Self::String(vec![None; len]),
+        }
+    }
+
+    fn place(&mut self, src: &Self, indices: &[usize]) -> io::Result<()> {
+        match (self, src) {
+            (Self::Integer(dst), Self::Integer(src)) => place_values(dst, src, indices),
+            (Self::Float(dst), Self::Float(src)) => place_values(dst, src, indices),
+            (Self::Character(dst), Self::Character(src)) => place_values(dst, src, indices),
+            (Self::String(dst), Self::String(src)) => place_values(dst, src, indices),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "field value type mismatch",
+            )),
+        }
+    }
+}
+
+impl FieldArray for SampleArray {
+    fn len(&self) -> usize {
+        match self {
+            Self::Integer(values) => values.len(),
+            Self::Float(values) => values.len(),
+            Self::Character(values) => values.len(),
+            Self::String(values) => values.len(),
+        }
+    }
+
+    fn select(&self, indices: &[usize]) -> io::Result<Self> {
+        match self {
+            Self::Integer(values) => select_values(values, indices).map(Self::Integer),
+            Self::Float(values) => select_values(values, indices).map(Self::Float),
+            Self::Character(values) => select_values(values, indices).map(Self::Character),
+            Self::String(values) => select_values(values, indices).map(Self::String),
+        }
+    }
+
+    fn empty(&self, len: usize) -> Self {
+        match self {
+            Self::Integer(_) => Self::Integer(vec![None; len]),
+            Self::Float(_) => Self::Float(vec![None; len]),
+            Self::Character(_) => Self::Character(vec![None; len]),
+            Self::String(_) => Self::String(vec![None; len]),
+        }
+    }
+
+    fn place(&mut self, src: &Self, indices: &[usize]) -> io::Result<()> {
+        match (self, src) {
+            (Self::Integer(dst), Self::Integer(src)) => place_values(dst, src, indices),
+            (Self::Float(dst), Self::Float(src)) => place_values(dst, src, indices),
+            (Self::Character(dst), Self::Character(src)) => place_values(dst, src, indices),
+            (Self::String(dst), Self::String(src)) => place_values(dst, src, indices),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "field value type mismatch",
+            )),
+        }
+    }
+}
+
+fn select_values<T>(values: &[Option<T>], indices: &[usize]) -> io::Result<Vec<Option<T>>>
+where
+    T: Clone,
+{
+    indices
+        .iter()
+        .map(|&i| {
+            values.get(i).cloned().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "invalid field value length")
+            })
+        })
+        .collect()
+}
+
+fn place_values<T>(dst: &mut [Option<T>], src: &[Option<T>], indices: &[usize]) -> io::Result<()>
+where
+    T: Clone,
+{
+    if src.len() != indices.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid field value length",
+        ));
+    }
+
+    for (value, &i) in src.iter().zip(indices) {
+        let dst_value = dst.get_mut(i).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "invalid field value length")
+        })?;
+
+        if dst_value.is_none() {
+            dst_value.clone_from(value);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_vcf::{
+        header::record::value::{
+            map::{Format, Info},
+            Map,
+        },
+        variant::record::info::field::key as info_key,
+    };
+
+    use super::*;
+
+    fn build_header() -> vcf::Header {
+        vcf::Header::builder()
+            .add_info(
+                info_key::ALLELE_COUNT,
+                Map::<Info>::from(info_key::ALLELE_COUNT),
+            )
+            .add_info(
+                info_key::TOTAL_ALLELE_COUNT,
+                Map::<Info>::from(info_key::TOTAL_ALLELE_COUNT),
+            )
+            .add_format(key::GENOTYPE, Map::<Format>::from(key::GENOTYPE))
+            .add_format(key::READ_DEPTHS, Map::<Format>::from(key::READ_DEPTHS))
+            .add_format(
+                key::ROUNDED_GENOTYPE_LIKELIHOODS,
+                Map::<Format>::from(key::ROUNDED_GENOTYPE_LIKELIHOODS),
+            )
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build()
+    }
+
+    fn build_record(
+        alternate_bases: &[&str],
+        allele_counts: Vec<Option<i32>>,
+        samples: Vec<Vec<Option<SampleValue>>>,
+    ) -> RecordBuf {
+        let keys = [
+            key::GENOTYPE,
+            key::READ_DEPTHS,
+            key::ROUNDED_GENOTYPE_LIKELIHOODS,
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_alternate_bases(
+                alternate_bases
+                    .iter()
+                    .map(|bases| String::from(*bases))
+                    .collect::<Vec<_>>()
+                    .into(),
+            )
+            .set_info(
+                [
+                    (
+                        String::from(info_key::ALLELE_COUNT),
+                        Some(InfoValue::Array(InfoArray::Integer(allele_counts))),
+                    ),
+                    (
+                        String::from(info_key::TOTAL_ALLELE_COUNT),
+                        Some(InfoValue::Integer(4)),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_samples(Samples::new(keys, samples))
+            .build()
+    }
+
+    fn build_sample(
+        genotype: &str,
+        read_depths: Vec<Option<i32>>,
+        likelihoods: Vec<Option<i32>>,
+    ) -> Vec<Option<SampleValue>> {
+        vec![
+            Some(SampleValue::Genotype(genotype.parse().unwrap())),
+            Some(SampleValue::Array(SampleArray::Integer(read_depths))),
+            Some(SampleValue::Array(SampleArray::Integer(likelihoods))),
+        ]
+    }
+
+    #[test]
+    fn test_split() -> io::Result<()> {
+        let header = build_header();
+
+        let record = build_record(
+            &["C", "G"],
+            vec![Some(1), Some(2)],
+            vec![
+                build_sample(
+                    "1/2",
+                    vec![Some(0), Some(5), Some(6)],
+                    (0..6).map(Some).collect(),
+                ),
+                build_sample("0|2", vec![Some(7), Some(0), Some(8)], vec![None; 6]),
+            ],
+        );
+
+        let actual = split(&header, &record)?;
+
+        let expected = [
+            build_record(
+                &["C"],
+                vec![Some(1)],
+                vec![
+                    build_sample(
+                        "1/0",
+                        vec![Some(0), Some(5)],
+                        vec![Some(0), Some(1), Some(2)],
+                    ),
+                    build_sample("0|0", vec![Some(7), Some(0)], vec![None; 3]),
+                ],
+            ),
+            build_record(
+                &["G"],
+                vec![Some(2)],
+                vec![
+                    build_sample(
+                        "0/1",
+                        vec![Some(0), Some(6)],
+                        vec![Some(0), Some(3), Some(5)],
+                    ),
+                    build_sample("0|1", vec![Some(7), Some(8)], vec![None; 3]),
+                ],
+            ),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_with_biallelic_record() -> io::Result<()> {
+        let header = build_header();
+        let record = build_record(&["C"], vec![Some(1)], Vec::new());
+        assert_eq!(split(&header, &record)?, [record]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_join() -> io::Result<()> {
+        let header = build_header();
+
+        let records = [
+            build_record(
+                &["C"],
+                vec![Some(1)],
+                vec![
+                    build_sample(
+                        "0/1",
+                        vec![Some(0), Some(5)],
+                        vec![Some(0), Some(1), Some(2)],
+                    ),
+                    build_sample("0|0", vec![Some(7), Some(0)], vec![None; 3]),
+                ],
+            ),
+            build_record(
+                &["G"],
+                vec![Some(2)],
+                vec![
+                    build_sample(
+                        "0/1",
+                        vec![Some(0), Some(6)],
+                        vec![Some(0), Some(3), Some(5)],
+                    ),
+                    build_sample("0|1", vec![Some(7), Some(8)], vec![None; 3]),
+                ],
+            ),
+        ];
+
+        let actual = join(&header, &records)?;
+
+        let expected = build_record(
+            &["C", "G"],
+            vec![Some(1), Some(2)],
+            vec![
+                build_sample(
+                    "0/1",
+                    vec![Some(0), Some(5), Some(6)],
+                    vec![Some(0), Some(1), Some(2), Some(3), None, Some(5)],
+                ),
+                build_sample("0|2", vec![Some(7), Some(0), Some(8)], vec![None; 6]),
+            ],
+        );
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_with_different_positions() {
+        let header = build_header();
+
+        let mut record = build_record(&["G"], vec![Some(2)], Vec::new());
+        *record.variant_start_mut() = Position::new(2);
+
+        let records = [build_record(&["C"], vec![Some(1)], Vec::new()), record];
+
+        assert!(matches!(
+            join(&header, &records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+}
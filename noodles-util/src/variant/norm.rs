@@ -0,0 +1,320 @@
+//! Variant normalization.
+//!
+//! This is similar to `bcftools norm`.
+
+use std::io;
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+use noodles_vcf::variant::{
+    record::info::field::key,
+    record_buf::{info::field::Value, AlternateBases},
+    RecordBuf,
+};
+
+/// The result of normalizing a variant record.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// The record is already normalized.
+    Unchanged,
+    /// The record was left-aligned and/or trimmed.
+    Normalized,
+    /// The record cannot be normalized.
+    ///
+    /// This is the case for records without alternate bases; with symbolic, breakend, or
+    /// overlapping deletion (`*`) alternate bases; or with alternate bases that are the same as the
+    /// reference bases.
+    Skipped,
+    /// The reference bases of the record differ from the reference sequence.
+    ///
+    /// This holds the bases of the reference sequence.
+    ReferenceMismatch(String),
+}
+
+/// Normalizes a variant record.
+///
+/// The reference bases of the record are first checked against the reference sequence,
+/// ignoring case. If they match, the alleles are left-aligned and parsimoniously trimmed, i.e.,
+/// common trailing bases are removed and leading bases are prepended from the reference sequence
+/// until the alleles end with different bases, and then common leading bases are removed while all
+/// alleles have at least two bases. The variant start and, if present, the end position (`END`)
+/// info field are updated accordingly.
+///
+/// The record is left unchanged if the status is not [`Status::Normalized`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_util::variant::norm::{self, Status};
+/// use noodles_vcf::variant::RecordBuf;
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"GCACACAT".to_vec()),
+/// )]);
+///
+/// let mut record = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::try_from(4)?)
+///     .set_reference_bases("CAC")
+///     .set_alternate_bases(vec![String::from("C")].into())
+///     .build();
+///
+/// assert_eq!(norm::normalize(&repository, &mut record)?, Status::Normalized);
+/// assert_eq!(record.variant_start(), Some(Position::MIN));
+/// assert_eq!(record.reference_bases(), "GCA");
+/// assert_eq!(record.alternate_bases().as_ref(), [String::from("G")]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn normalize(repository: &fasta::Repository, record: &mut RecordBuf) -> io::Result<Status> {
+    let reference_sequence_name = record.reference_sequence_name();
+
+    let reference_sequence = repository
+        .get(reference_sequence_name.as_bytes())
+        .transpose()?
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("missing reference sequence: {reference_sequence_name}"),
+            )
+        })?;
+
+    let reference_sequence = reference_sequence.as_ref();
+
+    let variant_start = record
+        .variant_start()
+        .map(usize::from)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing variant start"))?;
+
+    let reference_bases = record.reference_bases().as_bytes();
+
+    let expected_reference_bases = reference_sequence
+        .get(variant_start - 1..variant_start - 1 + reference_bases.len())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "variant is out of reference sequence bounds",
+            )
+        })?;
+
+    if !reference_bases.eq_ignore_ascii_case(expected_reference_bases) {
+        return Ok(Status::ReferenceMismatch(
+            String::from_utf8_lossy(expected_reference_bases).into_owned(),
+        ));
+    }
+
+    let alternate_bases = record.alternate_bases().as_ref();
+
+    if reference_bases.is_empty()
+        || alternate_bases.is_empty()
+        || alternate_bases.iter().any(|bases| {
+            !is_normalizable(bases.as_bytes())
+                || bases.as_bytes().eq_ignore_ascii_case(reference_bases)
+        })
+    {
+        return Ok(Status::Skipped);
+    }
+
+    let mut alleles: Vec<Vec<u8>> = [record.reference_bases()]
+        .into_iter()
+        .chain(alternate_bases.iter().map(|bases| bases.as_str()))
+        .map(|bases| bases.as_bytes().to_vec())
+        .collect();
+
+    let start = left_align_and_trim(reference_sequence, variant_start, &mut alleles);
+
+    let is_unchanged = start == variant_start
+        && alleles[0].len() == reference_bases.len()
+        && alleles[1..]
+            .iter()
+            .zip(alternate_bases)
+            .all(|(allele, bases)| allele.len() == bases.len());
+
+    if is_unchanged {
+        return Ok(Status::Unchanged);
+    }
+
+    let mut alleles = alleles.into_iter().map(|allele| {
+        String::from_utf8(allele).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    });
+
+    // SAFETY: `alleles` has at least two elements.
+    let new_reference_bases = alleles.next().unwrap()?;
+    let new_alternate_bases: Vec<_> = alleles.collect::<io::Result<_>>()?;
+
+    let end = start + new_reference_bases.len() - 1;
+
+    *record.variant_start_mut() = Position::new(start);
+    *record.reference_bases_mut() = new_reference_bases;
+    *record.alternate_bases_mut() = AlternateBases::from(new_alternate_bases);
+
+    if let Some(value) = record.info_mut().get_mut(key::END_POSITION) {
+        let end = i32::try_from(end).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        *value = Some(Value::Integer(end));
+    }
+
+    Ok(Status::Normalized)
+}
+
+fn is_normalizable(bases: &[u8]) -> bool {
+    !bases.is_empty()
+        && bases
+            .iter()
+            .all(|b| matches!(b.to_ascii_uppercase(), b'A' | b'C' | b'G' | b'T' | b'N'))
+}
+
+// Returns the new 1-based start position.
+fn left_align_and_trim(
+    reference_sequence: &[u8],
+    mut start: usize,
+    alleles: &mut [Vec<u8>],
+) -> usize {
+    loop {
+        let mut is_changed = false;
+
+        let can_trim_last_base = alleles.iter().all(|allele| allele.len() > 1) || start > 1;
+
+        if can_trim_last_base && have_equal_bases(alleles, |allele| allele.last().copied()) {
+            for allele in alleles.iter_mut() {
+                allele.pop();
+            }
+
+            is_changed = true;
+        }
+
+        if alleles.iter().any(|allele| allele.is_empty()) {
+            let base = reference_sequence[start - 2].to_ascii_uppercase();
+
+            for allele in alleles.iter_mut() {
+                allele.insert(0, base);
+            }
+
+            start -= 1;
+            is_changed = true;
+        }
+
+        if !is_changed {
+            break;
+        }
+    }
+
+    while alleles.iter().all(|allele| allele.len() > 1)
+        && have_equal_bases(alleles, |allele| allele.first().copied())
+    {
+        for allele in alleles.iter_mut() {
+            allele.remove(0);
+        }
+
+        start += 1;
+    }
+
+    start
+}
+
+fn have_equal_bases<F>(alleles: &[Vec<u8>], f: F) -> bool
+where
+    F: Fn(&[u8]) -> Option<u8>,
+{
+    let mut bases = alleles
+        .iter()
+        .map(|allele| f(allele).map(|b| b.to_ascii_uppercase()));
+
+    match bases.next().flatten() {
+        Some(first) => bases.all(|base| base == Some(first)),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fasta::record::{Definition, Sequence};
+
+    use super::*;
+
+    fn build_repository() -> fasta::Repository {
+        fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGTTTTGCA".to_vec()),
+        )])
+    }
+
+    fn build_record(
+        variant_start: usize,
+        reference_bases: &str,
+        alternate_bases: &[&str],
+    ) -> RecordBuf {
+        RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::new(variant_start).unwrap())
+            .set_reference_bases(reference_bases)
+            .set_alternate_bases(
+                alternate_bases
+                    .iter()
+                    .map(|bases| String::from(*bases))
+                    .collect::<Vec<_>>()
+                    .into(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_normalize() -> io::Result<()> {
+        let repository = build_repository();
+
+        let cases = [
+            // deletion of a T in a homopolymer
+            ((6, "TT", vec!["T"]), (3, "GT", vec!["G"])),
+            // insertion of a T in a homopolymer
+            ((7, "T", vec!["TT"]), (3, "G", vec!["GT"])),
+            // redundant leading and trailing bases
+            ((1, "ACGTT", vec!["AGGTT"]), (2, "C", vec!["G"])),
+            // multiallelic
+            ((5, "TTT", vec!["TT", "TTTT"]), (3, "GT", vec!["G", "GTT"])),
+            // at the start of the reference sequence
+            ((1, "AC", vec!["C"]), (1, "AC", vec!["C"])),
+        ];
+
+        for ((start, reference_bases, alternate_bases), expected) in cases {
+            let mut record = build_record(start, reference_bases, &alternate_bases);
+            normalize(&repository, &mut record)?;
+
+            let (expected_start, expected_reference_bases, expected_alternate_bases) = expected;
+            assert_eq!(record.variant_start(), Position::new(expected_start));
+            assert_eq!(record.reference_bases(), expected_reference_bases);
+            assert_eq!(record.alternate_bases().as_ref(), expected_alternate_bases);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_with_status() -> io::Result<()> {
+        let repository = build_repository();
+
+        let mut record = build_record(2, "C", &["G"]);
+        assert_eq!(normalize(&repository, &mut record)?, Status::Unchanged);
+
+        let mut record = build_record(2, "C", &["<DEL>"]);
+        assert_eq!(normalize(&repository, &mut record)?, Status::Skipped);
+
+        let mut record = build_record(2, "G", &["T"]);
+        assert_eq!(
+            normalize(&repository, &mut record)?,
+            Status::ReferenceMismatch(String::from("C"))
+        );
+
+        let mut record = build_record(6, "TT", &["T"]);
+        record
+            .info_mut()
+            .insert(String::from(key::END_POSITION), Some(Value::Integer(7)));
+        assert_eq!(normalize(&repository, &mut record)?, Status::Normalized);
+        assert_eq!(
+            record.info().get(key::END_POSITION),
+            Some(Some(&Value::Integer(4)))
+        );
+
+        Ok(())
+    }
+}
@@ -9,7 +9,7 @@ use noodles_bgzf as bgzf;
 use noodles_csi::{self as csi, BinningIndex};
 use noodles_tabix as tabix;
 
-use super::IndexedReader;
+use super::{IndexedReader, Reader};
 
 /// An indexed VCF reader builder.
 #[derive(Default)]
@@ -87,6 +87,41 @@ impl Builder {
 
         Ok(IndexedReader::new(reader, index))
     }
+
+    /// Builds an indexed VCF reader from a BGZF decoder.
+    ///
+    /// This allows using a decoder other than [`bgzf::Reader`], e.g., a
+    /// [`bgzf::MultithreadedReader`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf::io::indexed_reader::Builder;
+    ///
+    /// let index = tabix::Index::default();
+    /// let decoder = bgzf::MultithreadedReader::with_worker_count(NonZeroUsize::MIN, io::empty());
+    /// let reader = Builder::default()
+    ///     .set_index(index)
+    ///     .build_from_decoder(decoder)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_from_decoder<R>(self, decoder: R) -> io::Result<IndexedReader<R>>
+    where
+        R: bgzf::io::BufRead + bgzf::io::Seek,
+    {
+        let index = self
+            .index
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index"))?;
+
+        Ok(IndexedReader {
+            inner: Reader::new(decoder),
+            index,
+        })
+    }
 }
 
 fn read_associated_index<P>(src: P) -> io::Result<Box<dyn BinningIndex>>